@@ -0,0 +1,50 @@
+#[path = "src/package_file_finder.rs"]
+mod package_file_finder;
+
+use package_file_finder::find_package_files;
+use std::{fs, io::Write, path::Path};
+
+// The FFI library is embedded along with the prelude package as its FFI crate
+// depends on the library by a relative path.
+const EMBEDDED_DIRECTORIES: &[(&str, &str)] = &[
+    ("../../lib/prelude", "prelude_package_files.rs"),
+    ("../../lib/ffi", "ffi_library_files.rs"),
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    for (directory, filename) in EMBEDDED_DIRECTORIES {
+        println!("cargo:rerun-if-changed={}", directory);
+
+        write_file_list(
+            Path::new(directory),
+            &Path::new(&std::env::var("OUT_DIR")?).join(filename),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn write_file_list(
+    directory: &Path,
+    output_file_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut file = fs::File::create(output_file_path)?;
+
+    writeln!(file, "&[")?;
+
+    for path in find_package_files(directory)? {
+        writeln!(
+            file,
+            "(&{:?}, include_bytes!({:?})),",
+            path.strip_prefix(directory)?
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<_>>(),
+            fs::canonicalize(&path)?,
+        )?;
+    }
+
+    writeln!(file, "]")?;
+
+    Ok(())
+}
@@ -1,14 +1,25 @@
 use super::{
-    compile_configuration::COMPILE_CONFIGURATION, file_path_configuration::FILE_PATH_CONFIGURATION,
+    compile_configuration::COMPILE_CONFIGURATION,
+    file_path_configuration::FILE_PATH_CONFIGURATION,
+    prelude_package_files::{read_ffi_library_files, read_prelude_package_files},
     system_package_configuration::SYSTEM_PACKAGE_CONFIGURATION,
+    watch,
 };
 
-pub fn build() -> Result<(), Box<dyn std::error::Error>> {
+const ROOT_DIRECTORY_VARIABLE: &str = "EIN_ROOT";
+
+pub fn build(
+    profile_name: app::ProfileName,
+    target_configuration: Option<app::TargetConfiguration>,
+    backend: app::Backend,
+    emitted_stages: &[lang::Stage],
+    watch: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
     let package_directory = find_package_directory()?;
 
     let logger = infra::Logger::new();
 
-    let file_path_converter = infra::FilePathConverter::new(package_directory);
+    let file_path_converter = infra::FilePathConverter::new(&package_directory);
     let file_system = infra::FileSystem::new(&file_path_converter);
     let static_file_path_manager = app::StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
     let file_path_resolver =
@@ -16,14 +27,16 @@ pub fn build() -> Result<(), Box<dyn std::error::Error>> {
     let file_path_displayer = infra::FilePathDisplayer::new(&file_path_converter);
 
     let command_runner = infra::CommandRunner::new();
+    let command_finder = infra::CommandFinder::new();
     let module_parser = app::ModuleParser::new(&file_path_displayer);
     let module_compiler = app::ModuleCompiler::new(
         &module_parser,
         &file_path_resolver,
         &file_system,
         &logger,
-        COMPILE_CONFIGURATION.clone(),
+        compile_configuration(target_configuration.as_ref(), backend),
         &FILE_PATH_CONFIGURATION,
+        emitted_stages,
     );
     let modules_finder = app::ModulesFinder::new(&file_system, &FILE_PATH_CONFIGURATION);
     let modules_builder = app::ModulesBuilder::new(
@@ -41,28 +54,42 @@ pub fn build() -> Result<(), Box<dyn std::error::Error>> {
     );
     let ffi_package_initializer =
         infra::FfiPackageInitializer::new(&command_runner, &file_path_converter);
+    let ffi_package_builder = infra::FfiPackageBuilder::new(
+        &command_runner,
+        &file_path_converter,
+        &file_system,
+        &static_file_path_manager,
+        target_configuration.as_ref(),
+    );
     let package_builder = app::PackageBuilder::new(
         &modules_builder,
         &ffi_package_initializer,
+        &ffi_package_builder,
+        None,
         &file_system,
         &logger,
     );
 
-    let root_directory_string = std::env::var("EIN_ROOT")?;
-    let root_directory = std::path::Path::new(&root_directory_string);
-
-    let prelude_package_downloader = infra::PreludePackageDownloader::new(
-        &command_runner,
-        &file_path_converter,
-        root_directory.join("lib/prelude"),
-    );
+    let root_directory = find_root_directory();
+    let prelude_package_files =
+        read_prelude_package_files(root_directory.as_deref().map(std::path::Path::new))?;
+    let ffi_library_files =
+        read_ffi_library_files(root_directory.as_deref().map(std::path::Path::new))?;
     let prelude_package_builder = app::PreludePackageBuilder::new(
         &package_configuration_reader,
         &package_builder,
-        &prelude_package_downloader,
+        &file_system,
         &static_file_path_manager,
+        &prelude_package_files,
+        &ffi_library_files,
+    );
+    let application_linker = infra::ApplicationLinker::new(
+        &command_runner,
+        &command_finder,
+        &file_path_converter,
+        &static_file_path_manager,
+        target_configuration.as_ref(),
     );
-    let application_linker = infra::ApplicationLinker::new(&command_runner, &file_path_converter);
     let external_package_downloader = infra::ExternalPackageDownloader::new(&file_path_converter);
     let cached_external_package_downloader = app::CachedExternalPackageDownloader::new(
         &package_configuration_reader,
@@ -88,10 +115,50 @@ pub fn build() -> Result<(), Box<dyn std::error::Error>> {
         &SYSTEM_PACKAGE_CONFIGURATION,
     );
 
-    main_package_builder.build()
+    if watch {
+        watch::watch(&package_directory, || {
+            main_package_builder.build(profile_name)
+        })
+    } else {
+        main_package_builder.build(profile_name)
+    }
+}
+
+fn compile_configuration(
+    target_configuration: Option<&app::TargetConfiguration>,
+    backend: app::Backend,
+) -> std::sync::Arc<lang::CompileConfiguration> {
+    let mut configuration = COMPILE_CONFIGURATION.as_ref().clone();
+    let mut code_generation_configuration =
+        configuration.code_generation_configuration.as_ref().clone();
+
+    if let Some(target_configuration) = target_configuration {
+        // WebAssembly modules use the allocator of WASI libc directly.
+        if target_configuration.is_wasm() {
+            configuration.malloc_function_name = "malloc".into();
+            configuration.realloc_function_name = "realloc".into();
+            configuration.free_function_name = "free".into();
+        }
+
+        code_generation_configuration.target_triple = Some(target_configuration.triple().into());
+    }
+
+    if backend == app::Backend::C {
+        code_generation_configuration.output_format = lang::OutputFormat::C;
+    }
+
+    configuration.code_generation_configuration = code_generation_configuration.into();
+
+    configuration.into()
+}
+
+// The prelude package in a source tree overrides the embedded one for its
+// development.
+pub fn find_root_directory() -> Option<String> {
+    std::env::var(ROOT_DIRECTORY_VARIABLE).ok()
 }
 
-fn find_package_directory() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+pub fn find_package_directory() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
     let mut directory: &std::path::Path = &std::env::current_dir()?;
 
     while !directory
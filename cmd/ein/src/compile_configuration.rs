@@ -6,6 +6,21 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<lang::CompileConfiguration>> = Lazy::
         malloc_function_name: "_ein_malloc".into(),
         realloc_function_name: "_ein_realloc".into(),
         free_function_name: "_ein_free".into(),
+        array_type_configuration: lang::ArrayTypeConfiguration {
+            empty_array_variable_name: "_emptyArray".into(),
+            push_function_name: "pushToArray".into(),
+        }
+        .into(),
+        integer_type_configuration: lang::IntegerTypeConfiguration {
+            add_function_name: "_addIntegers".into(),
+            subtract_function_name: "_subtractIntegers".into(),
+            multiply_function_name: "_multiplyIntegers".into(),
+            divide_function_name: "_divideIntegers".into(),
+            remainder_function_name: "_remainderIntegers".into(),
+            equal_function_name: "_equalIntegers".into(),
+            compare_function_name: "_compareIntegers".into(),
+        }
+        .into(),
         list_type_configuration: lang::ListTypeConfiguration {
             empty_list_variable_name: "_emptyList".into(),
             concatenate_function_name: "_concatenateLists".into(),
@@ -19,8 +34,24 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<lang::CompileConfiguration>> = Lazy::
             map_function_name: "_mapList".into(),
         }
         .into(),
+        map_type_configuration: lang::MapTypeConfiguration {
+            empty_map_function_name: "_emptyMap".into(),
+            insert_function_name: "insertToMap".into(),
+            hash_integer_function_name: "_hashInteger".into(),
+            hash_number_function_name: "_hashNumber".into(),
+            hash_string_function_name: "_hashString".into(),
+            combine_hashes_function_name: "_combineHashes".into(),
+        }
+        .into(),
+        number_type_configuration: lang::NumberTypeConfiguration {
+            integer_divide_function_name: "_integerDivideNumbers".into(),
+            remainder_function_name: "_remainderNumbers".into(),
+        }
+        .into(),
         string_type_configuration: lang::StringTypeConfiguration {
             equal_function_name: "_equalStrings".into(),
+            concatenate_function_name: "_concatenateStrings".into(),
+            convert_to_string_function_name: "_convertToString".into(),
         }
         .into(),
         error_type_configuration: lang::ErrorTypeConfiguration {
@@ -35,6 +66,14 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<lang::CompileConfiguration>> = Lazy::
             }
             .into(),
         ),
+        code_generation_configuration: lang::CodeGenerationConfiguration {
+            output_format: lang::OutputFormat::Object,
+            optimization_level: lang::OptimizationLevel::Aggressive,
+            tail_call_optimization: true,
+            debug_information: false,
+            target_triple: None,
+        }
+        .into(),
     }
     .into()
 });
@@ -0,0 +1,116 @@
+use super::{
+    build::{find_package_directory, find_root_directory},
+    compile_configuration::COMPILE_CONFIGURATION,
+    file_path_configuration::FILE_PATH_CONFIGURATION,
+    prelude_package_files::{read_ffi_library_files, read_prelude_package_files},
+    system_package_configuration::SYSTEM_PACKAGE_CONFIGURATION,
+};
+use app::{FilePathDisplayer, Logger};
+
+pub fn doc(format: lang::DocumentationFormat) -> Result<(), Box<dyn std::error::Error>> {
+    let package_directory = find_package_directory()?;
+
+    let logger = infra::Logger::new();
+
+    let file_path_converter = infra::FilePathConverter::new(package_directory);
+    let file_system = infra::FileSystem::new(&file_path_converter);
+    let static_file_path_manager = app::StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+    let file_path_resolver =
+        app::FilePathResolver::new(&static_file_path_manager, &FILE_PATH_CONFIGURATION);
+    let file_path_displayer = infra::FilePathDisplayer::new(&file_path_converter);
+
+    let command_runner = infra::CommandRunner::new();
+    let module_parser = app::ModuleParser::new(&file_path_displayer);
+    let module_compiler = app::ModuleCompiler::new(
+        &module_parser,
+        &file_path_resolver,
+        &file_system,
+        &logger,
+        COMPILE_CONFIGURATION.clone(),
+        &FILE_PATH_CONFIGURATION,
+        &[],
+    );
+    let modules_finder = app::ModulesFinder::new(&file_system, &FILE_PATH_CONFIGURATION);
+    let modules_builder = app::ModulesBuilder::new(
+        &module_parser,
+        &module_compiler,
+        &modules_finder,
+        &file_system,
+        &file_path_resolver,
+    );
+
+    let package_configuration_reader = app::PackageConfigurationReader::new(
+        &file_system,
+        &file_path_displayer,
+        &static_file_path_manager,
+    );
+    let ffi_package_initializer =
+        infra::FfiPackageInitializer::new(&command_runner, &file_path_converter);
+    let ffi_package_builder = infra::FfiPackageBuilder::new(
+        &command_runner,
+        &file_path_converter,
+        &file_system,
+        &static_file_path_manager,
+        None,
+    );
+    // Documentation is generated from module interfaces only and no foreign
+    // library is built or loaded.
+    let package_builder = app::PackageBuilder::new(
+        &modules_builder,
+        &ffi_package_initializer,
+        &ffi_package_builder,
+        None,
+        &file_system,
+        &logger,
+    );
+
+    let root_directory = find_root_directory();
+    let prelude_package_files =
+        read_prelude_package_files(root_directory.as_deref().map(std::path::Path::new))?;
+    let ffi_library_files =
+        read_ffi_library_files(root_directory.as_deref().map(std::path::Path::new))?;
+    let prelude_package_builder = app::PreludePackageBuilder::new(
+        &package_configuration_reader,
+        &package_builder,
+        &file_system,
+        &static_file_path_manager,
+        &prelude_package_files,
+        &ffi_library_files,
+    );
+    let external_package_downloader = infra::ExternalPackageDownloader::new(&file_path_converter);
+    let cached_external_package_downloader = app::CachedExternalPackageDownloader::new(
+        &package_configuration_reader,
+        &external_package_downloader,
+        &file_system,
+        &file_path_resolver,
+        &logger,
+    );
+    let external_packages_downloader =
+        app::ExternalPackagesDownloader::new(&cached_external_package_downloader);
+    let external_packages_builder = app::ExternalPackagesBuilder::new(&package_builder);
+    let system_package_builder =
+        app::SystemPackageBuilder::new(&package_builder, &cached_external_package_downloader);
+    let main_package_loader = app::MainPackageLoader::new(
+        &package_configuration_reader,
+        &package_builder,
+        &prelude_package_builder,
+        &system_package_builder,
+        &external_packages_downloader,
+        &external_packages_builder,
+        &SYSTEM_PACKAGE_CONFIGURATION,
+    );
+    let documentation_generator = app::DocumentationGenerator::new(
+        &main_package_loader,
+        &file_system,
+        &static_file_path_manager,
+    );
+
+    let file_path = documentation_generator.generate(format)?;
+
+    logger.log(&format!(
+        "documentation generated at {}",
+        file_path_displayer.display(&file_path)
+    ))?;
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use super::{
+    build::find_root_directory,
+    prelude_package_files::{read_ffi_library_files, read_prelude_package_files},
+};
+use app::Logger;
+
+pub fn doctor() -> Result<(), Box<dyn std::error::Error>> {
+    let logger = infra::Logger::new();
+    let command_runner = infra::CommandRunner::new();
+    let command_finder = infra::CommandFinder::new();
+    let mut error_count = 0;
+
+    if let Some(directory) = find_root_directory() {
+        match read_prelude_package_files(Some(std::path::Path::new(&directory)))
+            .and_then(|_| read_ffi_library_files(Some(std::path::Path::new(&directory))))
+        {
+            Ok(_) => logger.log(&format!(
+                "prelude package found in EIN_ROOT at {}",
+                directory
+            ))?,
+            Err(error) => {
+                error_count += 1;
+                logger.log_error(
+                    Box::<dyn std::error::Error>::from(format!(
+                        "{}\nhint: set EIN_ROOT to the root directory of the Ein repository or unset it to use the embedded prelude package",
+                        error
+                    ))
+                    .as_ref(),
+                )?;
+            }
+        }
+    } else {
+        logger.log("embedded prelude package used")?;
+    }
+
+    for command in infra::REQUIRED_COMMANDS {
+        match check_command(command, &command_finder, &command_runner) {
+            Ok(message) => logger.log(&message)?,
+            // Builds not using optional commands succeed without them.
+            Err(error) if command.optional => logger.log_warning(error.as_ref())?,
+            Err(error) => {
+                error_count += 1;
+                logger.log_error(error.as_ref())?;
+            }
+        }
+    }
+
+    if error_count == 0 {
+        Ok(())
+    } else {
+        Err(format!("{} problem(s) found in the environment", error_count).into())
+    }
+}
+
+fn check_command(
+    command: &infra::RequiredCommand,
+    command_finder: &infra::CommandFinder,
+    command_runner: &infra::CommandRunner,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let path = command_finder.find(command.name)?;
+
+    let version = if let Some(option) = command.version_option {
+        let version = command_runner
+            .run(std::process::Command::new(&path).arg(option))
+            .map_err(|error| {
+                format!(
+                    "failed to get version of {} at {}: {}",
+                    command.name,
+                    path.to_string_lossy(),
+                    error
+                )
+            })?
+            .lines()
+            .find(|line| !line.trim().is_empty())
+            .unwrap_or_default()
+            .trim()
+            .to_owned();
+
+        if command.llvm && !command_finder.is_llvm_version_supported(&version) {
+            return Err(format!(
+                "unsupported version of {} at {}: {}\nhint: {}",
+                command.name,
+                path.to_string_lossy(),
+                version,
+                command.hint
+            )
+            .into());
+        }
+
+        Some(version)
+    } else {
+        None
+    };
+
+    Ok(format!(
+        "{} found at {}{}",
+        command.name,
+        path.to_string_lossy(),
+        version
+            .map(|version| format!(" ({})", version))
+            .unwrap_or_default()
+    ))
+}
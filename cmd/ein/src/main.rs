@@ -1,12 +1,21 @@
 mod build;
 mod compile_configuration;
+mod doc;
+mod doctor;
 mod file_path_configuration;
 mod init;
+mod package_file_finder;
 mod package_initialization_configuration;
+mod prelude_package_files;
+mod repl;
 mod system_package_configuration;
+mod watch;
 
 use build::build;
+use doc::doc;
+use doctor::doctor;
 use init::init;
+use repl::repl;
 
 fn main() {
     if let Err(error) = run() {
@@ -19,7 +28,68 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
     match clap::App::new("ein")
         .version("0.1.0")
         .setting(clap::AppSettings::SubcommandRequired)
-        .subcommand(clap::SubCommand::with_name("build").about("Builds a package"))
+        .subcommand(
+            clap::SubCommand::with_name("build")
+                .arg(
+                    clap::Arg::with_name("profile")
+                        .long("profile")
+                        .takes_value(true)
+                        .possible_values(&["dev", "release"])
+                        .default_value("release")
+                        .help("Specifies a build profile"),
+                )
+                .arg(
+                    clap::Arg::with_name("backend")
+                        .long("backend")
+                        .takes_value(true)
+                        .possible_values(&["llvm", "c"])
+                        .default_value("llvm")
+                        .help("Specifies a code generation backend"),
+                )
+                .arg(
+                    clap::Arg::with_name("target")
+                        .long("target")
+                        .takes_value(true)
+                        .help("Specifies a target triple for cross-compilation"),
+                )
+                .arg(
+                    clap::Arg::with_name("sysroot")
+                        .long("sysroot")
+                        .takes_value(true)
+                        .requires("target")
+                        .help("Specifies a system root directory of a target"),
+                )
+                .arg(
+                    clap::Arg::with_name("emit")
+                        .long("emit")
+                        .takes_value(true)
+                        .use_delimiter(true)
+                        .possible_values(&["ast", "typed-ast", "eir", "fmm", "llvm-ir", "asm"])
+                        .help("Emits intermediate representations of modules"),
+                )
+                .arg(
+                    clap::Arg::with_name("watch")
+                        .short("w")
+                        .long("watch")
+                        .help("Rebuilds a package on changes of source files"),
+                )
+                .about("Builds a package"),
+        )
+        .subcommand(
+            clap::SubCommand::with_name("doc")
+                .arg(
+                    clap::Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&["html", "markdown"])
+                        .default_value("html")
+                        .help("Specifies a documentation format"),
+                )
+                .about("Generates documentation of a package"),
+        )
+        .subcommand(
+            clap::SubCommand::with_name("doctor").about("Diagnoses a toolchain environment"),
+        )
         .subcommand(
             clap::SubCommand::with_name("init")
                 .arg(
@@ -35,10 +105,38 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 )
                 .about("Initializes a package"),
         )
+        .subcommand(
+            clap::SubCommand::with_name("repl")
+                .about("Evaluates expressions interactively in a package"),
+        )
         .get_matches()
         .subcommand()
     {
-        ("build", _) => build(),
+        ("build", matches) => {
+            let matches = matches.unwrap();
+
+            build(
+                matches.value_of("profile").unwrap().parse().unwrap(),
+                matches.value_of("target").map(|triple| {
+                    app::TargetConfiguration::new(
+                        triple,
+                        matches.value_of("sysroot").map(From::from),
+                    )
+                }),
+                matches.value_of("backend").unwrap().parse().unwrap(),
+                &matches
+                    .values_of("emit")
+                    .map(|values| values.map(|value| value.parse().unwrap()).collect())
+                    .unwrap_or_else(Vec::new),
+                matches.is_present("watch"),
+            )
+        }
+        ("doc", matches) => {
+            let matches = matches.unwrap();
+
+            doc(matches.value_of("format").unwrap().parse().unwrap())
+        }
+        ("doctor", _) => doctor(),
         ("init", matches) => {
             let matches = matches.unwrap();
 
@@ -47,6 +145,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 matches.is_present("lib"),
             )
         }
+        ("repl", _) => repl(),
         _ => unreachable!(),
     }
 }
@@ -0,0 +1,30 @@
+use std::path::{Path, PathBuf};
+
+// Build artifacts of FFI crates and packages are not part of packages.
+const IGNORED_DIRECTORIES: &[&str] = &["target", ".ein"];
+
+// Package files are found by the build script to embed them and by the `ein`
+// command to read them from source trees.
+pub fn find_package_files(directory: &Path) -> Result<Vec<PathBuf>, std::io::Error> {
+    let mut paths = vec![];
+
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            if !IGNORED_DIRECTORIES
+                .iter()
+                .any(|name| path.file_name() == Some(name.as_ref()))
+            {
+                paths.extend(find_package_files(&path)?);
+            }
+        } else {
+            paths.push(path);
+        }
+    }
+
+    // Files are sorted to make builds reproducible.
+    paths.sort();
+
+    Ok(paths)
+}
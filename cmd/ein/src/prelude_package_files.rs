@@ -0,0 +1,65 @@
+use super::package_file_finder::find_package_files;
+
+const PRELUDE_PACKAGE_DIRECTORY: &str = "lib/prelude";
+const FFI_LIBRARY_DIRECTORY: &str = "lib/ffi";
+
+// Files in the prelude package and the FFI library are listed by the build
+// script so that the `ein` command can build packages without the source tree.
+const PRELUDE_PACKAGE_FILES: &[(&[&str], &[u8])] =
+    include!(concat!(env!("OUT_DIR"), "/prelude_package_files.rs"));
+const FFI_LIBRARY_FILES: &[(&[&str], &[u8])] =
+    include!(concat!(env!("OUT_DIR"), "/ffi_library_files.rs"));
+
+type EmbeddedFiles = Vec<(app::FilePath, Vec<u8>)>;
+
+pub fn read_prelude_package_files(
+    root_directory: Option<&std::path::Path>,
+) -> Result<EmbeddedFiles, Box<dyn std::error::Error>> {
+    read_files(
+        PRELUDE_PACKAGE_FILES,
+        PRELUDE_PACKAGE_DIRECTORY,
+        root_directory,
+    )
+}
+
+pub fn read_ffi_library_files(
+    root_directory: Option<&std::path::Path>,
+) -> Result<EmbeddedFiles, Box<dyn std::error::Error>> {
+    read_files(FFI_LIBRARY_FILES, FFI_LIBRARY_DIRECTORY, root_directory)
+}
+
+// Files are read from source trees instead when they are given so that the
+// prelude package can be developed without rebuilding the `ein` command.
+fn read_files(
+    files: &[(&[&str], &[u8])],
+    directory: &str,
+    root_directory: Option<&std::path::Path>,
+) -> Result<EmbeddedFiles, Box<dyn std::error::Error>> {
+    if let Some(root_directory) = root_directory {
+        let directory = root_directory.join(directory);
+
+        find_package_files(&directory)
+            .map_err(|source| infra::InfrastructureError::ReadDirectory {
+                path: directory.clone(),
+                source,
+            })?
+            .into_iter()
+            .map(|path| {
+                Ok((
+                    app::FilePath::new(
+                        path.strip_prefix(&directory)?
+                            .components()
+                            .map(|component| component.as_os_str().to_string_lossy()),
+                    ),
+                    std::fs::read(&path)
+                        .map_err(|source| infra::InfrastructureError::ReadFile { path, source })?,
+                ))
+            })
+            .collect()
+    } else {
+        Ok(files
+            .iter()
+            .map(|(components, data)| (app::FilePath::new(components.iter()), data.to_vec()))
+            .collect())
+    }
+}
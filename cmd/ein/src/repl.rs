@@ -0,0 +1,164 @@
+use super::{
+    build::{find_package_directory, find_root_directory},
+    compile_configuration::COMPILE_CONFIGURATION,
+    file_path_configuration::FILE_PATH_CONFIGURATION,
+    prelude_package_files::{read_ffi_library_files, read_prelude_package_files},
+    system_package_configuration::SYSTEM_PACKAGE_CONFIGURATION,
+};
+use std::io::{BufRead, Write};
+
+const PROMPT: &str = "> ";
+const CONTINUATION_PROMPT: &str = "| ";
+const SOURCE_NAME: &str = "<repl>";
+
+pub fn repl() -> Result<(), Box<dyn std::error::Error>> {
+    let package_directory = find_package_directory()?;
+
+    let logger = infra::Logger::new();
+
+    let file_path_converter = infra::FilePathConverter::new(package_directory);
+    let file_system = infra::FileSystem::new(&file_path_converter);
+    let static_file_path_manager = app::StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+    let file_path_resolver =
+        app::FilePathResolver::new(&static_file_path_manager, &FILE_PATH_CONFIGURATION);
+    let file_path_displayer = infra::FilePathDisplayer::new(&file_path_converter);
+
+    let command_runner = infra::CommandRunner::new();
+    let module_parser = app::ModuleParser::new(&file_path_displayer);
+    let module_compiler = app::ModuleCompiler::new(
+        &module_parser,
+        &file_path_resolver,
+        &file_system,
+        &logger,
+        COMPILE_CONFIGURATION.clone(),
+        &FILE_PATH_CONFIGURATION,
+        &[],
+    );
+    let modules_finder = app::ModulesFinder::new(&file_system, &FILE_PATH_CONFIGURATION);
+    let modules_builder = app::ModulesBuilder::new(
+        &module_parser,
+        &module_compiler,
+        &modules_finder,
+        &file_system,
+        &file_path_resolver,
+    );
+
+    let package_configuration_reader = app::PackageConfigurationReader::new(
+        &file_system,
+        &file_path_displayer,
+        &static_file_path_manager,
+    );
+    let ffi_package_initializer =
+        infra::FfiPackageInitializer::new(&command_runner, &file_path_converter);
+    let ffi_package_builder = infra::FfiPackageBuilder::new(
+        &command_runner,
+        &file_path_converter,
+        &file_system,
+        &static_file_path_manager,
+        None,
+    );
+    let foreign_library_loader = infra::ForeignLibraryLoader::new(
+        &command_runner,
+        &file_path_converter,
+        &static_file_path_manager,
+    );
+    let package_builder = app::PackageBuilder::new(
+        &modules_builder,
+        &ffi_package_initializer,
+        &ffi_package_builder,
+        Some(&foreign_library_loader),
+        &file_system,
+        &logger,
+    );
+
+    let root_directory = find_root_directory();
+    let prelude_package_files =
+        read_prelude_package_files(root_directory.as_deref().map(std::path::Path::new))?;
+    let ffi_library_files =
+        read_ffi_library_files(root_directory.as_deref().map(std::path::Path::new))?;
+    let prelude_package_builder = app::PreludePackageBuilder::new(
+        &package_configuration_reader,
+        &package_builder,
+        &file_system,
+        &static_file_path_manager,
+        &prelude_package_files,
+        &ffi_library_files,
+    );
+    let external_package_downloader = infra::ExternalPackageDownloader::new(&file_path_converter);
+    let cached_external_package_downloader = app::CachedExternalPackageDownloader::new(
+        &package_configuration_reader,
+        &external_package_downloader,
+        &file_system,
+        &file_path_resolver,
+        &logger,
+    );
+    let external_packages_downloader =
+        app::ExternalPackagesDownloader::new(&cached_external_package_downloader);
+    let external_packages_builder = app::ExternalPackagesBuilder::new(&package_builder);
+    let system_package_builder =
+        app::SystemPackageBuilder::new(&package_builder, &cached_external_package_downloader);
+    let main_package_loader = app::MainPackageLoader::new(
+        &package_configuration_reader,
+        &package_builder,
+        &prelude_package_builder,
+        &system_package_builder,
+        &external_packages_downloader,
+        &external_packages_builder,
+        &SYSTEM_PACKAGE_CONFIGURATION,
+    );
+    let repl_initializer =
+        app::ReplInitializer::new(&main_package_loader, COMPILE_CONFIGURATION.clone());
+
+    let mut repl = repl_initializer.initialize(lang::Interpreter::new())?;
+
+    while let Some(source) = read_input()? {
+        if source.trim().is_empty() {
+            continue;
+        }
+
+        match lang::parse_repl_input(&source, SOURCE_NAME)
+            .map_err(Box::<dyn std::error::Error>::from)
+            .and_then(|input| Ok(repl.evaluate(&input)?))
+        {
+            Ok(Some(output)) => println!("{}", output),
+            Ok(None) => {}
+            Err(error) => logger.log_error(error.as_ref())?,
+        }
+    }
+
+    Ok(())
+}
+
+// Lines ending with backslashes are continued to next ones.
+fn read_input() -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let mut source = String::new();
+    let mut prompt = PROMPT;
+
+    loop {
+        print!("{}", prompt);
+        std::io::stdout().flush()?;
+
+        let mut line = String::new();
+
+        if std::io::stdin().lock().read_line(&mut line)? == 0 {
+            return Ok(if source.is_empty() {
+                None
+            } else {
+                Some(source)
+            });
+        }
+
+        match line.trim_end().strip_suffix('\\') {
+            Some(line) => {
+                source.push_str(line);
+                source.push('\n');
+                prompt = CONTINUATION_PROMPT;
+            }
+            None => {
+                source.push_str(&line);
+
+                return Ok(Some(source));
+            }
+        }
+    }
+}
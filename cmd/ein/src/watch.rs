@@ -0,0 +1,205 @@
+use super::file_path_configuration::FILE_PATH_CONFIGURATION;
+use app::FileSystem;
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    time::{Duration, Instant, SystemTime},
+};
+
+const POLLING_INTERVAL: Duration = Duration::from_millis(200);
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(300);
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+type Snapshot = BTreeMap<app::FilePath, SystemTime>;
+
+// Files are polled instead of being watched with OS-specific APIs.
+pub fn watch(
+    directory: &Path,
+    mut build: impl FnMut() -> Result<(), Box<dyn std::error::Error>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let logger = infra::Logger::new();
+    let file_path_converter = infra::FilePathConverter::new(directory);
+    let file_system = infra::FileSystem::new(&file_path_converter);
+    let mut debouncer = Debouncer::new(DEBOUNCE_DURATION);
+    let mut snapshot = take_snapshot(&file_system, &app::FilePath::empty())?;
+
+    loop {
+        print!("{}", CLEAR_SCREEN);
+
+        if let Err(error) = build() {
+            logger.log_error(error.as_ref())?;
+        }
+
+        loop {
+            std::thread::sleep(POLLING_INTERVAL);
+
+            let new_snapshot = take_snapshot(&file_system, &app::FilePath::empty())?;
+
+            if new_snapshot != snapshot {
+                snapshot = new_snapshot;
+                debouncer.notify(Instant::now());
+            }
+
+            if debouncer.poll(Instant::now()) {
+                break;
+            }
+        }
+    }
+}
+
+// Dot directories are ignored like the ones of output files. Files removed
+// while snapshots are taken are skipped.
+fn take_snapshot(
+    file_system: &dyn FileSystem,
+    directory: &app::FilePath,
+) -> Result<Snapshot, Box<dyn std::error::Error>> {
+    let mut snapshot = Snapshot::new();
+
+    for path in skip_not_found(file_system.read_directory(directory))?.unwrap_or_default() {
+        if path
+            .components()
+            .last()
+            .map(|name| name.starts_with('.'))
+            .unwrap_or_default()
+        {
+            continue;
+        }
+
+        if file_system.is_directory(&path) {
+            snapshot.extend(take_snapshot(file_system, &path)?);
+        } else if is_watched_file(&path) {
+            if let Some(time) = skip_not_found(file_system.read_modified_time(&path))? {
+                snapshot.insert(path, time);
+            }
+        }
+    }
+
+    Ok(snapshot)
+}
+
+fn skip_not_found<T>(
+    result: Result<T, Box<dyn std::error::Error>>,
+) -> Result<Option<T>, Box<dyn std::error::Error>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error)
+            if error
+                .source()
+                .and_then(|source| source.downcast_ref::<std::io::Error>())
+                .map(|error| error.kind())
+                == Some(std::io::ErrorKind::NotFound) =>
+        {
+            Ok(None)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+fn is_watched_file(path: &app::FilePath) -> bool {
+    path.has_extension(FILE_PATH_CONFIGURATION.source_file_extension)
+        || path.components().last() == Some(FILE_PATH_CONFIGURATION.build_configuration_filename)
+}
+
+// Debouncers fire only after no change is notified for their durations.
+struct Debouncer {
+    duration: Duration,
+    last_change_time: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            last_change_time: None,
+        }
+    }
+
+    pub fn notify(&mut self, time: Instant) {
+        self.last_change_time = Some(time);
+    }
+
+    pub fn poll(&mut self, time: Instant) -> bool {
+        match self.last_change_time {
+            Some(last_change_time) if time.duration_since(last_change_time) >= self.duration => {
+                self.last_change_time = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fire_debouncer_after_duration() {
+        let time = Instant::now();
+        let mut debouncer = Debouncer::new(Duration::from_millis(100));
+
+        assert!(!debouncer.poll(time));
+
+        debouncer.notify(time);
+
+        assert!(!debouncer.poll(time + Duration::from_millis(50)));
+        assert!(debouncer.poll(time + Duration::from_millis(100)));
+        assert!(!debouncer.poll(time + Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn delay_debouncer_on_changes() {
+        let time = Instant::now();
+        let mut debouncer = Debouncer::new(Duration::from_millis(100));
+
+        debouncer.notify(time);
+        debouncer.notify(time + Duration::from_millis(80));
+
+        assert!(!debouncer.poll(time + Duration::from_millis(120)));
+        assert!(debouncer.poll(time + Duration::from_millis(180)));
+    }
+
+    #[test]
+    fn take_snapshot_of_watched_files() {
+        let directory = std::env::temp_dir().join(format!("ein-watch-{}", std::process::id()));
+        let file_path_converter = infra::FilePathConverter::new(&directory);
+        let file_system = infra::FileSystem::new(&file_path_converter);
+
+        for path in &[
+            app::FilePath::new(&["ein.json"]),
+            app::FilePath::new(&["Foo", "Bar.ein"]),
+            app::FilePath::new(&["README.md"]),
+            app::FilePath::new(&[".ein", "Baz.ein"]),
+        ] {
+            file_system.write(path, &[]).unwrap();
+        }
+
+        let snapshot = take_snapshot(&file_system, &app::FilePath::empty()).unwrap();
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            snapshot.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                app::FilePath::new(&["Foo", "Bar.ein"]),
+                app::FilePath::new(&["ein.json"])
+            ]
+        );
+    }
+
+    #[test]
+    fn take_snapshot_of_missing_directory() {
+        let directory =
+            std::env::temp_dir().join(format!("ein-watch-missing-{}", std::process::id()));
+        let file_path_converter = infra::FilePathConverter::new(&directory);
+
+        assert_eq!(
+            take_snapshot(
+                &infra::FileSystem::new(&file_path_converter),
+                &app::FilePath::empty()
+            )
+            .unwrap(),
+            Snapshot::new()
+        );
+    }
+}
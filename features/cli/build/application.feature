@@ -7,6 +7,79 @@ Feature: Application
     When I successfully run `ein build`
     Then I successfully run `sh -c ./foo`
 
+  Scenario: Build an application with a dev profile
+    When I successfully run `ein build --profile dev`
+    Then I successfully run `sh -c ./foo`
+
+  Scenario: Build an application with debug information
+    When I successfully run `ein build --profile dev`
+    Then I successfully run `sh -c "readelf --debug-dump=info foo | grep Main.ein"`
+
+  Scenario: Build an application with the C backend
+    Given a file named "ein.json" with:
+    """
+    {
+      "application": {
+        "name": "foo",
+        "system": {
+          "name": "github.com/ein-lang/os",
+          "version": "main"
+        }
+      },
+      "dependencies": {},
+      "profiles": {
+        "release": { "tailCallOptimization": false }
+      }
+    }
+    """
+    When I successfully run `ein build --backend c`
+    Then I successfully run `sh -c ./foo`
+
+  Scenario: Fail to build an application with the C backend and guaranteed tail calls
+    When I run `ein build --backend c`
+    Then stderr from "ein build --backend c" should contain "C backend cannot guarantee tail calls"
+    And the exit status should not be 0
+
+  Scenario: Build an application with a custom profile
+    Given a file named "ein.json" with:
+    """
+    {
+      "application": {
+        "name": "foo",
+        "system": {
+          "name": "github.com/ein-lang/os",
+          "version": "main"
+        }
+      },
+      "dependencies": {},
+      "profiles": {
+        "release": { "optimizationLevel": 1, "strip": true }
+      }
+    }
+    """
+    When I successfully run `ein build`
+    Then I successfully run `sh -c ./foo`
+
+  Scenario: Build an application with link-time optimization
+    Given a file named "ein.json" with:
+    """
+    {
+      "application": {
+        "name": "foo",
+        "system": {
+          "name": "github.com/ein-lang/os",
+          "version": "main"
+        }
+      },
+      "dependencies": {},
+      "profiles": {
+        "release": { "linkTimeOptimization": true }
+      }
+    }
+    """
+    When I successfully run `ein build`
+    Then I successfully run `sh -c ./foo`
+
   Scenario: Build an application with a dependency
     Given a file named "ein.json" with:
     """
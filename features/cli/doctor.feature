@@ -0,0 +1,5 @@
+Feature: Doctor
+  Scenario: Diagnose an environment
+    When I run `ein doctor`
+    Then the exit status should be 0
+    And stderr from "ein doctor" should contain "clang found"
@@ -0,0 +1,45 @@
+Feature: REPL
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Call foreign functions in the prelude package
+    When I run `ein repl` interactively
+    And I type "numberToString 42"
+    And I close the stdin stream
+    Then the exit status should be 0
+    And the output should contain:
+    """
+    "42" : String
+    """
+
+  Scenario: Call foreign functions in C sources of a package
+    Given a file named "ein.json" with:
+    """
+    {
+      "dependencies": {},
+      "ffi": {
+        "c": ["foo.c"]
+      }
+    }
+    """
+    And a file named "foo.c" with:
+    """
+    double foo(double x) { return 2 * x; }
+    """
+    And a file named "Foo.ein" with:
+    """
+    export { bar }
+
+    import foreign "c" foo : Number -> Number
+
+    bar : Number -> Number
+    bar x = foo x
+    """
+    When I run `ein repl` interactively
+    And I type "Foo.bar 21"
+    And I close the stdin stream
+    Then the exit status should be 0
+    And the output should contain:
+    """
+    42 : Number
+    """
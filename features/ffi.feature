@@ -12,3 +12,29 @@ Feature: FFI
     """
     When I run `ein build`
     Then the exit status should be 0
+
+  Scenario: Build C sources declared in a package configuration
+    Given a file named "ein.json" with:
+    """
+    {
+      "dependencies": {},
+      "ffi": {
+        "c": ["foo.c"],
+        "libraries": ["m"]
+      }
+    }
+    """
+    And a file named "foo.c" with:
+    """
+    double foo(double x) { return x; }
+    """
+    And a file named "Foo.ein" with:
+    """
+    import foreign "c" foo : Number -> Number
+
+    bar : Number -> Number
+    bar x = foo x
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+    And a file named "foo.o" should not exist
@@ -114,3 +114,95 @@ Feature: Module
     """
     When I run `ein build`
     Then the exit status should be 0
+
+  Scenario: Import an opaque type
+    Given a file named "Foo.ein" with:
+    """
+    export { opaque Foo, foo }
+
+    type Foo { foo : Number }
+
+    foo : Foo
+    foo = Foo{ foo = 42 }
+    """
+    And a file named "Bar.ein" with:
+    """
+    import "/Foo"
+
+    bar : Foo.Foo
+    bar = Foo.foo
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Fail to construct a record of an opaque type
+    Given a file named "Foo.ein" with:
+    """
+    export { opaque Foo }
+
+    type Foo { foo : Number }
+    """
+    And a file named "Bar.ein" with:
+    """
+    import "/Foo"
+
+    bar : Foo.Foo
+    bar = Foo.Foo{ foo = 42 }
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
+
+  Scenario: Fail to update a record of an opaque type
+    Given a file named "Foo.ein" with:
+    """
+    export { opaque Foo }
+
+    type Foo { foo : Number }
+    """
+    And a file named "Bar.ein" with:
+    """
+    import "/Foo"
+
+    bar : Foo.Foo -> Foo.Foo
+    bar x = Foo.Foo{ ...x, foo = 42 }
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
+
+  Scenario: Fail to deconstruct a record of an opaque type
+    Given a file named "Foo.ein" with:
+    """
+    export { opaque Foo }
+
+    type Foo { foo : Number }
+    """
+    And a file named "Bar.ein" with:
+    """
+    import "/Foo"
+
+    bar : Foo.Foo -> Number
+    bar x =
+      let
+        Foo.Foo{ foo = y } = x
+      in
+        y
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
+
+  Scenario: Fail to read an element of a record of an opaque type
+    Given a file named "Foo.ein" with:
+    """
+    export { opaque Foo }
+
+    type Foo { foo : Number }
+    """
+    And a file named "Bar.ein" with:
+    """
+    import "/Foo"
+
+    bar : Foo.Foo -> Number
+    bar x = Foo.Foo.foo x
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
@@ -0,0 +1,39 @@
+Feature: Array
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Create an empty array
+    Given a file named "Foo.ein" with:
+    """
+    foo : Array Number
+    foo = [||]
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Create an array with elements
+    Given a file named "Foo.ein" with:
+    """
+    foo : Array Number
+    foo = [| 1, 2, 3 |]
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Get an element in an array
+    Given a file named "Foo.ein" with:
+    """
+    foo : Number | None
+    foo = getArray 2 (setArray 2 42 [| 1, 2, 3 |])
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Convert an array into a list
+    Given a file named "Foo.ein" with:
+    """
+    foo : List Number
+    foo = arrayToList (concatenateArrays (listToArray [1, 2]) [| 3 |])
+    """
+    When I run `ein build`
+    Then the exit status should be 0
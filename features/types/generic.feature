@@ -0,0 +1,49 @@
+Feature: Generic
+  Background:
+    Given I successfully run `ein init foo`
+    And I cd to "foo"
+
+  Scenario: Run a program with a generic function and a generic record
+    Given a file named "Main.ein" with:
+    """
+    import "github.com/ein-lang/os/Os"
+
+    type Pair a b {
+      first : a,
+      second : b,
+    }
+
+    identity : a -> a
+    identity x = x
+
+    swap : Pair a b -> Pair b a
+    swap pair = Pair{ first = Pair.second pair, second = Pair.first pair }
+
+    mapList : (a -> b) -> List a -> List b
+    mapList f xs =
+      case xs
+        [] => []
+        [x, ...xs] => [f x, ...mapList f xs]
+
+    sum : List Number -> Number
+    sum xs =
+      case xs
+        [] => 0
+        [x, ...xs] => x + sum xs
+
+    main : Os.Os -> Number
+    main os =
+      let
+        pair = swap (Pair{ first = "foo", second = identity 42 })
+      in
+        if
+          Pair.first pair == 42
+            && Pair.second pair == "foo"
+            && sum (mapList (\x -> x * 2) [1, 2, 3]) == 12
+        then
+          0
+        else
+          1
+    """
+    When I successfully run `ein build`
+    Then I successfully run `sh -c ./foo`
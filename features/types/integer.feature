@@ -0,0 +1,115 @@
+Feature: Integer
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Use an integer literal
+    Given a file named "Foo.ein" with:
+    """
+    foo : Integer
+    foo = 42i
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Use integer literals with radix prefixes
+    Given a file named "Foo.ein" with:
+    """
+    foo : List Integer
+    foo = [ 0xFFi, 0o17i, 0b1010i, 1_000i ]
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Use arithmetic operations
+    Given a file named "Foo.ein" with:
+    """
+    foo : Integer -> Integer
+    foo x = (x + 1i) * 2i // 3i % 4i
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Convert integers and numbers
+    Given a file named "Foo.ein" with:
+    """
+    foo : Number
+    foo = integerToNumber (numberToInteger 42)
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Use integers as map keys
+    Given a file named "Foo.ein" with:
+    """
+    foo : Map Integer String
+    foo = { 42i: "foo" }
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Fail to mix integers and numbers in arithmetic operations
+    Given a file named "Foo.ein" with:
+    """
+    foo : Number
+    foo = 1i + 1
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
+
+  Scenario: Fail to divide integers with a number division operator
+    Given a file named "Foo.ein" with:
+    """
+    foo : Integer -> Integer
+    foo x = x / 2i
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
+
+  Scenario: Fail to use an integer literal out of range
+    Given a file named "Foo.ein" with:
+    """
+    foo : Integer
+    foo = 9223372036854775808i
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
+
+  Scenario: Evaluate integer literals near zero
+    Given I successfully run `ein init foo`
+    And I cd to "foo"
+    And a file named "Main.ein" with:
+    """
+    import "github.com/ein-lang/os/Os"
+
+    main : Os.Os -> Number
+    main os =
+      if 0i + 1i == 1i && 1i - 1i == 0i && 2i * 2i == 4i && numberToInteger 1 == 1i then
+        0
+      else
+        1
+    """
+    When I successfully run `ein build`
+    Then I successfully run `sh -c ./foo`
+
+  Scenario: Evaluate negative integers
+    Given I successfully run `ein init foo`
+    And I cd to "foo"
+    And a file named "Main.ein" with:
+    """
+    import "github.com/ein-lang/os/Os"
+
+    main : Os.Os -> Number
+    main os =
+      if
+        -1i + 1i == 0i
+          && -7i // 2i == -3i
+          && -7i % 2i == -1i
+          && -9223372036854775808i - 1i == 9223372036854775807i
+          && integerToNumber -42i == -42
+      then
+        0
+      else
+        1
+    """
+    When I successfully run `ein build`
+    Then I successfully run `sh -c ./foo`
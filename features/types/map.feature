@@ -0,0 +1,52 @@
+Feature: Map
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Create an empty map
+    Given a file named "Foo.ein" with:
+    """
+    foo : Map String Number
+    foo = {}
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Create a map with entries
+    Given a file named "Foo.ein" with:
+    """
+    foo : Map String Number
+    foo = { "foo": 1, "bar": 2 }
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Look up a value in a map
+    Given a file named "Foo.ein" with:
+    """
+    foo : Number | None
+    foo = lookupMap 42 (insertToMap 42 13 {})
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Use records as map keys
+    Given a file named "Foo.ein" with:
+    """
+    type Bar {
+      bar : Number,
+    }
+
+    foo : Map Bar String
+    foo = { Bar{ bar = 42 }: "foo" }
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Fail to use functions as map keys
+    Given a file named "Foo.ein" with:
+    """
+    foo : Map (Number -> Number) Number
+    foo = {}
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
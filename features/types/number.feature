@@ -0,0 +1,39 @@
+Feature: Number
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Use number literals
+    Given a file named "Foo.ein" with:
+    """
+    foo : List Number
+    foo = [ 3.14, -42, 1e-9, 1_000_000 ]
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Use number literals with radix prefixes
+    Given a file named "Foo.ein" with:
+    """
+    foo : List Number
+    foo = [ 0xFF, 0o17, 0b1010 ]
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Fail to use a malformed number literal
+    Given a file named "Foo.ein" with:
+    """
+    foo : Number
+    foo = 0x
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
+
+  Scenario: Fail to use a number literal out of range
+    Given a file named "Foo.ein" with:
+    """
+    foo : Number
+    foo = 1e999
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
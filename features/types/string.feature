@@ -28,3 +28,49 @@ Feature: String
     """
     When I run `ein build`
     Then the exit status should be 0
+
+  Scenario: Interpolate values into a string
+    Given a file named "Foo.ein" with:
+    """
+    foo : String -> Number -> String
+    foo name count = "user #{name} has #{count} items"
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Use escape sequences
+    Given a file named "Foo.ein" with:
+    """
+    foo : String
+    foo = "\u{1F600}\r\0\x41\#{"
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Create a multi-line raw string
+    Given a file named "Foo.ein" with:
+    """
+    foo : String
+    foo = `foo
+      bar\n`
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Fail to interpolate a list into a string
+    Given a file named "Foo.ein" with:
+    """
+    foo : String
+    foo = "#{[1]}"
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
+
+  Scenario: Fail to use an invalid escape sequence
+    Given a file named "Foo.ein" with:
+    """
+    foo : String
+    foo = "\q"
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
@@ -1,29 +1,39 @@
 use super::{
     json_application_build_configuration::JsonApplicationBuildConfiguration,
     json_external_package_configuration::JsonExternalPackageConfiguration,
+    json_ffi_configuration::{JsonFfiConfiguration, JsonFfiSource},
+    json_profile_configuration::{JsonProfileConfiguration, JsonProfileName},
     json_system_package_configuration::JsonSystemPackageConfiguration,
 };
 use crate::{
-    common::{ApplicationTarget, BuildConfiguration},
+    common::{ApplicationTarget, BuildConfiguration, FfiConfiguration, FfiSource, FilePath},
     ExternalPackage, Target,
 };
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{de::Error, Deserialize, Serialize};
+use std::{collections::HashMap, path::Component};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JsonBuildConfiguration {
     application: Option<JsonApplicationBuildConfiguration>,
     dependencies: HashMap<String, JsonExternalPackageConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ffi: Option<JsonFfiConfiguration>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    profiles: HashMap<JsonProfileName, JsonProfileConfiguration>,
 }
 
 impl JsonBuildConfiguration {
     pub fn new(
         application: Option<JsonApplicationBuildConfiguration>,
         dependencies: HashMap<String, JsonExternalPackageConfiguration>,
+        ffi: Option<JsonFfiConfiguration>,
+        profiles: HashMap<JsonProfileName, JsonProfileConfiguration>,
     ) -> Self {
         Self {
             application,
             dependencies,
+            ffi,
+            profiles,
         }
     }
 
@@ -49,11 +59,33 @@ impl JsonBuildConfiguration {
                     )
                 })
                 .collect(),
+            configuration.ffi().map(|ffi| {
+                JsonFfiConfiguration::new(
+                    match ffi.source() {
+                        FfiSource::Cargo(path) => JsonFfiSource::Cargo(format!("{}", path)),
+                        FfiSource::C(paths) => JsonFfiSource::C(serialize_file_paths(paths)),
+                        FfiSource::Archives(paths) => {
+                            JsonFfiSource::Archives(serialize_file_paths(paths))
+                        }
+                    },
+                    ffi.libraries().to_vec(),
+                )
+            }),
+            configuration
+                .profiles()
+                .iter()
+                .map(|(&name, profile)| {
+                    (
+                        JsonProfileName::serialize(name),
+                        JsonProfileConfiguration::serialize(profile),
+                    )
+                })
+                .collect(),
         )
     }
 
-    pub fn deserialize(&self) -> BuildConfiguration {
-        BuildConfiguration::new(
+    pub fn deserialize(&self) -> Result<BuildConfiguration, serde_json::Error> {
+        Ok(BuildConfiguration::new(
             self.application
                 .as_ref()
                 .map(|application| {
@@ -71,6 +103,68 @@ impl JsonBuildConfiguration {
                 .iter()
                 .map(|(name, configuration)| ExternalPackage::new(name, configuration.version()))
                 .collect(),
-        )
+            self.ffi
+                .as_ref()
+                .map(|ffi| -> Result<_, serde_json::Error> {
+                    Ok(FfiConfiguration::new(
+                        match ffi.source() {
+                            JsonFfiSource::Cargo(path) => {
+                                FfiSource::Cargo(deserialize_file_path(path)?)
+                            }
+                            JsonFfiSource::C(paths) => FfiSource::C(deserialize_file_paths(paths)?),
+                            JsonFfiSource::Archives(paths) => {
+                                FfiSource::Archives(deserialize_file_paths(paths)?)
+                            }
+                        },
+                        ffi.libraries().to_vec(),
+                    ))
+                })
+                .transpose()?,
+            self.profiles
+                .iter()
+                .map(|(name, profile)| {
+                    let name = name.deserialize();
+
+                    (name, profile.deserialize(name))
+                })
+                .collect(),
+        ))
     }
 }
+
+fn serialize_file_paths(paths: &[FilePath]) -> Vec<String> {
+    paths.iter().map(|path| format!("{}", path)).collect()
+}
+
+// Paths in package configurations are relative to package directories.
+fn deserialize_file_path(path: &str) -> Result<FilePath, serde_json::Error> {
+    let path = std::path::Path::new(path);
+
+    if path.is_absolute()
+        || path.components().any(|component| {
+            matches!(
+                component,
+                Component::Prefix(_) | Component::RootDir | Component::ParentDir
+            )
+        })
+    {
+        return Err(serde_json::Error::custom(format!(
+            "path outside package directory: {}",
+            path.display()
+        )));
+    }
+
+    Ok(FilePath::new(path.components().filter_map(
+        |component| match component {
+            Component::Normal(component) => Some(component.to_string_lossy()),
+            _ => None,
+        },
+    )))
+}
+
+fn deserialize_file_paths(paths: &[String]) -> Result<Vec<FilePath>, serde_json::Error> {
+    paths
+        .iter()
+        .map(|path| deserialize_file_path(path))
+        .collect()
+}
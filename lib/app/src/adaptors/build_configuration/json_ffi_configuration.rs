@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonFfiSource {
+    Cargo(String),
+    C(Vec<String>),
+    Archives(Vec<String>),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct JsonFfiConfiguration {
+    #[serde(flatten)]
+    source: JsonFfiSource,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    libraries: Vec<String>,
+}
+
+impl JsonFfiConfiguration {
+    pub fn new(source: JsonFfiSource, libraries: Vec<String>) -> Self {
+        Self { source, libraries }
+    }
+
+    pub fn source(&self) -> &JsonFfiSource {
+        &self.source
+    }
+
+    pub fn libraries(&self) -> &[String] {
+        &self.libraries
+    }
+}
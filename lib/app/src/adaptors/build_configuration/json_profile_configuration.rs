@@ -0,0 +1,89 @@
+use crate::common::{OptimizationLevel, Profile, ProfileName};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonProfileName {
+    Dev,
+    Release,
+}
+
+impl JsonProfileName {
+    pub fn serialize(name: ProfileName) -> Self {
+        match name {
+            ProfileName::Dev => Self::Dev,
+            ProfileName::Release => Self::Release,
+        }
+    }
+
+    pub fn deserialize(&self) -> ProfileName {
+        match self {
+            Self::Dev => ProfileName::Dev,
+            Self::Release => ProfileName::Release,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub struct JsonOptimizationLevel(OptimizationLevel);
+
+impl TryFrom<u8> for JsonOptimizationLevel {
+    type Error = String;
+
+    fn try_from(level: u8) -> Result<Self, String> {
+        Ok(Self(OptimizationLevel::try_from(level)?))
+    }
+}
+
+impl From<JsonOptimizationLevel> for u8 {
+    fn from(level: JsonOptimizationLevel) -> Self {
+        level.0.into()
+    }
+}
+
+// All fields are optional and default to ones of built-in profiles.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonProfileConfiguration {
+    optimization_level: Option<JsonOptimizationLevel>,
+    tail_call_optimization: Option<bool>,
+    debug_information: Option<bool>,
+    strip: Option<bool>,
+    link_time_optimization: Option<bool>,
+    linker_flags: Option<Vec<String>>,
+}
+
+impl JsonProfileConfiguration {
+    pub fn serialize(profile: &Profile) -> Self {
+        Self {
+            optimization_level: Some(JsonOptimizationLevel(profile.optimization_level())),
+            tail_call_optimization: Some(profile.tail_call_optimization()),
+            debug_information: Some(profile.debug_information()),
+            strip: Some(profile.strip()),
+            link_time_optimization: Some(profile.link_time_optimization()),
+            linker_flags: Some(profile.linker_flags().to_vec()),
+        }
+    }
+
+    pub fn deserialize(&self, name: ProfileName) -> Profile {
+        let profile = Profile::default_of(name);
+
+        Profile::new(
+            self.optimization_level
+                .map(|level| level.0)
+                .unwrap_or_else(|| profile.optimization_level()),
+            self.tail_call_optimization
+                .unwrap_or_else(|| profile.tail_call_optimization()),
+            self.debug_information
+                .unwrap_or_else(|| profile.debug_information()),
+            self.strip.unwrap_or_else(|| profile.strip()),
+            self.link_time_optimization
+                .unwrap_or_else(|| profile.link_time_optimization()),
+            self.linker_flags
+                .clone()
+                .unwrap_or_else(|| profile.linker_flags().to_vec()),
+        )
+    }
+}
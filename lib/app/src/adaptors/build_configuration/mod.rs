@@ -1,6 +1,8 @@
 mod json_application_build_configuration;
 mod json_build_configuration;
 mod json_external_package_configuration;
+mod json_ffi_configuration;
+mod json_profile_configuration;
 mod json_system_package_configuration;
 
 use self::json_build_configuration::JsonBuildConfiguration;
@@ -15,5 +17,172 @@ pub fn serialize_build_configuration(
 pub fn deserialize_build_configuration(
     string: &str,
 ) -> Result<BuildConfiguration, serde_json::Error> {
-    Ok(serde_json::from_str::<JsonBuildConfiguration>(string)?.deserialize())
+    serde_json::from_str::<JsonBuildConfiguration>(string)?.deserialize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{
+        FfiConfiguration, FfiSource, FilePath, OptimizationLevel, Profile, ProfileName, Target,
+    };
+
+    #[test]
+    fn deserialize_without_ffi() {
+        assert_eq!(
+            deserialize_build_configuration(r#"{ "dependencies": {} }"#)
+                .unwrap()
+                .ffi(),
+            None
+        );
+    }
+
+    #[test]
+    fn deserialize_cargo_ffi() {
+        assert_eq!(
+            deserialize_build_configuration(r#"{ "dependencies": {}, "ffi": { "cargo": "ffi" } }"#)
+                .unwrap()
+                .ffi(),
+            Some(&FfiConfiguration::new(
+                FfiSource::Cargo(FilePath::new(&["ffi"])),
+                vec![]
+            ))
+        );
+    }
+
+    #[test]
+    fn deserialize_c_ffi_with_libraries() {
+        assert_eq!(
+            deserialize_build_configuration(
+                r#"{ "dependencies": {}, "ffi": { "c": ["src/foo.c"], "libraries": ["m"] } }"#
+            )
+            .unwrap()
+            .ffi(),
+            Some(&FfiConfiguration::new(
+                FfiSource::C(vec![FilePath::new(&["src", "foo.c"])]),
+                vec!["m".into()]
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_deserialize_ffi_without_source() {
+        assert!(deserialize_build_configuration(
+            r#"{ "dependencies": {}, "ffi": { "libraries": ["m"] } }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn deserialize_ffi_path_with_current_directory() {
+        assert_eq!(
+            deserialize_build_configuration(
+                r#"{ "dependencies": {}, "ffi": { "c": ["./src//foo.c"] } }"#
+            )
+            .unwrap()
+            .ffi(),
+            Some(&FfiConfiguration::new(
+                FfiSource::C(vec![FilePath::new(&["src", "foo.c"])]),
+                vec![]
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_deserialize_ffi_path_outside_package_directory() {
+        for path in &["/usr/lib/libfoo.a", "../libfoo.a", "lib/../../libfoo.a"] {
+            assert!(deserialize_build_configuration(&format!(
+                r#"{{ "dependencies": {{}}, "ffi": {{ "archives": ["{}"] }} }}"#,
+                path
+            ))
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn serialize_archives_ffi() {
+        let configuration = BuildConfiguration::new(
+            Target::Library,
+            Default::default(),
+            Some(FfiConfiguration::new(
+                FfiSource::Archives(vec![FilePath::new(&["lib", "libfoo.a"])]),
+                vec!["pthread".into()],
+            )),
+            Default::default(),
+        );
+
+        assert_eq!(
+            deserialize_build_configuration(
+                &serialize_build_configuration(&configuration).unwrap()
+            )
+            .unwrap()
+            .ffi(),
+            configuration.ffi()
+        );
+    }
+
+    #[test]
+    fn deserialize_default_profiles() {
+        let configuration = deserialize_build_configuration(r#"{ "dependencies": {} }"#).unwrap();
+
+        assert_eq!(
+            configuration.profile(ProfileName::Dev),
+            Profile::default_of(ProfileName::Dev)
+        );
+        assert_eq!(
+            configuration.profile(ProfileName::Release),
+            Profile::default_of(ProfileName::Release)
+        );
+    }
+
+    #[test]
+    fn deserialize_partial_profile() {
+        assert_eq!(
+            deserialize_build_configuration(
+                r#"{ "dependencies": {}, "profiles": { "release": { "optimizationLevel": 2, "strip": true } } }"#
+            )
+            .unwrap()
+            .profile(ProfileName::Release),
+            Profile::new(OptimizationLevel::Default, true, false, true, false, vec![])
+        );
+    }
+
+    #[test]
+    fn fail_to_deserialize_invalid_optimization_level() {
+        assert!(deserialize_build_configuration(
+            r#"{ "dependencies": {}, "profiles": { "dev": { "optimizationLevel": 4 } } }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn serialize_profile() {
+        let configuration = BuildConfiguration::new(
+            Target::Library,
+            Default::default(),
+            None,
+            vec![(
+                ProfileName::Dev,
+                Profile::new(
+                    OptimizationLevel::Less,
+                    false,
+                    true,
+                    false,
+                    true,
+                    vec!["-static".into()],
+                ),
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(
+            deserialize_build_configuration(
+                &serialize_build_configuration(&configuration).unwrap()
+            )
+            .unwrap()
+            .profiles(),
+            configuration.profiles()
+        );
+    }
 }
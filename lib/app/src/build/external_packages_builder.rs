@@ -2,12 +2,14 @@ use super::{
     error::BuildError, package_builder::PackageBuilder,
     utilities::convert_module_interface_vec_to_map,
 };
-use crate::common::{ExternalPackage, FilePath, PackageConfiguration};
+use crate::common::{ExternalPackage, FilePath, PackageConfiguration, Profile};
 use petgraph::{algo::toposort, graph::Graph};
 use std::collections::HashMap;
 
 type ExternalModuleInterfaces =
     HashMap<ExternalPackage, HashMap<lang::ExternalUnresolvedModulePath, lang::ModuleInterface>>;
+// Object file paths, system libraries, and module interfaces
+type ExternalPackagesBuildOutput = (Vec<FilePath>, Vec<String>, ExternalModuleInterfaces);
 
 pub struct ExternalPackagesBuilder<'a> {
     package_builder: &'a PackageBuilder<'a>,
@@ -22,20 +24,24 @@ impl<'a> ExternalPackagesBuilder<'a> {
         &self,
         package_configurations: &HashMap<ExternalPackage, PackageConfiguration>,
         prelude_module_interfaces: &[lang::ModuleInterface],
-    ) -> Result<(Vec<FilePath>, ExternalModuleInterfaces), Box<dyn std::error::Error>> {
+        profile: &Profile,
+    ) -> Result<ExternalPackagesBuildOutput, Box<dyn std::error::Error>> {
         let mut package_object_file_paths = vec![];
+        let mut package_libraries = vec![];
         let mut external_module_interfaces = HashMap::new();
 
         for external_package in self.sort_external_packages(package_configurations)? {
             let package_configuration = &package_configurations[&external_package];
 
-            let (object_file_paths, module_interfaces) = self.package_builder.build(
+            let (object_file_paths, libraries, module_interfaces) = self.package_builder.build(
                 package_configuration,
                 &external_module_interfaces,
                 prelude_module_interfaces,
+                profile,
             )?;
 
             package_object_file_paths.extend(object_file_paths);
+            package_libraries.extend(libraries);
 
             external_module_interfaces.insert(
                 external_package.clone(),
@@ -43,7 +49,36 @@ impl<'a> ExternalPackagesBuilder<'a> {
             );
         }
 
-        Ok((package_object_file_paths, external_module_interfaces))
+        Ok((
+            package_object_file_paths,
+            package_libraries,
+            external_module_interfaces,
+        ))
+    }
+
+    pub fn load(
+        &self,
+        package_configurations: &HashMap<ExternalPackage, PackageConfiguration>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        interpreter: &mut lang::Interpreter,
+    ) -> Result<ExternalModuleInterfaces, Box<dyn std::error::Error>> {
+        let mut external_module_interfaces = HashMap::new();
+
+        for external_package in self.sort_external_packages(package_configurations)? {
+            let module_interfaces = self.package_builder.load(
+                &package_configurations[&external_package],
+                &external_module_interfaces,
+                prelude_module_interfaces,
+                interpreter,
+            )?;
+
+            external_module_interfaces.insert(
+                external_package.clone(),
+                convert_module_interface_vec_to_map(&module_interfaces),
+            );
+        }
+
+        Ok(external_module_interfaces)
     }
 
     fn sort_external_packages(
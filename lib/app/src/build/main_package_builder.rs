@@ -7,7 +7,7 @@ use super::{
     utilities::convert_module_interface_vec_to_map,
 };
 use crate::{
-    common::{ApplicationTarget, FilePath, PackageConfiguration, Target},
+    common::{ApplicationTarget, FilePath, PackageConfiguration, Profile, ProfileName, Target},
     infra::{ApplicationLinker, Logger},
 };
 use std::collections::HashMap;
@@ -50,14 +50,17 @@ impl<'a> MainPackageBuilder<'a> {
         }
     }
 
-    pub fn build(&self) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn build(&self, profile_name: ProfileName) -> Result<(), Box<dyn std::error::Error>> {
         let package_configuration = self.package_configuration_reader.read(&FilePath::empty())?;
+        let profile = package_configuration
+            .build_configuration()
+            .profile(profile_name);
 
         match package_configuration.build_configuration().target() {
             Target::Application(application_target) => {
-                self.build_application(&package_configuration, application_target)
+                self.build_application(&package_configuration, application_target, &profile)
             }
-            Target::Library => self.build_library(&package_configuration),
+            Target::Library => self.build_library(&package_configuration, &profile),
         }
     }
 
@@ -65,14 +68,16 @@ impl<'a> MainPackageBuilder<'a> {
         &self,
         package_configuration: &PackageConfiguration,
         application_target: &ApplicationTarget,
+        profile: &Profile,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let (prelude_module_object_paths, prelude_module_interfaces) =
-            self.prelude_package_builder.build()?;
+        let (prelude_module_object_paths, prelude_libraries, prelude_module_interfaces) =
+            self.prelude_package_builder.build(profile)?;
 
-        let (system_module_object_paths, system_module_interfaces) =
+        let (system_module_object_paths, system_libraries, system_module_interfaces) =
             self.system_package_builder.build(
                 application_target.system_package(),
                 &prelude_module_interfaces,
+                profile,
             )?;
         let (main_function_module_interfaces, system_module_interfaces) = system_module_interfaces
             .into_iter()
@@ -104,11 +109,14 @@ impl<'a> MainPackageBuilder<'a> {
                 .collect::<Vec<_>>(),
         )?;
 
-        let (external_module_object_paths, mut external_module_interfaces) = self
-            .external_packages_builder
-            .build(&external_package_configurations, &prelude_module_interfaces)?;
+        let (external_module_object_paths, external_libraries, mut external_module_interfaces) =
+            self.external_packages_builder.build(
+                &external_package_configurations,
+                &prelude_module_interfaces,
+                profile,
+            )?;
 
-        let (module_object_paths, _) = self.package_builder.build(
+        let (module_object_paths, libraries, _) = self.package_builder.build(
             package_configuration,
             &external_module_interfaces
                 .drain()
@@ -118,6 +126,7 @@ impl<'a> MainPackageBuilder<'a> {
                 )])
                 .collect::<HashMap<_, _>>(),
             &prelude_module_interfaces,
+            profile,
         )?;
 
         self.logger.log(&format!(
@@ -132,7 +141,14 @@ impl<'a> MainPackageBuilder<'a> {
                 .chain(external_module_object_paths)
                 .chain(module_object_paths)
                 .collect::<Vec<_>>(),
+            &prelude_libraries
+                .into_iter()
+                .chain(system_libraries)
+                .chain(external_libraries)
+                .chain(libraries)
+                .collect::<Vec<_>>(),
             application_target.name(),
+            profile,
         )?;
 
         Ok(())
@@ -141,8 +157,9 @@ impl<'a> MainPackageBuilder<'a> {
     fn build_library(
         &self,
         package_configuration: &PackageConfiguration,
+        profile: &Profile,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let (_, prelude_module_interfaces) = self.prelude_package_builder.build()?;
+        let (_, _, prelude_module_interfaces) = self.prelude_package_builder.build(profile)?;
 
         let external_package_configurations = self.external_packages_downloader.download(
             &package_configuration
@@ -152,14 +169,17 @@ impl<'a> MainPackageBuilder<'a> {
                 .collect::<Vec<_>>(),
         )?;
 
-        let (_, external_module_interfaces) = self
-            .external_packages_builder
-            .build(&external_package_configurations, &prelude_module_interfaces)?;
+        let (_, _, external_module_interfaces) = self.external_packages_builder.build(
+            &external_package_configurations,
+            &prelude_module_interfaces,
+            profile,
+        )?;
 
         self.package_builder.build(
             package_configuration,
             &external_module_interfaces,
             &prelude_module_interfaces,
+            profile,
         )?;
 
         Ok(())
@@ -0,0 +1,123 @@
+use super::{
+    external_packages_builder::ExternalPackagesBuilder,
+    external_packages_downloader::ExternalPackagesDownloader, package_builder::PackageBuilder,
+    package_configuration_reader::PackageConfigurationReader,
+    prelude_package_builder::PreludePackageBuilder, system_package_builder::SystemPackageBuilder,
+    system_package_configuration::SystemPackageConfiguration,
+    utilities::convert_module_interface_vec_to_map,
+};
+use crate::common::{FilePath, PackageConfiguration};
+
+// Package configuration and module interfaces of prelude, dependency and main
+// packages
+pub type MainPackageLoadOutput = (
+    PackageConfiguration,
+    Vec<lang::ModuleInterface>,
+    Vec<lang::ModuleInterface>,
+    Vec<lang::ModuleInterface>,
+);
+
+pub struct MainPackageLoader<'a> {
+    package_configuration_reader: &'a PackageConfigurationReader<'a>,
+    package_builder: &'a PackageBuilder<'a>,
+    prelude_package_builder: &'a PreludePackageBuilder<'a>,
+    system_package_builder: &'a SystemPackageBuilder<'a>,
+    external_packages_downloader: &'a ExternalPackagesDownloader<'a>,
+    external_packages_builder: &'a ExternalPackagesBuilder<'a>,
+    system_package_configuration: &'a SystemPackageConfiguration,
+}
+
+impl<'a> MainPackageLoader<'a> {
+    pub fn new(
+        package_configuration_reader: &'a PackageConfigurationReader<'a>,
+        package_builder: &'a PackageBuilder<'a>,
+        prelude_package_builder: &'a PreludePackageBuilder<'a>,
+        system_package_builder: &'a SystemPackageBuilder<'a>,
+        external_packages_downloader: &'a ExternalPackagesDownloader<'a>,
+        external_packages_builder: &'a ExternalPackagesBuilder<'a>,
+        system_package_configuration: &'a SystemPackageConfiguration,
+    ) -> Self {
+        Self {
+            package_configuration_reader,
+            package_builder,
+            prelude_package_builder,
+            system_package_builder,
+            external_packages_downloader,
+            external_packages_builder,
+            system_package_configuration,
+        }
+    }
+
+    // Modules of a main package and its dependencies are loaded into an
+    // interpreter. Main function modules of system packages are excluded from
+    // dependencies as they are not supposed to be imported.
+    pub fn load(
+        &self,
+        interpreter: &mut lang::Interpreter,
+    ) -> Result<MainPackageLoadOutput, Box<dyn std::error::Error>> {
+        let package_configuration = self.package_configuration_reader.read(&FilePath::empty())?;
+
+        let prelude_module_interfaces = self.prelude_package_builder.load(interpreter)?;
+
+        let mut external_module_interfaces = self.external_packages_builder.load(
+            &self.external_packages_downloader.download(
+                &package_configuration
+                    .build_configuration()
+                    .dependencies()
+                    .iter()
+                    .collect::<Vec<_>>(),
+            )?,
+            &prelude_module_interfaces,
+            interpreter,
+        )?;
+
+        let mut dependency_module_interfaces = package_configuration
+            .build_configuration()
+            .dependencies()
+            .iter()
+            .map(|external_package| external_module_interfaces[external_package].values())
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if let Some(application_target) = package_configuration
+            .build_configuration()
+            .target()
+            .as_application()
+        {
+            let system_module_interfaces = self.system_package_builder.load(
+                application_target.system_package(),
+                &prelude_module_interfaces,
+                interpreter,
+            )?;
+
+            dependency_module_interfaces.extend(
+                system_module_interfaces
+                    .iter()
+                    .filter(|interface| {
+                        interface.path().components().collect::<Vec<_>>()
+                            != vec![self.system_package_configuration.main_function_module_name]
+                    })
+                    .cloned(),
+            );
+            external_module_interfaces.insert(
+                application_target.system_package().clone(),
+                convert_module_interface_vec_to_map(&system_module_interfaces),
+            );
+        }
+
+        let module_interfaces = self.package_builder.load(
+            &package_configuration,
+            &external_module_interfaces,
+            &prelude_module_interfaces,
+            interpreter,
+        )?;
+
+        Ok((
+            package_configuration,
+            prelude_module_interfaces,
+            dependency_module_interfaces,
+            module_interfaces,
+        ))
+    }
+}
@@ -3,6 +3,7 @@ mod error;
 mod external_packages_builder;
 mod external_packages_downloader;
 mod main_package_builder;
+mod main_package_loader;
 mod module_compiler;
 mod module_parser;
 mod modules_builder;
@@ -19,6 +20,7 @@ pub use error::*;
 pub use external_packages_builder::*;
 pub use external_packages_downloader::*;
 pub use main_package_builder::*;
+pub use main_package_loader::*;
 pub use module_compiler::*;
 pub use module_parser::*;
 pub use modules_builder::*;
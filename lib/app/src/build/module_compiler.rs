@@ -1,6 +1,9 @@
 use super::{error::BuildError, module_parser::ModuleParser};
 use crate::{
-    common::{FilePath, FilePathConfiguration, FilePathResolver, PackageConfiguration, Target},
+    common::{
+        FilePath, FilePathConfiguration, FilePathResolver, OptimizationLevel, PackageConfiguration,
+        Profile, Target,
+    },
     infra::{FileSystem, Logger},
 };
 use std::{
@@ -16,6 +19,7 @@ pub struct ModuleCompiler<'a> {
     logger: &'a dyn Logger,
     compile_configuration: Arc<lang::CompileConfiguration>,
     file_path_configuration: &'a FilePathConfiguration,
+    emitted_stages: &'a [lang::Stage],
 }
 
 impl<'a> ModuleCompiler<'a> {
@@ -26,6 +30,7 @@ impl<'a> ModuleCompiler<'a> {
         logger: &'a dyn Logger,
         compile_configuration: Arc<lang::CompileConfiguration>,
         file_path_configuration: &'a FilePathConfiguration,
+        emitted_stages: &'a [lang::Stage],
     ) -> Self {
         Self {
             module_parser,
@@ -34,6 +39,7 @@ impl<'a> ModuleCompiler<'a> {
             logger,
             compile_configuration,
             file_path_configuration,
+            emitted_stages,
         }
     }
 
@@ -43,69 +49,101 @@ impl<'a> ModuleCompiler<'a> {
         module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
         prelude_module_interfaces: &[lang::ModuleInterface],
         package_configuration: &PackageConfiguration,
+        profile: &Profile,
     ) -> Result<(FilePath, FilePath), Box<dyn std::error::Error>> {
         let source = self.file_system.read_to_string(source_file_path)?;
         let module = self.module_parser.parse(&source, source_file_path)?;
 
-        let imported_module_interfaces = module
-            .imports()
-            .iter()
-            .map(|import| {
-                Ok(module_interfaces
-                    .get(import.module_path())
-                    .ok_or(BuildError::ModuleNotFound {
-                        module_path: import.module_path().clone(),
-                        source_file_path: source_file_path.clone(),
-                    })?
-                    .clone())
-            })
-            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+        let imported_module_interfaces =
+            self.resolve_imported_module_interfaces(&module, source_file_path, module_interfaces)?;
 
-        let module_id =
-            self.generate_module_id(source_file_path, &source, &imported_module_interfaces);
-        let object_file_path = self.file_path_resolver.resolve_object_file_path(&module_id);
+        let code_generation_configuration = self.compile_code_generation_configuration(profile);
+        let module_id = self.generate_module_id(
+            source_file_path,
+            &source,
+            &imported_module_interfaces,
+            &code_generation_configuration,
+        );
+        let object_file_path = match code_generation_configuration.output_format {
+            lang::OutputFormat::BitCode => self
+                .file_path_resolver
+                .resolve_bit_code_file_path(&module_id),
+            lang::OutputFormat::C => self
+                .file_path_resolver
+                .resolve_c_source_file_path(&module_id),
+            lang::OutputFormat::Object => {
+                self.file_path_resolver.resolve_object_file_path(&module_id)
+            }
+        };
         let interface_file_path = self
             .file_path_resolver
             .resolve_interface_file_path(&module_id);
 
-        if self.file_system.exists(&object_file_path) {
+        if self.file_system.exists(&object_file_path)
+            && self
+                .emitted_stages
+                .iter()
+                .filter(|stage| stage.is_emitted(code_generation_configuration.output_format))
+                .all(|&stage| {
+                    self.file_system.exists(
+                        &self
+                            .file_path_resolver
+                            .resolve_stage_file_path(&module_id, stage),
+                    )
+                })
+        {
             return Ok((object_file_path, interface_file_path));
         }
 
-        let module_path = self.file_path_resolver.resolve_module_path(
-            &source_file_path.relative_to(package_configuration.directory_path()),
-            package_configuration.package(),
-        );
+        let module_path = self.resolve_module_path(source_file_path, package_configuration);
 
         self.logger.log(&format!(
             "compiling module {}",
             &module_path.external_unresolved()
         ))?;
 
-        let (module_object_data, module_interface) =
-            lang::compile(
-                &module.resolve(
-                    module_path.clone(),
-                    imported_module_interfaces
-                        .into_iter()
-                        .map(|module_interface| lang::Import::new(module_interface, true))
-                        .chain(prelude_module_interfaces.iter().map(|module_interface| {
-                            lang::Import::new(module_interface.clone(), false)
-                        }))
-                        .collect(),
-                ),
-                // TODO Refactor this by creating the following classes.
-                // - MainModuleCompiler
-                // - ApplicationPackageBuilder
-                // - LibraryPackageBuilder
-                if self.is_main_module(&module_path, package_configuration) {
-                    self.compile_configuration.clone()
-                } else {
-                    let mut configuration = self.compile_configuration.as_ref().clone();
+        let mut stage_outputs = vec![];
+        let (module_object_data, module_interface, warnings) = lang::compile_with_stage_hook(
+            &self.resolve_module(
+                module,
+                module_path.clone(),
+                imported_module_interfaces,
+                prelude_module_interfaces,
+            ),
+            // TODO Refactor this by creating the following classes.
+            // - MainModuleCompiler
+            // - ApplicationPackageBuilder
+            // - LibraryPackageBuilder
+            {
+                let mut configuration = self.compile_configuration.as_ref().clone();
+
+                if !self.is_main_module(&module_path, package_configuration) {
                     configuration.main_module_configuration = None;
-                    configuration.into()
-                },
+                }
+
+                configuration.code_generation_configuration = code_generation_configuration.into();
+
+                configuration.into()
+            },
+            &mut |stage, render| {
+                if self.emitted_stages.contains(&stage) {
+                    stage_outputs.push((stage, render()));
+                }
+            },
+        )?;
+
+        for warning in warnings {
+            self.logger.log(&format!("warning: {}", warning))?;
+        }
+
+        for (stage, output) in stage_outputs {
+            self.file_system.write(
+                &self
+                    .file_path_resolver
+                    .resolve_stage_file_path(&module_id, stage),
+                output.as_bytes(),
             )?;
+        }
 
         self.file_system
             .write(&object_file_path, &module_object_data)?;
@@ -117,11 +155,99 @@ impl<'a> ModuleCompiler<'a> {
         Ok((object_file_path, interface_file_path))
     }
 
+    // Modules are loaded into interpreters without being compiled into
+    // objects.
+    pub fn load(
+        &self,
+        source_file_path: &FilePath,
+        module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        package_configuration: &PackageConfiguration,
+        interpreter: &mut lang::Interpreter,
+    ) -> Result<lang::ModuleInterface, Box<dyn std::error::Error>> {
+        let module = self.module_parser.parse(
+            &self.file_system.read_to_string(source_file_path)?,
+            source_file_path,
+        )?;
+        let imported_module_interfaces =
+            self.resolve_imported_module_interfaces(&module, source_file_path, module_interfaces)?;
+
+        Ok(interpreter.load(
+            &self.resolve_module(
+                module,
+                self.resolve_module_path(source_file_path, package_configuration),
+                imported_module_interfaces,
+                prelude_module_interfaces,
+            ),
+            {
+                let mut configuration = self.compile_configuration.as_ref().clone();
+
+                configuration.main_module_configuration = None;
+
+                configuration.into()
+            },
+        )?)
+    }
+
+    fn resolve_imported_module_interfaces(
+        &self,
+        module: &lang::UnresolvedModule,
+        source_file_path: &FilePath,
+        module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+    ) -> Result<Vec<lang::ModuleInterface>, Box<dyn std::error::Error>> {
+        module
+            .imports()
+            .iter()
+            .map(|import| {
+                Ok(module_interfaces
+                    .get(import.module_path())
+                    .ok_or(BuildError::ModuleNotFound {
+                        module_path: import.module_path().clone(),
+                        source_file_path: source_file_path.clone(),
+                    })?
+                    .clone())
+            })
+            .collect()
+    }
+
+    fn resolve_module_path(
+        &self,
+        source_file_path: &FilePath,
+        package_configuration: &PackageConfiguration,
+    ) -> lang::ModulePath {
+        self.file_path_resolver.resolve_module_path(
+            &source_file_path.relative_to(package_configuration.directory_path()),
+            package_configuration.package(),
+        )
+    }
+
+    fn resolve_module(
+        &self,
+        module: lang::UnresolvedModule,
+        module_path: lang::ModulePath,
+        imported_module_interfaces: Vec<lang::ModuleInterface>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+    ) -> lang::Module {
+        module.resolve(
+            module_path,
+            imported_module_interfaces
+                .into_iter()
+                .map(|module_interface| lang::Import::new(module_interface, true))
+                .chain(
+                    prelude_module_interfaces
+                        .iter()
+                        .map(|module_interface| lang::Import::new(module_interface.clone(), false)),
+                )
+                .collect(),
+        )
+    }
+
     fn generate_module_id<'b>(
         &self,
         source_file_path: &FilePath,
         source: &str,
         imported_module_interfaces: impl IntoIterator<Item = &'b lang::ModuleInterface>,
+        code_generation_configuration: &lang::CodeGenerationConfiguration,
     ) -> String {
         let mut hasher = DefaultHasher::new();
 
@@ -132,9 +258,47 @@ impl<'a> ModuleCompiler<'a> {
             module_interface.hash(&mut hasher);
         }
 
+        code_generation_configuration.hash(&mut hasher);
+
         format!("{:x}", hasher.finish())
     }
 
+    fn compile_code_generation_configuration(
+        &self,
+        profile: &Profile,
+    ) -> lang::CodeGenerationConfiguration {
+        lang::CodeGenerationConfiguration {
+            // Modules are kept as bit code for link-time optimization and
+            // compiled into native objects otherwise unless the C backend is
+            // selected.
+            output_format: if self
+                .compile_configuration
+                .code_generation_configuration
+                .output_format
+                == lang::OutputFormat::C
+            {
+                lang::OutputFormat::C
+            } else if profile.link_time_optimization() {
+                lang::OutputFormat::BitCode
+            } else {
+                lang::OutputFormat::Object
+            },
+            optimization_level: match profile.optimization_level() {
+                OptimizationLevel::None => lang::OptimizationLevel::None,
+                OptimizationLevel::Less => lang::OptimizationLevel::Less,
+                OptimizationLevel::Default => lang::OptimizationLevel::Default,
+                OptimizationLevel::Aggressive => lang::OptimizationLevel::Aggressive,
+            },
+            tail_call_optimization: profile.tail_call_optimization(),
+            debug_information: profile.debug_information(),
+            target_triple: self
+                .compile_configuration
+                .code_generation_configuration
+                .target_triple
+                .clone(),
+        }
+    }
+
     fn is_main_module(
         &self,
         module_path: &lang::ModulePath,
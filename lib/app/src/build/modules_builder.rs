@@ -3,7 +3,7 @@ use super::{
     modules_finder::ModulesFinder,
 };
 use crate::{
-    common::{FilePath, FilePathResolver, PackageConfiguration},
+    common::{FilePath, FilePathResolver, PackageConfiguration, Profile},
     infra::FileSystem,
 };
 use petgraph::{algo::toposort, graph::Graph};
@@ -42,11 +42,10 @@ impl<'a> ModulesBuilder<'a> {
             lang::ModuleInterface,
         >,
         prelude_module_interfaces: &[lang::ModuleInterface],
+        profile: &Profile,
     ) -> Result<(Vec<FilePath>, Vec<FilePath>), Box<dyn std::error::Error>> {
-        let mut module_interfaces = external_module_interfaces
-            .iter()
-            .map(|(path, module_interface)| (path.clone().into(), module_interface.clone()))
-            .collect::<HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>>();
+        let mut module_interfaces =
+            self.convert_external_module_interfaces(external_module_interfaces);
 
         let mut object_file_paths = vec![];
         let mut interface_file_paths = vec![];
@@ -62,6 +61,7 @@ impl<'a> ModulesBuilder<'a> {
                 &module_interfaces,
                 prelude_module_interfaces,
                 package_configuration,
+                profile,
             )?;
 
             let module_interface = serde_json::from_str::<lang::ModuleInterface>(
@@ -79,6 +79,57 @@ impl<'a> ModulesBuilder<'a> {
         Ok((object_file_paths, interface_file_paths))
     }
 
+    pub fn load(
+        &self,
+        package_configuration: &PackageConfiguration,
+        external_module_interfaces: &HashMap<
+            lang::ExternalUnresolvedModulePath,
+            lang::ModuleInterface,
+        >,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        interpreter: &mut lang::Interpreter,
+    ) -> Result<Vec<lang::ModuleInterface>, Box<dyn std::error::Error>> {
+        let mut module_interfaces =
+            self.convert_external_module_interfaces(external_module_interfaces);
+        let mut package_module_interfaces = vec![];
+
+        for source_file_path in self.sort_source_file_paths(
+            &self
+                .modules_finder
+                .find(package_configuration.directory_path())?,
+            package_configuration,
+        )? {
+            let module_interface = self.module_compiler.load(
+                source_file_path,
+                &module_interfaces,
+                prelude_module_interfaces,
+                package_configuration,
+                interpreter,
+            )?;
+
+            module_interfaces.insert(
+                module_interface.path().internal_unresolved().into(),
+                module_interface.clone(),
+            );
+            package_module_interfaces.push(module_interface);
+        }
+
+        Ok(package_module_interfaces)
+    }
+
+    fn convert_external_module_interfaces(
+        &self,
+        external_module_interfaces: &HashMap<
+            lang::ExternalUnresolvedModulePath,
+            lang::ModuleInterface,
+        >,
+    ) -> HashMap<lang::UnresolvedModulePath, lang::ModuleInterface> {
+        external_module_interfaces
+            .iter()
+            .map(|(path, module_interface)| (path.clone().into(), module_interface.clone()))
+            .collect()
+    }
+
     fn sort_source_file_paths<'b>(
         &self,
         source_file_paths: &'b [FilePath],
@@ -1,13 +1,18 @@
 use super::modules_builder::ModulesBuilder;
 use crate::{
-    common::{ExternalPackage, FilePath, PackageConfiguration},
-    infra::{FfiPackageInitializer, FileSystem, Logger},
+    common::{ExternalPackage, FilePath, PackageConfiguration, Profile, ProfileName},
+    infra::{FfiPackageBuilder, FfiPackageInitializer, FileSystem, ForeignLibraryLoader, Logger},
 };
 use std::collections::HashMap;
 
+// Object file paths, system libraries, and module interfaces
+pub type PackageBuildOutput = (Vec<FilePath>, Vec<String>, Vec<lang::ModuleInterface>);
+
 pub struct PackageBuilder<'a> {
     modules_builder: &'a ModulesBuilder<'a>,
     ffi_package_initializer: &'a dyn FfiPackageInitializer,
+    ffi_package_builder: &'a dyn FfiPackageBuilder,
+    foreign_library_loader: Option<&'a dyn ForeignLibraryLoader>,
     file_system: &'a dyn FileSystem,
     logger: &'a dyn Logger,
 }
@@ -16,12 +21,16 @@ impl<'a> PackageBuilder<'a> {
     pub fn new(
         modules_builder: &'a ModulesBuilder<'a>,
         ffi_package_initializer: &'a dyn FfiPackageInitializer,
+        ffi_package_builder: &'a dyn FfiPackageBuilder,
+        foreign_library_loader: Option<&'a dyn ForeignLibraryLoader>,
         file_system: &'a dyn FileSystem,
         logger: &'a dyn Logger,
     ) -> Self {
         Self {
             modules_builder,
             ffi_package_initializer,
+            ffi_package_builder,
+            foreign_library_loader,
             file_system,
             logger,
         }
@@ -35,30 +44,144 @@ impl<'a> PackageBuilder<'a> {
             HashMap<lang::ExternalUnresolvedModulePath, lang::ModuleInterface>,
         >,
         prelude_module_interfaces: &[lang::ModuleInterface],
-    ) -> Result<(Vec<FilePath>, Vec<lang::ModuleInterface>), Box<dyn std::error::Error>> {
+        profile: &Profile,
+    ) -> Result<PackageBuildOutput, Box<dyn std::error::Error>> {
         self.logger.log(&format!(
             "building package {} {}",
             package_configuration.package().name(),
             package_configuration.package().version()
         ))?;
 
-        let ffi_object_file_path = if self
-            .ffi_package_initializer
-            .is_ffi_used(package_configuration.directory_path())
+        let ffi_object_file_paths = self.build_ffi(package_configuration, profile)?;
+
+        let (object_file_paths, interface_file_paths) = self.modules_builder.build(
+            package_configuration,
+            &self.collect_external_module_interfaces(
+                package_configuration,
+                external_module_interfaces,
+            ),
+            prelude_module_interfaces,
+            profile,
+        )?;
+
+        Ok((
+            object_file_paths
+                .into_iter()
+                .chain(ffi_object_file_paths)
+                .collect(),
+            self.collect_system_libraries(package_configuration),
+            interface_file_paths
+                .iter()
+                .map(|file_path| {
+                    Ok(serde_json::from_slice(
+                        &self.file_system.read_to_vec(file_path)?,
+                    )?)
+                })
+                .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?,
+        ))
+    }
+
+    // FFI of packages is built and loaded into interpreters as libraries only
+    // if a foreign library loader is given.
+    pub fn load(
+        &self,
+        package_configuration: &PackageConfiguration,
+        external_module_interfaces: &HashMap<
+            ExternalPackage,
+            HashMap<lang::ExternalUnresolvedModulePath, lang::ModuleInterface>,
+        >,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        interpreter: &mut lang::Interpreter,
+    ) -> Result<Vec<lang::ModuleInterface>, Box<dyn std::error::Error>> {
+        self.logger.log(&format!(
+            "loading package {} {}",
+            package_configuration.package().name(),
+            package_configuration.package().version()
+        ))?;
+
+        if let Some(foreign_library_loader) = self.foreign_library_loader {
+            // Interpreters are never optimized and so are their foreign
+            // libraries.
+            let ffi_object_file_paths = self.build_ffi(
+                package_configuration,
+                &Profile::default_of(ProfileName::Dev),
+            )?;
+
+            if !ffi_object_file_paths.is_empty() {
+                foreign_library_loader.load(
+                    &ffi_object_file_paths,
+                    &self.collect_system_libraries(package_configuration),
+                    interpreter,
+                )?;
+            }
+        }
+
+        self.modules_builder.load(
+            package_configuration,
+            &self.collect_external_module_interfaces(
+                package_configuration,
+                external_module_interfaces,
+            ),
+            prelude_module_interfaces,
+            interpreter,
+        )
+    }
+
+    fn build_ffi(
+        &self,
+        package_configuration: &PackageConfiguration,
+        profile: &Profile,
+    ) -> Result<Vec<FilePath>, Box<dyn std::error::Error>> {
+        let ffi_configuration = package_configuration.build_configuration().ffi();
+
+        if ffi_configuration.is_none()
+            && !self
+                .ffi_package_initializer
+                .is_ffi_used(package_configuration.directory_path())
         {
-            self.logger.log(&format!(
-                "building FFI for package {} {}",
-                package_configuration.package().name(),
-                package_configuration.package().version()
-            ))?;
+            return Ok(vec![]);
+        }
 
+        self.logger.log(&format!(
+            "building FFI for package {} {}",
+            package_configuration.package().name(),
+            package_configuration.package().version()
+        ))?;
+
+        Ok(if let Some(ffi_configuration) = ffi_configuration {
+            self.ffi_package_builder.build(
+                package_configuration.directory_path(),
+                ffi_configuration,
+                profile,
+            )?
+        } else {
             self.ffi_package_initializer
                 .initialize(package_configuration.directory_path())?
-        } else {
-            None
-        };
+                .into_iter()
+                .collect()
+        })
+    }
+
+    fn collect_system_libraries(
+        &self,
+        package_configuration: &PackageConfiguration,
+    ) -> Vec<String> {
+        package_configuration
+            .build_configuration()
+            .ffi()
+            .map(|configuration| configuration.libraries().to_vec())
+            .unwrap_or_default()
+    }
 
-        let external_module_interfaces = package_configuration
+    fn collect_external_module_interfaces(
+        &self,
+        package_configuration: &PackageConfiguration,
+        external_module_interfaces: &HashMap<
+            ExternalPackage,
+            HashMap<lang::ExternalUnresolvedModulePath, lang::ModuleInterface>,
+        >,
+    ) -> HashMap<lang::ExternalUnresolvedModulePath, lang::ModuleInterface> {
+        package_configuration
             .build_configuration()
             .dependencies()
             .iter()
@@ -77,27 +200,6 @@ impl<'a> PackageBuilder<'a> {
                 )
             })
             .flatten()
-            .collect();
-
-        let (object_file_paths, interface_file_paths) = self.modules_builder.build(
-            package_configuration,
-            &external_module_interfaces,
-            prelude_module_interfaces,
-        )?;
-
-        Ok((
-            object_file_paths
-                .into_iter()
-                .chain(ffi_object_file_path)
-                .collect(),
-            interface_file_paths
-                .iter()
-                .map(|file_path| {
-                    Ok(serde_json::from_slice(
-                        &self.file_system.read_to_vec(file_path)?,
-                    )?)
-                })
-                .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?,
-        ))
+            .collect()
     }
 }
@@ -1,48 +1,196 @@
 use super::{
-    package_builder::PackageBuilder, package_configuration_reader::PackageConfigurationReader,
+    package_builder::{PackageBuildOutput, PackageBuilder},
+    package_configuration_reader::PackageConfigurationReader,
 };
 use crate::{
-    common::{FilePath, StaticFilePathManager},
-    infra::PreludePackageDownloader,
+    common::{FilePath, PackageConfiguration, Profile, StaticFilePathManager},
+    infra::FileSystem,
 };
+use std::collections::HashSet;
+
+// Build artifacts of FFI crates are kept while files removed from the prelude
+// package are removed from its copy.
+const GENERATED_FILE_NAMES: &[&str] = &["target", "Cargo.lock"];
 
 pub struct PreludePackageBuilder<'a> {
     package_configuration_reader: &'a PackageConfigurationReader<'a>,
     package_builder: &'a PackageBuilder<'a>,
-    prelude_package_downloader: &'a dyn PreludePackageDownloader,
+    file_system: &'a dyn FileSystem,
     static_file_path_manager: &'a StaticFilePathManager,
+    prelude_package_files: &'a [(FilePath, Vec<u8>)],
+    ffi_library_files: &'a [(FilePath, Vec<u8>)],
 }
 
 impl<'a> PreludePackageBuilder<'a> {
     pub fn new(
         package_configuration_reader: &'a PackageConfigurationReader<'a>,
         package_builder: &'a PackageBuilder<'a>,
-        prelude_package_downloader: &'a dyn PreludePackageDownloader,
+        file_system: &'a dyn FileSystem,
         static_file_path_manager: &'a StaticFilePathManager,
+        prelude_package_files: &'a [(FilePath, Vec<u8>)],
+        ffi_library_files: &'a [(FilePath, Vec<u8>)],
     ) -> Self {
         Self {
             package_configuration_reader,
             package_builder,
-            prelude_package_downloader,
+            file_system,
             static_file_path_manager,
+            prelude_package_files,
+            ffi_library_files,
         }
     }
 
     pub fn build(
         &self,
-    ) -> Result<(Vec<FilePath>, Vec<lang::ModuleInterface>), Box<dyn std::error::Error>> {
+        profile: &Profile,
+    ) -> Result<PackageBuildOutput, Box<dyn std::error::Error>> {
+        self.package_builder.build(
+            &self.read_package_configuration()?,
+            &Default::default(),
+            &[],
+            profile,
+        )
+    }
+
+    pub fn load(
+        &self,
+        interpreter: &mut lang::Interpreter,
+    ) -> Result<Vec<lang::ModuleInterface>, Box<dyn std::error::Error>> {
+        self.package_builder.load(
+            &self.read_package_configuration()?,
+            &Default::default(),
+            &[],
+            interpreter,
+        )
+    }
+
+    fn read_package_configuration(
+        &self,
+    ) -> Result<PackageConfiguration, Box<dyn std::error::Error>> {
         let directory_path = self
             .static_file_path_manager
             .prelude_package_directory_path();
 
-        self.prelude_package_downloader.download(directory_path)?;
+        self.write_files(directory_path, self.prelude_package_files)?;
+        // The FFI crate of the prelude package refers to the FFI library at
+        // its sibling directory.
+        self.write_files(
+            self.static_file_path_manager.ffi_library_directory_path(),
+            self.ffi_library_files,
+        )?;
+
+        self.package_configuration_reader.read(directory_path)
+    }
+
+    fn write_files(
+        &self,
+        directory_path: &FilePath,
+        files: &[(FilePath, Vec<u8>)],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        synchronize_files(self.file_system, directory_path, files)
+    }
+}
+
+fn synchronize_files(
+    file_system: &dyn FileSystem,
+    directory_path: &FilePath,
+    files: &[(FilePath, Vec<u8>)],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file_paths = files
+        .iter()
+        .map(|(path, _)| directory_path.join(path))
+        .collect::<HashSet<_>>();
+
+    remove_stale_files(file_system, directory_path, &file_paths)?;
+
+    for (path, data) in files {
+        let path = directory_path.join(path);
+
+        // Unchanged files are not written again so that FFI crates are not
+        // rebuilt every time.
+        if !file_system.exists(&path) || &file_system.read_to_vec(&path)? != data {
+            file_system.write(&path, data)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn remove_stale_files(
+    file_system: &dyn FileSystem,
+    directory_path: &FilePath,
+    file_paths: &HashSet<FilePath>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !file_system.is_directory(directory_path) {
+        return Ok(());
+    }
+
+    for path in file_system.read_directory(directory_path)? {
+        let name = path.components().last().unwrap_or_default();
+
+        if name.starts_with('.') || GENERATED_FILE_NAMES.contains(&name) {
+        } else if file_system.is_directory(&path) {
+            remove_stale_files(file_system, &path, file_paths)?;
+        } else if !file_paths.contains(&path) {
+            file_system.remove(&path)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::FakeFileSystem;
+
+    #[test]
+    fn write_files() {
+        let file_system = FakeFileSystem::new(Default::default());
+
+        synchronize_files(
+            &file_system,
+            &FilePath::new(&["prelude"]),
+            &[(FilePath::new(&["Foo.ein"]), b"foo".to_vec())],
+        )
+        .unwrap();
+
+        assert_eq!(
+            file_system
+                .read_to_vec(&FilePath::new(&["prelude", "Foo.ein"]))
+                .unwrap(),
+            b"foo"
+        );
+    }
 
-        let package_configuration = self.package_configuration_reader.read(directory_path)?;
+    #[test]
+    fn remove_stale_files_before_writing() {
+        let file_system = FakeFileSystem::new(
+            vec![
+                (FilePath::new(&["prelude", "Foo.ein"]), b"foo".to_vec()),
+                (FilePath::new(&["prelude", "Bar", "Baz.ein"]), vec![]),
+                (FilePath::new(&["prelude", "ffi", "Cargo.lock"]), vec![]),
+                (
+                    FilePath::new(&["prelude", "ffi", "target", "foo.a"]),
+                    vec![],
+                ),
+                (FilePath::new(&["prelude", ".ein", "foo.o"]), vec![]),
+            ]
+            .drain(..)
+            .collect(),
+        );
 
-        let (package_object_file_paths, module_interfaces) =
-            self.package_builder
-                .build(&package_configuration, &Default::default(), &[])?;
+        synchronize_files(
+            &file_system,
+            &FilePath::new(&["prelude"]),
+            &[(FilePath::new(&["Foo.ein"]), b"foo".to_vec())],
+        )
+        .unwrap();
 
-        Ok((package_object_file_paths, module_interfaces))
+        assert!(file_system.exists(&FilePath::new(&["prelude", "Foo.ein"])));
+        assert!(!file_system.exists(&FilePath::new(&["prelude", "Bar", "Baz.ein"])));
+        assert!(file_system.exists(&FilePath::new(&["prelude", "ffi", "Cargo.lock"])));
+        assert!(file_system.exists(&FilePath::new(&["prelude", "ffi", "target", "foo.a"])));
+        assert!(file_system.exists(&FilePath::new(&["prelude", ".ein", "foo.o"])));
     }
 }
@@ -1,8 +1,8 @@
 use super::{
     cached_external_package_downloader::CachedExternalPackageDownloader,
-    package_builder::PackageBuilder,
+    package_builder::{PackageBuildOutput, PackageBuilder},
 };
-use crate::common::{ExternalPackage, FilePath};
+use crate::common::{ExternalPackage, Profile};
 
 pub struct SystemPackageBuilder<'a> {
     package_builder: &'a PackageBuilder<'a>,
@@ -24,7 +24,8 @@ impl<'a> SystemPackageBuilder<'a> {
         &self,
         external_package: &ExternalPackage,
         prelude_module_interfaces: &[lang::ModuleInterface],
-    ) -> Result<(Vec<FilePath>, Vec<lang::ModuleInterface>), Box<dyn std::error::Error>> {
+        profile: &Profile,
+    ) -> Result<PackageBuildOutput, Box<dyn std::error::Error>> {
         let package_configuration = self
             .cached_external_package_downloader
             .download(external_package)?;
@@ -33,6 +34,25 @@ impl<'a> SystemPackageBuilder<'a> {
             &package_configuration,
             &Default::default(),
             prelude_module_interfaces,
+            profile,
+        )
+    }
+
+    pub fn load(
+        &self,
+        external_package: &ExternalPackage,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        interpreter: &mut lang::Interpreter,
+    ) -> Result<Vec<lang::ModuleInterface>, Box<dyn std::error::Error>> {
+        let package_configuration = self
+            .cached_external_package_downloader
+            .download(external_package)?;
+
+        self.package_builder.load(
+            &package_configuration,
+            &Default::default(),
+            prelude_module_interfaces,
+            interpreter,
         )
     }
 }
@@ -0,0 +1,19 @@
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Backend {
+    C,
+    Llvm,
+}
+
+impl FromStr for Backend {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, ()> {
+        match name {
+            "c" => Ok(Self::C),
+            "llvm" => Ok(Self::Llvm),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,7 +1,11 @@
+pub const BIT_CODE_FILE_EXTENSION: &str = "bc";
+pub const C_SOURCE_FILE_EXTENSION: &str = "c";
+pub const DOCUMENTATION_DIRECTORY: &str = "doc";
 pub const EXTERNAL_PACKAGES_DIRECTORY: &str = "packages";
+pub const FFI_LIBRARY_DIRECTORY: &str = "ffi";
 pub const INTERFACE_FILE_EXTENSION: &str = "json";
 pub const OBJECT_DIRECTORY: &str = "objects";
-pub const OBJECT_FILE_EXTENSION: &str = "bc";
+pub const OBJECT_FILE_EXTENSION: &str = "o";
 pub const PRELUDE_PACKAGE_DIRECTORY: &str = "prelude";
 
 pub struct FilePathConfiguration {
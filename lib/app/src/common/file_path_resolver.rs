@@ -1,7 +1,8 @@
 use super::{
     file_path::FilePath,
     file_path_configuration::{
-        FilePathConfiguration, INTERFACE_FILE_EXTENSION, OBJECT_FILE_EXTENSION,
+        FilePathConfiguration, BIT_CODE_FILE_EXTENSION, C_SOURCE_FILE_EXTENSION,
+        INTERFACE_FILE_EXTENSION, OBJECT_FILE_EXTENSION,
     },
     package_configuration::ExternalPackage,
     static_file_path_manager::StaticFilePathManager,
@@ -41,6 +42,30 @@ impl<'a> FilePathResolver<'a> {
             .with_extension(OBJECT_FILE_EXTENSION)
     }
 
+    pub fn resolve_bit_code_file_path(&self, id: impl AsRef<str>) -> FilePath {
+        self.resolve_object_file_path(id)
+            .with_extension(BIT_CODE_FILE_EXTENSION)
+    }
+
+    pub fn resolve_c_source_file_path(&self, id: impl AsRef<str>) -> FilePath {
+        self.resolve_object_file_path(id)
+            .with_extension(C_SOURCE_FILE_EXTENSION)
+    }
+
+    // Intermediate representations of modules are written next to their
+    // object files for debugging.
+    pub fn resolve_stage_file_path(&self, id: impl AsRef<str>, stage: lang::Stage) -> FilePath {
+        self.resolve_object_file_path(id)
+            .with_extension(match stage {
+                lang::Stage::Ast => "ast.ein",
+                lang::Stage::TypedAst => "typed.ein",
+                lang::Stage::Eir => "eir",
+                lang::Stage::Fmm => "fmm",
+                lang::Stage::LlvmIr => "ll",
+                lang::Stage::Assembly => "s",
+            })
+    }
+
     pub fn resolve_interface_file_path(&self, id: &str) -> FilePath {
         self.resolve_object_file_path(id)
             .with_extension(INTERFACE_FILE_EXTENSION)
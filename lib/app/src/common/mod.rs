@@ -1,11 +1,17 @@
+mod backend;
 mod file_path;
 mod file_path_configuration;
 mod file_path_resolver;
 mod package_configuration;
+mod profile;
 mod static_file_path_manager;
+mod target_configuration;
 
+pub use backend::*;
 pub use file_path::*;
 pub use file_path_configuration::*;
 pub use file_path_resolver::*;
 pub use package_configuration::*;
+pub use profile::*;
 pub use static_file_path_manager::*;
+pub use target_configuration::*;
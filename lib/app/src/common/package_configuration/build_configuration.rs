@@ -1,18 +1,27 @@
-use super::target::Target;
-use crate::common::ExternalPackage;
-use std::collections::HashSet;
+use super::{ffi_configuration::FfiConfiguration, target::Target};
+use crate::common::{ExternalPackage, Profile, ProfileName};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Debug)]
 pub struct BuildConfiguration {
     target: Target,
     dependencies: HashSet<ExternalPackage>,
+    ffi: Option<FfiConfiguration>,
+    profiles: HashMap<ProfileName, Profile>,
 }
 
 impl BuildConfiguration {
-    pub fn new(target: Target, dependencies: HashSet<ExternalPackage>) -> Self {
+    pub fn new(
+        target: Target,
+        dependencies: HashSet<ExternalPackage>,
+        ffi: Option<FfiConfiguration>,
+        profiles: HashMap<ProfileName, Profile>,
+    ) -> Self {
         Self {
             target,
             dependencies,
+            ffi,
+            profiles,
         }
     }
 
@@ -23,4 +32,19 @@ impl BuildConfiguration {
     pub fn dependencies(&self) -> &HashSet<ExternalPackage> {
         &self.dependencies
     }
+
+    pub fn ffi(&self) -> Option<&FfiConfiguration> {
+        self.ffi.as_ref()
+    }
+
+    pub fn profiles(&self) -> &HashMap<ProfileName, Profile> {
+        &self.profiles
+    }
+
+    pub fn profile(&self, name: ProfileName) -> Profile {
+        self.profiles
+            .get(&name)
+            .cloned()
+            .unwrap_or_else(|| Profile::default_of(name))
+    }
 }
@@ -0,0 +1,28 @@
+use crate::common::FilePath;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FfiSource {
+    Cargo(FilePath),
+    C(Vec<FilePath>),
+    Archives(Vec<FilePath>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FfiConfiguration {
+    source: FfiSource,
+    libraries: Vec<String>,
+}
+
+impl FfiConfiguration {
+    pub fn new(source: FfiSource, libraries: Vec<String>) -> Self {
+        Self { source, libraries }
+    }
+
+    pub fn source(&self) -> &FfiSource {
+        &self.source
+    }
+
+    pub fn libraries(&self) -> &[String] {
+        &self.libraries
+    }
+}
@@ -1,12 +1,14 @@
 mod application_target;
 mod build_configuration;
 mod external_package;
+mod ffi_configuration;
 mod target;
 
 use crate::common::FilePath;
 pub use application_target::ApplicationTarget;
 pub use build_configuration::BuildConfiguration;
 pub use external_package::ExternalPackage;
+pub use ffi_configuration::{FfiConfiguration, FfiSource};
 pub use target::Target;
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,75 @@
+mod optimization_level;
+mod profile_name;
+
+pub use optimization_level::OptimizationLevel;
+pub use profile_name::ProfileName;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Profile {
+    optimization_level: OptimizationLevel,
+    tail_call_optimization: bool,
+    debug_information: bool,
+    strip: bool,
+    link_time_optimization: bool,
+    linker_flags: Vec<String>,
+}
+
+impl Profile {
+    pub fn new(
+        optimization_level: OptimizationLevel,
+        tail_call_optimization: bool,
+        debug_information: bool,
+        strip: bool,
+        link_time_optimization: bool,
+        linker_flags: Vec<String>,
+    ) -> Self {
+        Self {
+            optimization_level,
+            tail_call_optimization,
+            debug_information,
+            strip,
+            link_time_optimization,
+            linker_flags,
+        }
+    }
+
+    pub fn default_of(name: ProfileName) -> Self {
+        match name {
+            ProfileName::Dev => {
+                Self::new(OptimizationLevel::None, true, true, false, false, vec![])
+            }
+            ProfileName::Release => Self::new(
+                OptimizationLevel::Aggressive,
+                true,
+                false,
+                false,
+                false,
+                vec![],
+            ),
+        }
+    }
+
+    pub fn optimization_level(&self) -> OptimizationLevel {
+        self.optimization_level
+    }
+
+    pub fn tail_call_optimization(&self) -> bool {
+        self.tail_call_optimization
+    }
+
+    pub fn debug_information(&self) -> bool {
+        self.debug_information
+    }
+
+    pub fn strip(&self) -> bool {
+        self.strip
+    }
+
+    pub fn link_time_optimization(&self) -> bool {
+        self.link_time_optimization
+    }
+
+    pub fn linker_flags(&self) -> &[String] {
+        &self.linker_flags
+    }
+}
@@ -0,0 +1,51 @@
+use std::convert::TryFrom;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OptimizationLevel {
+    None,
+    Less,
+    Default,
+    Aggressive,
+}
+
+impl From<OptimizationLevel> for u8 {
+    fn from(level: OptimizationLevel) -> Self {
+        match level {
+            OptimizationLevel::None => 0,
+            OptimizationLevel::Less => 1,
+            OptimizationLevel::Default => 2,
+            OptimizationLevel::Aggressive => 3,
+        }
+    }
+}
+
+impl TryFrom<u8> for OptimizationLevel {
+    type Error = String;
+
+    fn try_from(level: u8) -> Result<Self, String> {
+        match level {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Less),
+            2 => Ok(Self::Default),
+            3 => Ok(Self::Aggressive),
+            _ => Err(format!("invalid optimization level: {}", level)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_from_u8() {
+        for level in 0..4 {
+            assert_eq!(u8::from(OptimizationLevel::try_from(level).unwrap()), level);
+        }
+    }
+
+    #[test]
+    fn fail_to_convert_from_u8() {
+        assert!(OptimizationLevel::try_from(4).is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProfileName {
+    Dev,
+    Release,
+}
+
+impl Display for ProfileName {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "{}",
+            match self {
+                Self::Dev => "dev",
+                Self::Release => "release",
+            }
+        )
+    }
+}
+
+impl FromStr for ProfileName {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, ()> {
+        match name {
+            "dev" => Ok(Self::Dev),
+            "release" => Ok(Self::Release),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use super::{
     file_path::FilePath,
     file_path_configuration::{
-        FilePathConfiguration, EXTERNAL_PACKAGES_DIRECTORY, OBJECT_DIRECTORY,
-        PRELUDE_PACKAGE_DIRECTORY,
+        FilePathConfiguration, DOCUMENTATION_DIRECTORY, EXTERNAL_PACKAGES_DIRECTORY,
+        FFI_LIBRARY_DIRECTORY, OBJECT_DIRECTORY, PRELUDE_PACKAGE_DIRECTORY,
     },
 };
 
@@ -11,6 +11,8 @@ pub struct StaticFilePathManager {
     object_directory_path: FilePath,
     external_packages_directory_path: FilePath,
     prelude_package_directory_path: FilePath,
+    ffi_library_directory_path: FilePath,
+    documentation_directory_path: FilePath,
     main_source_file_path: FilePath,
 }
 
@@ -23,8 +25,12 @@ impl StaticFilePathManager {
         Self {
             prelude_package_directory_path: external_packages_directory_path
                 .join(&FilePath::new(&[PRELUDE_PACKAGE_DIRECTORY])),
+            ffi_library_directory_path: external_packages_directory_path
+                .join(&FilePath::new(&[FFI_LIBRARY_DIRECTORY])),
             external_packages_directory_path,
             object_directory_path: output_directory_path.join(&FilePath::new(&[OBJECT_DIRECTORY])),
+            documentation_directory_path: output_directory_path
+                .join(&FilePath::new(&[DOCUMENTATION_DIRECTORY])),
             main_source_file_path: FilePath::new(&[configuration.main_file_basename])
                 .with_extension(configuration.source_file_extension),
             build_configuration_file_path: FilePath::new(&[
@@ -49,6 +55,14 @@ impl StaticFilePathManager {
         &self.prelude_package_directory_path
     }
 
+    pub fn ffi_library_directory_path(&self) -> &FilePath {
+        &self.ffi_library_directory_path
+    }
+
+    pub fn documentation_directory_path(&self) -> &FilePath {
+        &self.documentation_directory_path
+    }
+
     pub fn main_source_file_path(&self) -> &FilePath {
         &self.main_source_file_path
     }
@@ -0,0 +1,37 @@
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TargetConfiguration {
+    triple: String,
+    sysroot: Option<String>,
+}
+
+impl TargetConfiguration {
+    pub fn new(triple: impl Into<String>, sysroot: Option<String>) -> Self {
+        Self {
+            triple: triple.into(),
+            sysroot,
+        }
+    }
+
+    pub fn triple(&self) -> &str {
+        &self.triple
+    }
+
+    pub fn sysroot(&self) -> Option<&str> {
+        self.sysroot.as_deref()
+    }
+
+    pub fn is_wasm(&self) -> bool {
+        lang::is_wasm_target(&self.triple)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_wasm() {
+        assert!(TargetConfiguration::new("wasm32-wasi", None).is_wasm());
+        assert!(!TargetConfiguration::new("aarch64-unknown-linux-gnu", None).is_wasm());
+    }
+}
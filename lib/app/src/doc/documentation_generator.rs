@@ -0,0 +1,58 @@
+use crate::{
+    build::MainPackageLoader,
+    common::{FilePath, StaticFilePathManager},
+    infra::FileSystem,
+};
+
+const INDEX_FILE_BASENAME: &str = "index";
+
+pub struct DocumentationGenerator<'a> {
+    main_package_loader: &'a MainPackageLoader<'a>,
+    file_system: &'a dyn FileSystem,
+    static_file_path_manager: &'a StaticFilePathManager,
+}
+
+impl<'a> DocumentationGenerator<'a> {
+    pub fn new(
+        main_package_loader: &'a MainPackageLoader<'a>,
+        file_system: &'a dyn FileSystem,
+        static_file_path_manager: &'a StaticFilePathManager,
+    ) -> Self {
+        Self {
+            main_package_loader,
+            file_system,
+            static_file_path_manager,
+        }
+    }
+
+    // Module interfaces are obtained by loading packages into an interpreter
+    // which is discarded afterward so that no code is generated.
+    pub fn generate(
+        &self,
+        format: lang::DocumentationFormat,
+    ) -> Result<FilePath, Box<dyn std::error::Error>> {
+        let (_, _, dependency_module_interfaces, module_interfaces) = self
+            .main_package_loader
+            .load(&mut lang::Interpreter::new())?;
+
+        let file_path = self
+            .static_file_path_manager
+            .documentation_directory_path()
+            .join(&FilePath::new(&[INDEX_FILE_BASENAME]))
+            .with_extension(format.file_extension());
+
+        self.file_system.write(
+            &file_path,
+            lang::document(
+                &module_interfaces
+                    .into_iter()
+                    .chain(dependency_module_interfaces)
+                    .collect::<Vec<_>>(),
+                format,
+            )
+            .as_bytes(),
+        )?;
+
+        Ok(file_path)
+    }
+}
@@ -0,0 +1,3 @@
+mod documentation_generator;
+
+pub use documentation_generator::*;
@@ -1,9 +1,11 @@
-use crate::common::FilePath;
+use crate::common::{FilePath, Profile};
 
 pub trait ApplicationLinker {
     fn link(
         &self,
         object_file_paths: &[FilePath],
+        system_libraries: &[String],
         application_name: &str,
+        profile: &Profile,
     ) -> Result<(), Box<dyn std::error::Error>>;
 }
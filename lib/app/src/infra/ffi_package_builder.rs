@@ -0,0 +1,10 @@
+use crate::common::{FfiConfiguration, FilePath, Profile};
+
+pub trait FfiPackageBuilder {
+    fn build(
+        &self,
+        directory_path: &FilePath,
+        ffi_configuration: &FfiConfiguration,
+        profile: &Profile,
+    ) -> Result<Vec<FilePath>, Box<dyn std::error::Error>>;
+}
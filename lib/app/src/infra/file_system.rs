@@ -5,12 +5,17 @@ pub trait FileSystem {
     fn exists(&self, path: &FilePath) -> bool;
     fn is_directory(&self, path: &FilePath) -> bool;
     fn read_directory(&self, path: &FilePath) -> Result<Vec<FilePath>, Box<dyn std::error::Error>>;
+    fn read_modified_time(
+        &self,
+        path: &FilePath,
+    ) -> Result<std::time::SystemTime, Box<dyn std::error::Error>>;
     fn read_repository(
         &self,
         directory_path: &FilePath,
     ) -> Result<Option<Repository>, Box<dyn std::error::Error>>;
     fn read_to_string(&self, path: &FilePath) -> Result<String, Box<dyn std::error::Error>>;
     fn read_to_vec(&self, path: &FilePath) -> Result<Vec<u8>, Box<dyn std::error::Error>>;
+    fn remove(&self, path: &FilePath) -> Result<(), Box<dyn std::error::Error>>;
     fn write(&self, path: &FilePath, data: &[u8]) -> Result<(), Box<dyn std::error::Error>>;
 }
 
@@ -34,11 +39,36 @@ impl FileSystem for FakeFileSystem {
         self.files.lock().unwrap().contains_key(path)
     }
 
-    fn is_directory(&self, _: &FilePath) -> bool {
-        todo!()
+    fn is_directory(&self, path: &FilePath) -> bool {
+        self.files
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|file_path| is_descendant(file_path, path))
+    }
+
+    fn read_directory(&self, path: &FilePath) -> Result<Vec<FilePath>, Box<dyn std::error::Error>> {
+        let mut paths = self
+            .files
+            .lock()
+            .unwrap()
+            .keys()
+            .filter(|file_path| is_descendant(file_path, path))
+            .map(|file_path| {
+                FilePath::new(file_path.components().take(path.components().count() + 1))
+            })
+            .collect::<Vec<_>>();
+
+        paths.sort();
+        paths.dedup();
+
+        Ok(paths)
     }
 
-    fn read_directory(&self, _: &FilePath) -> Result<Vec<FilePath>, Box<dyn std::error::Error>> {
+    fn read_modified_time(
+        &self,
+        _: &FilePath,
+    ) -> Result<std::time::SystemTime, Box<dyn std::error::Error>> {
         todo!()
     }
 
@@ -73,12 +103,30 @@ impl FileSystem for FakeFileSystem {
             .clone())
     }
 
+    fn remove(&self, path: &FilePath) -> Result<(), Box<dyn std::error::Error>> {
+        self.files
+            .lock()
+            .unwrap()
+            .remove(path)
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, ""))?;
+        Ok(())
+    }
+
     fn write(&self, path: &FilePath, data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
         self.files.lock().unwrap().insert(path.clone(), data.into());
         Ok(())
     }
 }
 
+#[cfg(test)]
+fn is_descendant(path: &FilePath, directory_path: &FilePath) -> bool {
+    path.components().count() > directory_path.components().count()
+        && path
+            .components()
+            .zip(directory_path.components())
+            .all(|(one, other)| one == other)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
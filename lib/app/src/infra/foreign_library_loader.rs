@@ -0,0 +1,10 @@
+use crate::common::FilePath;
+
+pub trait ForeignLibraryLoader {
+    fn load(
+        &self,
+        object_file_paths: &[FilePath],
+        system_libraries: &[String],
+        interpreter: &mut lang::Interpreter,
+    ) -> Result<(), Box<dyn std::error::Error>>;
+}
@@ -1,17 +1,19 @@
 mod application_linker;
 mod external_package_downloader;
+mod ffi_package_builder;
 mod ffi_package_initializer;
 mod file_path_displayer;
 mod file_system;
+mod foreign_library_loader;
 mod logger;
-mod prelude_package_downloader;
 mod repository;
 
 pub use application_linker::*;
 pub use external_package_downloader::*;
+pub use ffi_package_builder::*;
 pub use ffi_package_initializer::*;
 pub use file_path_displayer::*;
 pub use file_system::*;
+pub use foreign_library_loader::*;
 pub use logger::*;
-pub use prelude_package_downloader::*;
 pub use repository::*;
@@ -33,6 +33,8 @@ impl<'a> PackageInitializer<'a> {
             serialize_build_configuration(&BuildConfiguration::new(
                 target.clone(),
                 Default::default(),
+                None,
+                Default::default(),
             ))?
             .as_bytes(),
         )?;
@@ -1,10 +1,14 @@
 mod adaptors;
 mod build;
 mod common;
+mod doc;
 mod infra;
 mod init;
+mod repl;
 
 pub use build::*;
 pub use common::*;
+pub use doc::*;
 pub use infra::*;
 pub use init::*;
+pub use repl::*;
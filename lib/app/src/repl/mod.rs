@@ -0,0 +1,3 @@
+mod repl_initializer;
+
+pub use repl_initializer::*;
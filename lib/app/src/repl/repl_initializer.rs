@@ -0,0 +1,56 @@
+use crate::build::MainPackageLoader;
+use std::sync::Arc;
+
+// The module name is not an identifier so that it does not collide with ones
+// of modules in packages in practice.
+const REPL_MODULE_NAME: &str = "$Repl";
+
+pub struct ReplInitializer<'a> {
+    main_package_loader: &'a MainPackageLoader<'a>,
+    compile_configuration: Arc<lang::CompileConfiguration>,
+}
+
+impl<'a> ReplInitializer<'a> {
+    pub fn new(
+        main_package_loader: &'a MainPackageLoader<'a>,
+        compile_configuration: Arc<lang::CompileConfiguration>,
+    ) -> Self {
+        Self {
+            main_package_loader,
+            compile_configuration,
+        }
+    }
+
+    // Modules of a package and its dependencies are loaded into an
+    // interpreter and imported into a REPL module.
+    pub fn initialize(
+        &self,
+        mut interpreter: lang::Interpreter,
+    ) -> Result<lang::Repl, Box<dyn std::error::Error>> {
+        let (
+            package_configuration,
+            prelude_module_interfaces,
+            dependency_module_interfaces,
+            module_interfaces,
+        ) = self.main_package_loader.load(&mut interpreter)?;
+
+        Ok(lang::Repl::new(
+            lang::ModulePath::new(
+                package_configuration.package().clone(),
+                vec![REPL_MODULE_NAME.into()],
+            ),
+            dependency_module_interfaces
+                .into_iter()
+                .chain(module_interfaces)
+                .map(|module_interface| lang::Import::new(module_interface, true))
+                .chain(
+                    prelude_module_interfaces
+                        .into_iter()
+                        .map(|module_interface| lang::Import::new(module_interface, false)),
+                )
+                .collect(),
+            interpreter,
+            self.compile_configuration.clone(),
+        ))
+    }
+}
@@ -0,0 +1,94 @@
+// Type information is shared by all values of each type and lets anyone clone
+// or drop their payloads without knowing the types.
+#[repr(C)]
+pub struct TypeInformation {
+    pub clone: extern "C" fn(u64),
+    pub drop: extern "C" fn(u64),
+}
+
+#[repr(C)]
+pub struct Any {
+    type_information: &'static TypeInformation,
+    payload: u64,
+}
+
+impl Any {
+    pub fn new(type_information: &'static TypeInformation, payload: u64) -> Self {
+        Self {
+            type_information,
+            payload,
+        }
+    }
+
+    pub fn type_information(&self) -> &'static TypeInformation {
+        self.type_information
+    }
+
+    pub fn payload(&self) -> u64 {
+        self.payload
+    }
+
+    pub fn into_payload(self) -> u64 {
+        let payload = self.payload;
+
+        std::mem::forget(self);
+
+        payload
+    }
+}
+
+unsafe impl Sync for Any {}
+
+impl Clone for Any {
+    fn clone(&self) -> Self {
+        (self.type_information.clone)(self.payload);
+
+        Self {
+            type_information: self.type_information,
+            payload: self.payload,
+        }
+    }
+}
+
+impl Drop for Any {
+    fn drop(&mut self) {
+        (self.type_information.drop)(self.payload);
+    }
+}
+
+impl std::fmt::Debug for Any {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "Any({:x})", self.payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn clone(_: u64) {
+        COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+
+    extern "C" fn drop(_: u64) {
+        COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    static TYPE_INFORMATION: TypeInformation = TypeInformation { clone, drop };
+
+    #[test]
+    fn clone_and_drop() {
+        let any = Any::new(&TYPE_INFORMATION, 42);
+
+        COUNT.fetch_add(1, Ordering::SeqCst);
+        let other = any.clone();
+        assert_eq!(other.payload(), 42);
+        std::mem::drop(other);
+        std::mem::drop(any);
+
+        assert_eq!(COUNT.load(Ordering::SeqCst), 0);
+    }
+}
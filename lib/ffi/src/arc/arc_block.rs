@@ -37,6 +37,14 @@ impl ArcBlock {
         Self { pointer: null() }
     }
 
+    pub fn from_raw(pointer: *const u8) -> Self {
+        Self { pointer }
+    }
+
+    pub fn as_raw(&self) -> *const u8 {
+        self.pointer
+    }
+
     pub fn ptr(&self) -> *const u8 {
         &self.inner().payload as *const () as *const u8
     }
@@ -88,7 +96,7 @@ impl ArcBlock {
             fence(Ordering::Acquire);
 
             unsafe {
-                drop_in_place(self.inner_pointer() as *mut T);
+                drop_in_place(self.ptr_mut() as *mut T);
 
                 // This layout is expected not to be used.
                 dealloc(
@@ -124,6 +132,20 @@ mod tests {
         ArcBlock::new(Layout::from_size_align(1, 1).unwrap()).drop::<u8>();
     }
 
+    #[test]
+    fn drop_payload() {
+        let payload = std::sync::Arc::new(());
+        let mut arc = ArcBlock::new(Layout::new::<std::sync::Arc<()>>());
+
+        unsafe { std::ptr::write(arc.ptr_mut() as *mut std::sync::Arc<()>, payload.clone()) }
+
+        assert_eq!(std::sync::Arc::strong_count(&payload), 2);
+
+        arc.drop::<std::sync::Arc<()>>();
+
+        assert_eq!(std::sync::Arc::strong_count(&payload), 1);
+    }
+
     #[test]
     fn drop_twice() {
         let mut arc = ArcBlock::new(Layout::from_size_align(1, 1).unwrap());
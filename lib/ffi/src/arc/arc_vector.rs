@@ -0,0 +1,401 @@
+use super::Arc;
+use std::cmp::max;
+
+// Vectors are height-balanced binary trees with elements at their leaves. So
+// indexing, updates, slices and concatenations take O(log n) time while
+// unchanged subtrees are shared among vectors.
+#[derive(Debug)]
+#[repr(C)]
+pub struct ArcVector<T> {
+    root: Arc<Node<T>>,
+}
+
+#[derive(Debug)]
+enum Node<T> {
+    Empty,
+    Leaf(T),
+    Branch {
+        left: Arc<Node<T>>,
+        right: Arc<Node<T>>,
+        length: usize,
+        height: usize,
+    },
+}
+
+impl<T> ArcVector<T> {
+    pub fn new() -> Self {
+        Self { root: empty() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.root.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let mut node = &*self.root;
+        let mut index = index;
+
+        loop {
+            match node {
+                Node::Empty => return None,
+                Node::Leaf(element) => return if index == 0 { Some(element) } else { None },
+                Node::Branch { left, right, .. } => {
+                    if index < left.len() {
+                        node = left;
+                    } else {
+                        index -= left.len();
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn set(&self, index: usize, element: T) -> Self {
+        if index >= self.len() {
+            self.clone()
+        } else {
+            Self {
+                root: set(&self.root, index, element),
+            }
+        }
+    }
+
+    pub fn push(&self, element: T) -> Self {
+        Self {
+            root: join(&self.root, &Node::Leaf(element).into()),
+        }
+    }
+
+    pub fn pop(&self) -> Self {
+        self.slice(0, self.len().saturating_sub(1))
+    }
+
+    // Indices start from 0 and ends are exclusive.
+    pub fn slice(&self, start: usize, end: usize) -> Self {
+        let end = end.min(self.len());
+
+        if start >= end {
+            return Self::new();
+        }
+
+        Self {
+            root: split(&split(&self.root, end).0, start).1,
+        }
+    }
+
+    pub fn concatenate(&self, other: &Self) -> Self {
+        Self {
+            root: join(&self.root, &other.root),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut nodes = vec![&*self.root];
+
+        std::iter::from_fn(move || loop {
+            match nodes.pop()? {
+                Node::Empty => {}
+                Node::Leaf(element) => return Some(element),
+                Node::Branch { left, right, .. } => {
+                    nodes.push(right);
+                    nodes.push(left);
+                }
+            }
+        })
+    }
+}
+
+impl<T> Clone for ArcVector<T> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+        }
+    }
+}
+
+impl<T> Default for ArcVector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> From<&[T]> for ArcVector<T> {
+    fn from(elements: &[T]) -> Self {
+        elements
+            .iter()
+            .fold(Self::new(), |vector, element| vector.push(element.clone()))
+    }
+}
+
+impl<T> Node<T> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::Leaf(_) => 1,
+            Self::Branch { length, .. } => *length,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::Leaf(_) => 1,
+            Self::Branch { height, .. } => *height,
+        }
+    }
+}
+
+fn empty<T>() -> Arc<Node<T>> {
+    Node::Empty.into()
+}
+
+fn branch<T>(left: Arc<Node<T>>, right: Arc<Node<T>>) -> Arc<Node<T>> {
+    Node::Branch {
+        length: left.len() + right.len(),
+        height: max(left.height(), right.height()) + 1,
+        left,
+        right,
+    }
+    .into()
+}
+
+fn set<T>(node: &Arc<Node<T>>, index: usize, element: T) -> Arc<Node<T>> {
+    match &**node {
+        Node::Branch { left, right, .. } => {
+            if index < left.len() {
+                branch(set(left, index, element), right.clone())
+            } else {
+                branch(left.clone(), set(right, index - left.len(), element))
+            }
+        }
+        Node::Empty | Node::Leaf(_) => Node::Leaf(element).into(),
+    }
+}
+
+// Trees are joined by descending along the taller one until heights of
+// subtrees match.
+fn join<T>(left: &Arc<Node<T>>, right: &Arc<Node<T>>) -> Arc<Node<T>> {
+    if left.len() == 0 {
+        return right.clone();
+    } else if right.len() == 0 {
+        return left.clone();
+    }
+
+    match (&**left, &**right) {
+        (
+            Node::Branch {
+                left: left_left,
+                right: left_right,
+                ..
+            },
+            _,
+        ) if left.height() > right.height() + 1 => {
+            balance(left_left.clone(), join(left_right, right))
+        }
+        (
+            _,
+            Node::Branch {
+                left: right_left,
+                right: right_right,
+                ..
+            },
+        ) if right.height() > left.height() + 1 => {
+            balance(join(left, right_left), right_right.clone())
+        }
+        _ => branch(left.clone(), right.clone()),
+    }
+}
+
+// Heights of subtrees differ by 2 at most.
+fn balance<T>(left: Arc<Node<T>>, right: Arc<Node<T>>) -> Arc<Node<T>> {
+    if left.height() > right.height() + 1 {
+        if let Node::Branch {
+            left: left_left,
+            right: left_right,
+            ..
+        } = &*left
+        {
+            if left_left.height() >= left_right.height() {
+                return branch(left_left.clone(), branch(left_right.clone(), right));
+            } else if let Node::Branch {
+                left: left_right_left,
+                right: left_right_right,
+                ..
+            } = &**left_right
+            {
+                return branch(
+                    branch(left_left.clone(), left_right_left.clone()),
+                    branch(left_right_right.clone(), right),
+                );
+            }
+        }
+    } else if right.height() > left.height() + 1 {
+        if let Node::Branch {
+            left: right_left,
+            right: right_right,
+            ..
+        } = &*right
+        {
+            if right_right.height() >= right_left.height() {
+                return branch(branch(left, right_left.clone()), right_right.clone());
+            } else if let Node::Branch {
+                left: right_left_left,
+                right: right_left_right,
+                ..
+            } = &**right_left
+            {
+                return branch(
+                    branch(left, right_left_left.clone()),
+                    branch(right_left_right.clone(), right_right.clone()),
+                );
+            }
+        }
+    }
+
+    branch(left, right)
+}
+
+// Trees are split into ones with the first `index` elements and the others.
+fn split<T>(node: &Arc<Node<T>>, index: usize) -> (Arc<Node<T>>, Arc<Node<T>>) {
+    if index == 0 {
+        return (empty(), node.clone());
+    } else if index >= node.len() {
+        return (node.clone(), empty());
+    }
+
+    match &**node {
+        Node::Branch { left, right, .. } => {
+            if index <= left.len() {
+                let (one, other) = split(left, index);
+
+                (one, join(&other, right))
+            } else {
+                let (one, other) = split(right, index - left.len());
+
+                (join(left, &one), other)
+            }
+        }
+        Node::Empty | Node::Leaf(_) => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_vector(length: usize) -> ArcVector<usize> {
+        (0..length).collect::<Vec<_>>().as_slice().into()
+    }
+
+    fn to_vec(vector: &ArcVector<usize>) -> Vec<usize> {
+        vector.iter().cloned().collect()
+    }
+
+    fn check_balance(node: &Node<usize>) {
+        if let Node::Branch {
+            left,
+            right,
+            length,
+            height,
+        } = node
+        {
+            assert!(left.height().max(right.height()) - left.height().min(right.height()) <= 1);
+            assert_eq!(*height, left.height().max(right.height()) + 1);
+            assert_eq!(*length, left.len() + right.len());
+
+            check_balance(left);
+            check_balance(right);
+        }
+    }
+
+    #[test]
+    fn create() {
+        assert!(ArcVector::<usize>::new().is_empty());
+    }
+
+    #[test]
+    fn push() {
+        for length in 0..64 {
+            let vector = create_vector(length);
+
+            assert_eq!(to_vec(&vector), (0..length).collect::<Vec<_>>());
+            check_balance(&vector.root);
+        }
+    }
+
+    #[test]
+    fn get() {
+        let vector = create_vector(42);
+
+        for index in 0..42 {
+            assert_eq!(vector.get(index), Some(&index));
+        }
+
+        assert_eq!(vector.get(42), None);
+    }
+
+    #[test]
+    fn set() {
+        let vector = create_vector(42);
+        let other = vector.set(13, 0);
+
+        assert_eq!(other.get(13), Some(&0));
+        assert_eq!(vector.get(13), Some(&13));
+        assert_eq!(to_vec(&vector.set(42, 0)), to_vec(&vector));
+    }
+
+    #[test]
+    fn pop() {
+        assert_eq!(to_vec(&create_vector(3).pop()), vec![0, 1]);
+        assert!(ArcVector::<usize>::new().pop().is_empty());
+    }
+
+    #[test]
+    fn slice() {
+        let vector = create_vector(42);
+
+        for start in 0..44 {
+            for end in 0..44 {
+                let slice = vector.slice(start, end);
+
+                assert_eq!(
+                    to_vec(&slice),
+                    (start..end.min(42)).collect::<Vec<_>>(),
+                    "{} {}",
+                    start,
+                    end
+                );
+                check_balance(&slice.root);
+            }
+        }
+    }
+
+    #[test]
+    fn concatenate() {
+        for one in 0..32 {
+            for other in 0..32 {
+                let vector = create_vector(one)
+                    .concatenate(&(one..one + other).collect::<Vec<_>>().as_slice().into());
+
+                assert_eq!(to_vec(&vector), (0..one + other).collect::<Vec<_>>());
+                check_balance(&vector.root);
+            }
+        }
+    }
+
+    #[test]
+    fn drop_elements() {
+        let element = std::sync::Arc::new(());
+        let vector = ArcVector::new().push(element.clone()).push(element.clone());
+
+        drop(vector.pop());
+        drop(vector);
+
+        assert_eq!(std::sync::Arc::strong_count(&element), 1);
+    }
+}
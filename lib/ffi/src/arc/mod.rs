@@ -1,9 +1,11 @@
 mod arc_block;
 mod arc_buffer;
+mod arc_vector;
 
 use arc_block::*;
 pub use arc_buffer::*;
-use std::{alloc::Layout, marker::PhantomData, ops::Deref};
+pub use arc_vector::*;
+use std::{alloc::Layout, marker::PhantomData, mem::forget, ops::Deref, ptr::write};
 
 #[derive(Debug)]
 #[repr(C)]
@@ -16,13 +18,29 @@ impl<T> Arc<T> {
     pub fn new(payload: T) -> Self {
         let mut block = ArcBlock::new(Layout::new::<T>());
 
-        unsafe {
-            *(block.ptr_mut() as *mut T) = payload;
-        }
+        unsafe { write(block.ptr_mut() as *mut T, payload) }
 
         Self {
             block,
-            phantom: PhantomData::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn into_raw(self) -> *const T {
+        let pointer = self.block.as_raw();
+
+        forget(self);
+
+        pointer as *const T
+    }
+
+    /// # Safety
+    ///
+    /// Pointers must be ones returned by `Arc::into_raw`.
+    pub unsafe fn from_raw(pointer: *const T) -> Self {
+        Self {
+            block: ArcBlock::from_raw(pointer as *const u8),
+            phantom: PhantomData,
         }
     }
 }
@@ -45,7 +63,7 @@ impl<T> Clone for Arc<T> {
     fn clone(&self) -> Self {
         Self {
             block: self.block.clone(),
-            phantom: PhantomData::default(),
+            phantom: PhantomData,
         }
     }
 }
@@ -59,6 +77,7 @@ impl<T> Drop for Arc<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     fn drop<T>(_: T) {}
 
@@ -79,6 +98,45 @@ mod tests {
         assert_eq!(*Arc::new(42), 42);
     }
 
+    #[test]
+    fn drop_payload() {
+        let payload = std::sync::Arc::new(());
+
+        drop(Arc::new(payload.clone()));
+
+        assert_eq!(std::sync::Arc::strong_count(&payload), 1);
+    }
+
+    #[test]
+    fn initialize_payload_without_dropping_uninitialized_memory() {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        struct Payload(u8);
+
+        impl std::ops::Drop for Payload {
+            fn drop(&mut self) {
+                COUNT.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let arc = Arc::new(Payload(42));
+
+        assert_eq!(COUNT.load(Ordering::SeqCst), 0);
+        assert_eq!(arc.0, 42);
+
+        drop(arc);
+
+        assert_eq!(COUNT.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn convert_from_raw_pointer() {
+        let arc = Arc::new(42);
+
+        assert_eq!(*unsafe { Arc::from_raw(arc.clone().into_raw()) }, 42);
+        assert_eq!(*arc, 42);
+    }
+
     mod zero_sized {
         use super::*;
 
@@ -0,0 +1,198 @@
+use super::{
+    any::{Any, TypeInformation},
+    arc::{Arc, ArcVector},
+    number::Number,
+};
+
+static TYPE_INFORMATION: TypeInformation = TypeInformation {
+    clone: clone_array,
+    drop: drop_array,
+};
+
+// Arrays are passed to Ein as Any values with their own type information.
+#[repr(C)]
+#[derive(Clone, Debug, Default)]
+pub struct Array {
+    vector: ArcVector<Any>,
+}
+
+impl Array {
+    pub fn new(vector: ArcVector<Any>) -> Self {
+        Self { vector }
+    }
+
+    pub fn len(&self) -> usize {
+        self.vector.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vector.is_empty()
+    }
+
+    // Indices start from 1.
+    pub fn get(&self, index: Number) -> Option<&Any> {
+        self.vector.get(Self::convert_index(index)?)
+    }
+
+    pub fn set(&self, index: Number, element: Any) -> Self {
+        match Self::convert_index(index) {
+            Some(index) => Self {
+                vector: self.vector.set(index, element),
+            },
+            None => self.clone(),
+        }
+    }
+
+    pub fn push(&self, element: Any) -> Self {
+        Self {
+            vector: self.vector.push(element),
+        }
+    }
+
+    pub fn pop(&self) -> Self {
+        Self {
+            vector: self.vector.pop(),
+        }
+    }
+
+    // Indices are inclusive and start from 1.
+    pub fn slice(&self, start: Number, end: Number) -> Self {
+        let start = f64::from(start);
+        let end = f64::from(end);
+
+        if start.is_nan() || end.is_nan() {
+            return Self::default();
+        }
+
+        Self {
+            vector: self
+                .vector
+                .slice(start.max(1.0) as usize - 1, end.max(0.0) as usize),
+        }
+    }
+
+    pub fn concatenate(&self, other: &Self) -> Self {
+        Self {
+            vector: self.vector.concatenate(&other.vector),
+        }
+    }
+
+    fn convert_index(index: Number) -> Option<usize> {
+        let index = f64::from(index);
+
+        if index >= 1.0 && index.fract() == 0.0 {
+            Some(index as usize - 1)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<Array> for Any {
+    fn from(array: Array) -> Self {
+        Any::new(
+            &TYPE_INFORMATION,
+            Arc::into_raw(Arc::new(array.vector)) as u64,
+        )
+    }
+}
+
+impl From<Any> for Array {
+    fn from(any: Any) -> Self {
+        assert!(std::ptr::eq(any.type_information(), &TYPE_INFORMATION));
+
+        Self {
+            vector: (*unsafe { Arc::from_raw(any.into_payload() as *const ArcVector<Any>) })
+                .clone(),
+        }
+    }
+}
+
+extern "C" fn clone_array(payload: u64) {
+    let arc = unsafe { Arc::from_raw(payload as *const ArcVector<Any>) };
+
+    std::mem::forget(arc.clone());
+    std::mem::forget(arc);
+}
+
+extern "C" fn drop_array(payload: u64) {
+    drop(unsafe { Arc::from_raw(payload as *const ArcVector<Any>) });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Elements are arrays themselves so that they are reference-counted.
+    fn create_array(length: usize) -> Array {
+        (0..length).fold(Array::default(), |array, _| {
+            array.push(Array::default().into())
+        })
+    }
+
+    #[test]
+    fn convert_to_any() {
+        let array = Array::from(Any::from(create_array(3)));
+
+        assert_eq!(array.len(), 3);
+    }
+
+    #[test]
+    fn clone_any() {
+        let any = Any::from(create_array(3));
+        let other = any.clone();
+
+        drop(any);
+
+        assert_eq!(Array::from(other).len(), 3);
+    }
+
+    #[test]
+    fn get() {
+        let array = create_array(3);
+
+        assert!(array.get(0.0.into()).is_none());
+        assert!(array.get(1.0.into()).is_some());
+        assert!(array.get(3.0.into()).is_some());
+        assert!(array.get(1.5.into()).is_none());
+        assert!(array.get(4.0.into()).is_none());
+    }
+
+    #[test]
+    fn set() {
+        let array = create_array(3);
+
+        assert_eq!(
+            Array::from(
+                array
+                    .set(2.0.into(), create_array(42).into())
+                    .get(2.0.into())
+                    .unwrap()
+                    .clone()
+            )
+            .len(),
+            42
+        );
+        assert_eq!(array.set(0.0.into(), create_array(42).into()).len(), 3);
+    }
+
+    #[test]
+    fn pop() {
+        assert_eq!(create_array(3).pop().len(), 2);
+    }
+
+    #[test]
+    fn slice() {
+        let array = create_array(3);
+
+        assert_eq!(array.slice(2.0.into(), 3.0.into()).len(), 2);
+        assert_eq!(array.slice((-1.0).into(), 42.0.into()).len(), 3);
+        assert_eq!(array.slice(3.0.into(), 2.0.into()).len(), 0);
+        assert_eq!(array.slice(f64::NAN.into(), 2.0.into()).len(), 0);
+    }
+
+    #[test]
+    fn concatenate() {
+        assert_eq!(create_array(2).concatenate(&create_array(3)).len(), 5);
+    }
+}
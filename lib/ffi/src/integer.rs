@@ -0,0 +1,54 @@
+// Integers are passed as bits of floating-point numbers as EIR does not have
+// any integer type.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct Integer {
+    bits: f64,
+}
+
+impl Integer {
+    pub fn new(value: i64) -> Self {
+        Self {
+            bits: f64::from_bits(value as u64),
+        }
+    }
+}
+
+// Integers are compared by their bits as some of them are NaN as numbers.
+impl PartialEq for Integer {
+    fn eq(&self, other: &Self) -> bool {
+        i64::from(*self) == i64::from(*other)
+    }
+}
+
+impl Eq for Integer {}
+
+impl std::fmt::Debug for Integer {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "Integer({})", i64::from(*self))
+    }
+}
+
+impl From<Integer> for i64 {
+    fn from(integer: Integer) -> Self {
+        integer.bits.to_bits() as i64
+    }
+}
+
+impl From<i64> for Integer {
+    fn from(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert() {
+        for &value in &[0, 1, -1, 42, i64::MAX, i64::MIN] {
+            assert_eq!(i64::from(Integer::from(value)), value);
+        }
+    }
+}
@@ -1,14 +1,20 @@
+mod any;
 mod arc;
+mod array;
 mod boolean;
 mod closure;
 pub mod cps;
+mod integer;
 mod none;
 mod number;
 mod string;
 
+pub use any::*;
 pub use arc::*;
+pub use array::*;
 pub use boolean::*;
 pub use closure::*;
+pub use integer::*;
 pub use none::*;
 pub use number::*;
 pub use string::*;
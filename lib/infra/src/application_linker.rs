@@ -1,72 +1,233 @@
-use super::{command_runner::CommandRunner, file_path_converter::FilePathConverter};
+use super::{
+    clang_target_arguments::clang_target_arguments,
+    command_finder::CommandFinder,
+    command_runner::CommandRunner,
+    error::InfrastructureError,
+    file_path_converter::FilePathConverter,
+    system_library_arguments::system_library_arguments,
+    wasm::{is_wasm_target, validate_wasi_command},
+};
+
+const C_COMPILER_VARIABLE: &str = "CC";
+const DEFAULT_C_COMPILER: &str = "cc";
+const WASM_FILE_EXTENSION: &str = "wasm";
 
 pub struct ApplicationLinker<'a> {
     command_runner: &'a CommandRunner,
+    command_finder: &'a CommandFinder,
     file_path_converter: &'a FilePathConverter,
+    static_file_path_manager: &'a app::StaticFilePathManager,
+    target_configuration: Option<&'a app::TargetConfiguration>,
 }
 
 impl<'a> ApplicationLinker<'a> {
     pub fn new(
         command_runner: &'a CommandRunner,
+        command_finder: &'a CommandFinder,
         file_path_converter: &'a FilePathConverter,
+        static_file_path_manager: &'a app::StaticFilePathManager,
+        target_configuration: Option<&'a app::TargetConfiguration>,
     ) -> Self {
         Self {
             command_runner,
+            command_finder,
             file_path_converter,
+            static_file_path_manager,
+            target_configuration,
         }
     }
+
+    // Modules compiled by the C backend are compiled and linked by any C
+    // compiler specified by the `CC` environment variable.
+    fn link_c_sources(
+        &self,
+        c_source_paths: &[std::path::PathBuf],
+        object_paths: &[std::path::PathBuf],
+        system_libraries: &[String],
+        application_name: &str,
+        profile: &app::Profile,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let c_compiler =
+            std::env::var(C_COMPILER_VARIABLE).unwrap_or_else(|_| DEFAULT_C_COMPILER.into());
+        // Targets are detected from C compilers as they can be cross compilers.
+        let target_triple = self
+            .command_runner
+            .run(std::process::Command::new(&c_compiler).arg("-dumpmachine"))?;
+
+        self.command_runner.run(
+            std::process::Command::new(&c_compiler)
+                .arg("-std=c11")
+                .arg("-o")
+                .arg(
+                    self.file_path_converter
+                        .convert_to_os_path(&app::FilePath::new(&[application_name])),
+                )
+                .arg(format!("-O{}", u8::from(profile.optimization_level())))
+                .args(if profile.debug_information() {
+                    Some("-g")
+                } else {
+                    None
+                })
+                .args(if profile.strip() { Some("-s") } else { None })
+                .args(c_source_paths)
+                .args(object_paths)
+                .args(system_library_arguments(Some(target_triple.trim())))
+                .args(
+                    system_libraries
+                        .iter()
+                        .map(|library| format!("-l{}", library)),
+                )
+                .args(profile.linker_flags()),
+        )?;
+
+        Ok(())
+    }
+
+    // Merge all bitcode files into one, optimize it as a whole so that
+    // functions can be inlined across modules and packages, and compile it
+    // into an object file.
+    fn link_bitcode(
+        &self,
+        bitcode_paths: &[std::path::PathBuf],
+        application_name: &str,
+        profile: &app::Profile,
+    ) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+        let path = self.file_path_converter.convert_to_os_path(
+            &self
+                .static_file_path_manager
+                .object_directory_path()
+                .join(&app::FilePath::new(&[application_name]))
+                .with_extension("bc"),
+        );
+
+        self.command_runner.run(
+            std::process::Command::new(self.command_finder.find("llvm-link")?)
+                .arg("-o")
+                .arg(&path)
+                .args(bitcode_paths),
+        )?;
+
+        self.command_runner.run(
+            std::process::Command::new(self.command_finder.find("opt")?)
+                .arg(format!("-O{}", u8::from(profile.optimization_level())))
+                .arg("-o")
+                .arg(&path)
+                .arg(&path),
+        )?;
+
+        // Tail calls are guaranteed by the calling convention of functions
+        // emitted by the compiler and no extra option is required here.
+        self.command_runner.run(
+            std::process::Command::new(self.command_finder.find("llc")?)
+                .arg(format!("-O{}", u8::from(profile.optimization_level())))
+                .args(
+                    self.target_configuration
+                        .map(|configuration| format!("-mtriple={}", configuration.triple())),
+                )
+                .args(if is_wasm_target(self.target_configuration) {
+                    vec!["--relocation-model=static"]
+                        .into_iter()
+                        .chain(if profile.tail_call_optimization() {
+                            Some("-mattr=+tail-call")
+                        } else {
+                            None
+                        })
+                        .collect()
+                } else {
+                    vec!["--relocation-model=pic"]
+                })
+                .arg("-filetype=obj")
+                .arg(&path),
+        )?;
+
+        Ok(path.with_extension("o"))
+    }
 }
 
 impl<'a> app::ApplicationLinker for ApplicationLinker<'a> {
     fn link(
         &self,
         object_file_paths: &[app::FilePath],
+        system_libraries: &[String],
         application_name: &str,
+        profile: &app::Profile,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let (bitcode_paths, ffi_paths) = object_file_paths
+        let (bitcode_paths, object_paths) = object_file_paths
             .iter()
             .map(|path| self.file_path_converter.convert_to_os_path(path))
             .partition::<Vec<_>, _>(|path| path.extension() == Some(std::ffi::OsStr::new("bc")));
-        let llc = which::which("llc-13")
-            .or_else(|_| which::which("llc-12"))
-            .or_else(|_| which::which("llc-11"))
-            .or_else(|_| which::which("llc"))?;
-
-        for path in &bitcode_paths {
-            // LLVM C API doesn't seem to support the tailcallopt pass directly.
-            // So we compile each bitcode file with the pass manually in order
-            // to optimize all tail calls.
-            self.command_runner.run(
-                std::process::Command::new(&llc)
-                    .arg("-O3")
-                    .arg("-tailcallopt")
-                    .arg("--relocation-model=pic")
-                    .arg("-filetype=obj")
-                    .arg(path),
-            )?;
+        let (c_source_paths, object_paths) = object_paths
+            .into_iter()
+            .partition::<Vec<_>, _>(|path| path.extension() == Some(std::ffi::OsStr::new("c")));
+        let bitcode_object_paths = if bitcode_paths.is_empty() {
+            vec![]
+        } else {
+            vec![self.link_bitcode(&bitcode_paths, application_name, profile)?]
+        };
+
+        if !c_source_paths.is_empty() {
+            return self.link_c_sources(
+                &c_source_paths,
+                &bitcode_object_paths
+                    .into_iter()
+                    .chain(object_paths)
+                    .collect::<Vec<_>>(),
+                system_libraries,
+                application_name,
+                profile,
+            );
         }
 
+        let application_path = self
+            .file_path_converter
+            .convert_to_os_path(&app::FilePath::new(&[
+                // WASI runtimes expect the file extension for WebAssembly modules.
+                if is_wasm_target(self.target_configuration) {
+                    format!("{}.{}", application_name, WASM_FILE_EXTENSION)
+                } else {
+                    application_name.into()
+                },
+            ]));
+
         self.command_runner.run(
             std::process::Command::new("clang")
                 .arg("-Werror") // cspell:disable-line
                 .arg("-Wno-incompatible-pointer-types-discards-qualifiers") // cspell:disable-line
                 .arg("-Wno-override-module") // cspell:disable-line
                 .arg("-o")
-                .arg(
-                    self.file_path_converter
-                        .convert_to_os_path(&app::FilePath::new(&[application_name])),
+                .arg(&application_path)
+                .arg(format!("-O{}", u8::from(profile.optimization_level())))
+                .args(if profile.debug_information() {
+                    Some("-g")
+                } else {
+                    None
+                })
+                .args(clang_target_arguments(self.target_configuration))
+                .args(if profile.strip() { Some("-s") } else { None })
+                .args(bitcode_object_paths)
+                .args(object_paths)
+                .args(system_library_arguments(
+                    self.target_configuration
+                        .map(|configuration| configuration.triple()),
+                ))
+                .args(
+                    system_libraries
+                        .iter()
+                        .map(|library| format!("-l{}", library)),
                 )
-                .arg("-O3")
-                .args(bitcode_paths.iter().map(|path| {
-                    let mut path = path.clone();
-                    path.set_extension("o");
-                    path
-                }))
-                .args(ffi_paths)
-                .arg("-ldl")
-                .arg("-lpthread"),
+                .args(profile.linker_flags()),
         )?;
 
+        // Linkers build WebAssembly modules without entry points silently.
+        if is_wasm_target(self.target_configuration) {
+            validate_wasi_command(&std::fs::read(&application_path).map_err(|source| {
+                InfrastructureError::ReadFile {
+                    path: application_path.clone(),
+                    source,
+                }
+            })?)?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,40 @@
+pub(crate) fn clang_target_arguments(
+    target_configuration: Option<&app::TargetConfiguration>,
+) -> Vec<String> {
+    target_configuration
+        .map(|configuration| {
+            vec![format!("--target={}", configuration.triple())]
+                .into_iter()
+                .chain(
+                    configuration
+                        .sysroot()
+                        .map(|sysroot| format!("--sysroot={}", sysroot)),
+                )
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_no_argument_for_host() {
+        assert_eq!(clang_target_arguments(None), Vec::<String>::new());
+    }
+
+    #[test]
+    fn create_arguments_with_sysroot() {
+        assert_eq!(
+            clang_target_arguments(Some(&app::TargetConfiguration::new(
+                "aarch64-unknown-linux-gnu",
+                Some("/usr/aarch64-linux-gnu".into())
+            ))),
+            vec![
+                "--target=aarch64-unknown-linux-gnu",
+                "--sysroot=/usr/aarch64-linux-gnu"
+            ]
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use super::error::InfrastructureError;
+
+const LLVM_VERSIONS: &[&str] = &["14"];
+
+pub struct RequiredCommand {
+    pub name: &'static str,
+    pub purpose: &'static str,
+    pub hint: &'static str,
+    pub version_option: Option<&'static str>,
+    pub llvm: bool,
+    // Optional commands are needed only by some packages or profiles.
+    pub optional: bool,
+}
+
+pub const REQUIRED_COMMANDS: &[RequiredCommand] = &[
+    RequiredCommand {
+        name: "clang",
+        purpose: "compiling C sources and linking executables",
+        hint: "install clang from your package manager or https://releases.llvm.org",
+        version_option: Some("--version"),
+        llvm: false,
+        optional: false,
+    },
+    RequiredCommand {
+        name: "llc",
+        purpose: "compiling bitcode on link-time optimization",
+        hint: "install LLVM 14 with https://apt.llvm.org or Homebrew",
+        version_option: Some("--version"),
+        llvm: true,
+        optional: true,
+    },
+    RequiredCommand {
+        name: "llvm-link",
+        purpose: "merging bitcode on link-time optimization",
+        hint: "install LLVM 14 with https://apt.llvm.org or Homebrew",
+        version_option: Some("--version"),
+        llvm: true,
+        optional: true,
+    },
+    RequiredCommand {
+        name: "opt",
+        purpose: "optimizing bitcode on link-time optimization",
+        hint: "install LLVM 14 with https://apt.llvm.org or Homebrew",
+        version_option: Some("--version"),
+        llvm: true,
+        optional: true,
+    },
+    RequiredCommand {
+        name: "cargo",
+        purpose: "building FFI packages written in Rust",
+        hint: "install Rust with https://rustup.rs",
+        version_option: Some("--version"),
+        llvm: false,
+        optional: true,
+    },
+    RequiredCommand {
+        name: "sh",
+        purpose: "running FFI initialization scripts",
+        hint: "install a POSIX shell",
+        version_option: None,
+        llvm: false,
+        optional: false,
+    },
+];
+
+#[derive(Default)]
+pub struct CommandFinder {}
+
+impl CommandFinder {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn find(&self, name: &str) -> Result<std::path::PathBuf, InfrastructureError> {
+        let command = find_required_command(name);
+
+        if command.map(|command| command.llvm).unwrap_or_default() {
+            LLVM_VERSIONS
+                .iter()
+                .map(|version| which::which(format!("{}-{}", name, version)))
+                .find(Result::is_ok)
+                .unwrap_or_else(|| which::which(name))
+        } else {
+            which::which(name)
+        }
+        .map_err(|_| create_command_not_found_error(name))
+    }
+
+    pub fn is_llvm_version_supported(&self, version: &str) -> bool {
+        LLVM_VERSIONS.iter().any(|supported_version| {
+            version
+                .split_whitespace()
+                .any(|word| word.split('.').next() == Some(supported_version))
+        })
+    }
+}
+
+pub(crate) fn create_command_not_found_error(name: &str) -> InfrastructureError {
+    let command = find_required_command(name);
+
+    InfrastructureError::CommandNotFound {
+        command: name.into(),
+        purpose: command.map(|command| command.purpose),
+        hint: command.map(|command| command.hint),
+    }
+}
+
+fn find_required_command(name: &str) -> Option<&'static RequiredCommand> {
+    REQUIRED_COMMANDS
+        .iter()
+        .find(|command| command.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_supported_llvm_version() {
+        let finder = CommandFinder::new();
+
+        assert!(finder.is_llvm_version_supported("LLVM version 14.0.6"));
+        assert!(!finder.is_llvm_version_supported("LLVM version 12.0.1"));
+        assert!(!finder.is_llvm_version_supported("LLVM version 15.0.0"));
+    }
+}
@@ -1,4 +1,4 @@
-use super::error::InfrastructureError;
+use super::{command_finder::create_command_not_found_error, error::InfrastructureError};
 use std::io::Write;
 
 #[derive(Default)]
@@ -13,7 +13,15 @@ impl CommandRunner {
         &self,
         command: &mut std::process::Command,
     ) -> Result<String, Box<dyn std::error::Error>> {
-        let output = command.output()?;
+        let output = command
+            .output()
+            .map_err(|error| -> Box<dyn std::error::Error> {
+                if error.kind() == std::io::ErrorKind::NotFound {
+                    create_command_not_found_error(&command.get_program().to_string_lossy()).into()
+                } else {
+                    error.into()
+                }
+            })?;
 
         if output.status.success() {
             return Ok(String::from_utf8(output.stdout)?);
@@ -0,0 +1,53 @@
+use super::error::InfrastructureError;
+use std::{
+    ffi::{CStr, CString},
+    os::{raw::c_void, unix::ffi::OsStrExt},
+};
+
+// Libraries are never unloaded as values returned by their functions can
+// refer to their code until processes exit.
+pub(crate) struct DynamicLibrary {
+    handle: *mut c_void,
+}
+
+impl DynamicLibrary {
+    pub fn open(path: &std::path::Path) -> Result<Self, InfrastructureError> {
+        let os_path = CString::new(path.as_os_str().as_bytes()).map_err(|error| {
+            InfrastructureError::LoadLibrary(format!("{}: {}", path.to_string_lossy(), error))
+        })?;
+        let handle = unsafe { libc::dlopen(os_path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+
+        if handle.is_null() {
+            // Error messages of dynamic loaders include paths.
+            Err(InfrastructureError::LoadLibrary(unsafe {
+                CStr::from_ptr(libc::dlerror()).to_string_lossy().into()
+            }))
+        } else {
+            Ok(Self { handle })
+        }
+    }
+
+    pub fn find_symbol(&self, name: &str) -> Option<*const c_void> {
+        let name = CString::new(name).ok()?;
+        let symbol = unsafe { libc::dlsym(self.handle, name.as_ptr()) };
+
+        if symbol.is_null() {
+            None
+        } else {
+            Some(symbol as *const c_void)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fail_to_open_missing_library() {
+        assert!(matches!(
+            DynamicLibrary::open(std::path::Path::new("/nonexistent/library.so")),
+            Err(InfrastructureError::LoadLibrary(_))
+        ));
+    }
+}
@@ -5,10 +5,17 @@ pub enum InfrastructureError {
     CommandExit {
         status_code: Option<i32>,
     },
+    CommandNotFound {
+        command: String,
+        purpose: Option<&'static str>,
+        hint: Option<&'static str>,
+    },
     CreateDirectory {
         path: std::path::PathBuf,
         source: std::io::Error,
     },
+    InvalidWasiCommand(String),
+    LoadLibrary(String),
     ReadDirectory {
         path: std::path::PathBuf,
         source: std::io::Error,
@@ -21,6 +28,10 @@ pub enum InfrastructureError {
         path: std::path::PathBuf,
         source: std::io::Error,
     },
+    RemoveFile {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
     WriteFile {
         path: std::path::PathBuf,
         source: std::io::Error,
@@ -31,10 +42,14 @@ impl Error for InfrastructureError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::CommandExit { status_code: _ } => None,
+            Self::CommandNotFound { .. } => None,
             Self::CreateDirectory { path: _, source } => Some(source),
+            Self::InvalidWasiCommand(_) => None,
+            Self::LoadLibrary(_) => None,
             Self::ReadDirectory { path: _, source } => Some(source),
             Self::ReadRepository { path: _, source } => Some(source.as_ref()),
             Self::ReadFile { path: _, source } => Some(source),
+            Self::RemoveFile { path: _, source } => Some(source),
             Self::WriteFile { path: _, source } => Some(source),
         }
     }
@@ -49,11 +64,32 @@ impl Display for InfrastructureError {
                 }
                 None => write!(formatter, "command exited without status code"),
             },
+            Self::CommandNotFound {
+                command,
+                purpose,
+                hint,
+            } => {
+                write!(formatter, "command \"{}\" not found", command)?;
+
+                if let Some(purpose) = purpose {
+                    write!(formatter, "\nit is used for {}", purpose)?;
+                }
+
+                if let Some(hint) = hint {
+                    write!(formatter, "\nhint: {}", hint)?;
+                }
+
+                Ok(())
+            }
             Self::CreateDirectory { path, source: _ } => write!(
                 formatter,
                 "failed to create directory {}",
                 path.to_string_lossy()
             ),
+            Self::InvalidWasiCommand(message) => {
+                write!(formatter, "invalid WASI command module: {}", message)
+            }
+            Self::LoadLibrary(message) => write!(formatter, "failed to load library: {}", message),
             Self::ReadDirectory { path, source: _ } => write!(
                 formatter,
                 "failed to read directory {}",
@@ -67,6 +103,13 @@ impl Display for InfrastructureError {
             Self::ReadFile { path, source: _ } => {
                 write!(formatter, "failed to read file {}", path.to_string_lossy())
             }
+            Self::RemoveFile { path, source: _ } => {
+                write!(
+                    formatter,
+                    "failed to remove file {}",
+                    path.to_string_lossy()
+                )
+            }
             Self::WriteFile { path, source: _ } => {
                 write!(formatter, "failed to write file {}", path.to_string_lossy())
             }
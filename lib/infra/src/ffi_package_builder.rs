@@ -0,0 +1,232 @@
+use super::{
+    clang_target_arguments::clang_target_arguments, command_runner::CommandRunner,
+    file_path_converter::FilePathConverter, fnv_hasher::FnvHasher, wasm::is_wasm_target,
+};
+
+const STATIC_LIBRARY_CRATE_TYPE: &str = "staticlib";
+const STATIC_LIBRARY_EXTENSION: &str = "a";
+
+pub struct FfiPackageBuilder<'a> {
+    command_runner: &'a CommandRunner,
+    file_path_converter: &'a FilePathConverter,
+    file_system: &'a dyn app::FileSystem,
+    static_file_path_manager: &'a app::StaticFilePathManager,
+    target_configuration: Option<&'a app::TargetConfiguration>,
+}
+
+impl<'a> FfiPackageBuilder<'a> {
+    pub fn new(
+        command_runner: &'a CommandRunner,
+        file_path_converter: &'a FilePathConverter,
+        file_system: &'a dyn app::FileSystem,
+        static_file_path_manager: &'a app::StaticFilePathManager,
+        target_configuration: Option<&'a app::TargetConfiguration>,
+    ) -> Self {
+        Self {
+            command_runner,
+            file_path_converter,
+            file_system,
+            static_file_path_manager,
+            target_configuration,
+        }
+    }
+
+    fn build_cargo_crate(
+        &self,
+        crate_directory_path: &app::FilePath,
+    ) -> Result<Vec<app::FilePath>, Box<dyn std::error::Error>> {
+        let stdout = self.command_runner.run(
+            std::process::Command::new("cargo")
+                .arg("build")
+                .arg("--release")
+                .arg("--offline")
+                .args(
+                    self.target_configuration
+                        .iter()
+                        .flat_map(|configuration| vec!["--target", configuration.triple()]),
+                )
+                .arg("--message-format=json")
+                .arg("--manifest-path")
+                .arg(self.file_path_converter.convert_to_os_path(
+                    &crate_directory_path.join(&app::FilePath::new(&["Cargo.toml"])),
+                )),
+        )?;
+
+        find_static_libraries(&stdout)?
+            .iter()
+            .map(|path| self.file_path_converter.convert_absolute_to_file_path(path))
+            .collect()
+    }
+
+    fn build_c_sources(
+        &self,
+        source_file_paths: &[app::FilePath],
+        profile: &app::Profile,
+    ) -> Result<Vec<app::FilePath>, Box<dyn std::error::Error>> {
+        let mut object_file_paths = vec![];
+
+        for source_file_path in source_file_paths {
+            let object_file_path = self.resolve_object_file_path(
+                source_file_path,
+                &self.file_system.read_to_vec(source_file_path)?,
+                profile,
+            );
+
+            if !self.file_system.exists(&object_file_path) {
+                let object_os_path = self
+                    .file_path_converter
+                    .convert_to_os_path(&object_file_path);
+
+                if let Some(directory) = object_os_path.parent() {
+                    std::fs::create_dir_all(directory)?;
+                }
+
+                self.command_runner.run(
+                    std::process::Command::new("clang")
+                        .arg("-c")
+                        .arg(format!("-O{}", u8::from(profile.optimization_level())))
+                        .args(if profile.debug_information() {
+                            Some("-g")
+                        } else {
+                            None
+                        })
+                        .args(if is_wasm_target(self.target_configuration) {
+                            None
+                        } else {
+                            Some("-fPIC")
+                        })
+                        .args(clang_target_arguments(self.target_configuration))
+                        .arg("-o")
+                        .arg(&object_os_path)
+                        .arg(
+                            self.file_path_converter
+                                .convert_to_os_path(source_file_path),
+                        ),
+                )?;
+            }
+
+            object_file_paths.push(object_file_path);
+        }
+
+        Ok(object_file_paths)
+    }
+
+    // Object files are cached in the object directory with IDs calculated
+    // from their sources, targets and compiler options as modules are.
+    // Changes of headers included by the sources are not detected.
+    fn resolve_object_file_path(
+        &self,
+        source_file_path: &app::FilePath,
+        source: &[u8],
+        profile: &app::Profile,
+    ) -> app::FilePath {
+        let mut hasher = FnvHasher::new();
+
+        hasher.write(format!("{}", source_file_path).as_bytes());
+        hasher.write(source);
+        hasher.write(
+            self.target_configuration
+                .map(|configuration| configuration.triple())
+                .unwrap_or_default()
+                .as_bytes(),
+        );
+        hasher.write(&[
+            u8::from(profile.optimization_level()),
+            profile.debug_information() as u8,
+        ]);
+
+        self.static_file_path_manager
+            .object_directory_path()
+            .join(&app::FilePath::new(&[format!("{:x}", hasher.finish())]))
+            .with_extension(app::OBJECT_FILE_EXTENSION)
+    }
+}
+
+impl<'a> app::FfiPackageBuilder for FfiPackageBuilder<'a> {
+    fn build(
+        &self,
+        directory_path: &app::FilePath,
+        ffi_configuration: &app::FfiConfiguration,
+        profile: &app::Profile,
+    ) -> Result<Vec<app::FilePath>, Box<dyn std::error::Error>> {
+        match ffi_configuration.source() {
+            app::FfiSource::Cargo(path) => self.build_cargo_crate(&directory_path.join(path)),
+            app::FfiSource::C(paths) => self.build_c_sources(
+                &paths
+                    .iter()
+                    .map(|path| directory_path.join(path))
+                    .collect::<Vec<_>>(),
+                profile,
+            ),
+            app::FfiSource::Archives(paths) => {
+                Ok(paths.iter().map(|path| directory_path.join(path)).collect())
+            }
+        }
+    }
+}
+
+// Cargo reports artifacts as JSON messages, one per line, with
+// `--message-format=json`.
+fn find_static_libraries(
+    stdout: &str,
+) -> Result<Vec<std::path::PathBuf>, Box<dyn std::error::Error>> {
+    let mut paths = vec![];
+
+    for line in stdout.lines() {
+        let message = serde_json::from_str::<serde_json::Value>(line)?;
+
+        if message["reason"] != "compiler-artifact"
+            || !message["target"]["crate_types"]
+                .as_array()
+                .map(|crate_types| {
+                    crate_types
+                        .iter()
+                        .any(|crate_type| crate_type == STATIC_LIBRARY_CRATE_TYPE)
+                })
+                .unwrap_or(false)
+        {
+            continue;
+        }
+
+        for filename in message["filenames"].as_array().into_iter().flatten() {
+            if let Some(filename) = filename.as_str() {
+                let path = std::path::PathBuf::from(filename);
+
+                if path.extension() == Some(std::ffi::OsStr::new(STATIC_LIBRARY_EXTENSION)) {
+                    paths.push(path);
+                }
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_no_static_library() {
+        assert_eq!(
+            find_static_libraries(r#"{"reason":"build-finished","success":true}"#).unwrap(),
+            Vec::<std::path::PathBuf>::new()
+        );
+    }
+
+    #[test]
+    fn find_static_library() {
+        assert_eq!(
+            find_static_libraries(
+                &[
+                    r#"{"reason":"compiler-artifact","target":{"crate_types":["lib"]},"filenames":["/foo/libbar.rlib"]}"#,
+                    r#"{"reason":"compiler-artifact","target":{"crate_types":["staticlib"]},"filenames":["/foo/libfoo.a"]}"#,
+                    r#"{"reason":"build-finished","success":true}"#,
+                ]
+                .join("\n")
+            )
+            .unwrap(),
+            vec![std::path::PathBuf::from("/foo/libfoo.a")]
+        );
+    }
+}
@@ -79,6 +79,17 @@ impl<'a> app::FileSystem for FileSystem<'a> {
             })?)
     }
 
+    fn read_modified_time(
+        &self,
+        file_path: &app::FilePath,
+    ) -> Result<std::time::SystemTime, Box<dyn std::error::Error>> {
+        let path = self.file_path_converter.convert_to_os_path(file_path);
+
+        Ok(std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|source| InfrastructureError::ReadFile { path, source })?)
+    }
+
     fn read_repository(
         &self,
         directory_path: &app::FilePath,
@@ -113,6 +124,13 @@ impl<'a> app::FileSystem for FileSystem<'a> {
             .map_err(|source| InfrastructureError::ReadFile { path, source })?)
     }
 
+    fn remove(&self, file_path: &app::FilePath) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.file_path_converter.convert_to_os_path(file_path);
+
+        Ok(std::fs::remove_file(&path)
+            .map_err(|source| InfrastructureError::RemoveFile { path, source })?)
+    }
+
     fn write(
         &self,
         file_path: &app::FilePath,
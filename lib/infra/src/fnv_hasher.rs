@@ -0,0 +1,62 @@
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0100_0000_01b3;
+
+// FNV-1a hashes are stable across compilers and platforms unlike ones of
+// `DefaultHasher` and so can name files cached between builds. Each field is
+// prefixed with its length so that different fields never share hashes by
+// concatenation.
+pub(crate) struct FnvHasher {
+    hash: u64,
+}
+
+impl FnvHasher {
+    pub fn new() -> Self {
+        Self { hash: OFFSET_BASIS }
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        self.write_raw(&(bytes.len() as u64).to_le_bytes());
+        self.write_raw(bytes);
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    fn write_raw(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.hash = (self.hash ^ byte as u64).wrapping_mul(PRIME);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(fields: &[&str]) -> u64 {
+        let mut hasher = FnvHasher::new();
+
+        for field in fields {
+            hasher.write(field.as_bytes());
+        }
+
+        hasher.finish()
+    }
+
+    #[test]
+    fn hash_bytes() {
+        let mut hasher = FnvHasher::new();
+
+        hasher.write_raw(b"a");
+
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn hash_fields() {
+        assert_eq!(hash(&["foo"]), hash(&["foo"]));
+        assert_ne!(hash(&["foo"]), hash(&["bar"]));
+        assert_ne!(hash(&["ab", "c"]), hash(&["a", "bc"]));
+    }
+}
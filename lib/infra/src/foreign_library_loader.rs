@@ -0,0 +1,178 @@
+use super::{
+    command_runner::CommandRunner, dynamic_library::DynamicLibrary,
+    file_path_converter::FilePathConverter, fnv_hasher::FnvHasher, native_function::NativeFunction,
+    system_library_arguments::system_library_arguments,
+};
+
+const SHARED_LIBRARY_EXTENSION: &str = "so";
+const TEMPORARY_FILE_EXTENSION: &str = "so.tmp";
+
+pub struct ForeignLibraryLoader<'a> {
+    command_runner: &'a CommandRunner,
+    file_path_converter: &'a FilePathConverter,
+    static_file_path_manager: &'a app::StaticFilePathManager,
+}
+
+impl<'a> ForeignLibraryLoader<'a> {
+    pub fn new(
+        command_runner: &'a CommandRunner,
+        file_path_converter: &'a FilePathConverter,
+        static_file_path_manager: &'a app::StaticFilePathManager,
+    ) -> Self {
+        Self {
+            command_runner,
+            file_path_converter,
+            static_file_path_manager,
+        }
+    }
+
+    // All objects in archives are linked into shared libraries as foreign
+    // functions are not known until modules are loaded. The libraries are
+    // linked again only when any of the objects are updated.
+    fn link_shared_library(
+        &self,
+        object_file_paths: &[app::FilePath],
+        system_libraries: &[String],
+    ) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+        let object_file_paths = object_file_paths
+            .iter()
+            .map(|path| self.file_path_converter.convert_to_os_path(path))
+            .collect::<Vec<_>>();
+        let mut hasher = FnvHasher::new();
+
+        hasher.write(&(object_file_paths.len() as u64).to_le_bytes());
+
+        for path in &object_file_paths {
+            hasher.write(path.to_string_lossy().as_bytes());
+        }
+
+        for library in system_libraries {
+            hasher.write(library.as_bytes());
+        }
+
+        let path = self.file_path_converter.convert_to_os_path(
+            &self
+                .static_file_path_manager
+                .object_directory_path()
+                .join(&app::FilePath::new(&[format!("{:x}", hasher.finish())]))
+                .with_extension(SHARED_LIBRARY_EXTENSION),
+        );
+
+        if is_up_to_date(&path, &object_file_paths)? {
+            return Ok(path);
+        }
+
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        // Libraries are renamed after linking so that ones linked partially
+        // are never reused.
+        let temporary_path = path.with_extension(TEMPORARY_FILE_EXTENSION);
+
+        self.command_runner.run(
+            std::process::Command::new("clang")
+                .arg("-shared")
+                .arg("-o")
+                .arg(&temporary_path)
+                .arg(if cfg!(target_os = "macos") {
+                    "-Wl,-all_load"
+                } else {
+                    "-Wl,--whole-archive"
+                })
+                .args(&object_file_paths)
+                .args(if cfg!(target_os = "macos") {
+                    None
+                } else {
+                    Some("-Wl,--no-whole-archive")
+                })
+                .args(system_library_arguments(None))
+                .args(
+                    system_libraries
+                        .iter()
+                        .map(|library| format!("-l{}", library)),
+                ),
+        )?;
+
+        std::fs::rename(&temporary_path, &path)?;
+
+        Ok(path)
+    }
+}
+
+fn is_up_to_date(
+    library_path: &std::path::Path,
+    object_file_paths: &[std::path::PathBuf],
+) -> Result<bool, std::io::Error> {
+    let modified_time = match std::fs::metadata(library_path) {
+        Ok(metadata) => metadata.modified()?,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(error) => return Err(error),
+    };
+
+    for path in object_file_paths {
+        if std::fs::metadata(path)?.modified()? > modified_time {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+impl<'a> app::ForeignLibraryLoader for ForeignLibraryLoader<'a> {
+    fn load(
+        &self,
+        object_file_paths: &[app::FilePath],
+        system_libraries: &[String],
+        interpreter: &mut lang::Interpreter,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let library =
+            DynamicLibrary::open(&self.link_shared_library(object_file_paths, system_libraries)?)?;
+
+        interpreter.register_foreign_function_resolver(move |name, argument_types, result_type| {
+            let pointer = library.find_symbol(name)?;
+            let function: Box<lang::ForeignFunctionCallback> =
+                match NativeFunction::new(pointer, argument_types, result_type) {
+                    Ok(function) => Box::new(move |arguments| function.call(arguments)),
+                    Err(message) => Box::new(move |_| Err(message.clone())),
+                };
+
+            Some(function)
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_if_library_is_up_to_date() {
+        let directory =
+            std::env::temp_dir().join(format!("ein-foreign-library-{}", std::process::id()));
+        let library_path = directory.join("foo.so");
+        let object_file_paths = [directory.join("foo.a")];
+
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(&object_file_paths[0], &[]).unwrap();
+
+        let missing = is_up_to_date(&library_path, &object_file_paths).unwrap();
+
+        std::fs::write(&library_path, &[]).unwrap();
+
+        let linked = is_up_to_date(&library_path, &object_file_paths).unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        std::fs::write(&object_file_paths[0], &[0]).unwrap();
+
+        let updated = is_up_to_date(&library_path, &object_file_paths).unwrap();
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert!(!missing);
+        assert!(linked);
+        assert!(!updated);
+    }
+}
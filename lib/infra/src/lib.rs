@@ -1,21 +1,31 @@
 mod application_linker;
+mod clang_target_arguments;
+mod command_finder;
 mod command_runner;
+mod dynamic_library;
 mod error;
 mod external_package_downloader;
+mod ffi_package_builder;
 mod ffi_package_initializer;
 mod file_path_converter;
 mod file_path_displayer;
 mod file_system;
+mod fnv_hasher;
+mod foreign_library_loader;
 mod logger;
-mod prelude_package_downloader;
+mod native_function;
+mod system_library_arguments;
+mod wasm;
 
 pub use application_linker::*;
+pub use command_finder::*;
 pub use command_runner::*;
 pub use error::*;
 pub use external_package_downloader::*;
+pub use ffi_package_builder::*;
 pub use ffi_package_initializer::*;
 pub use file_path_converter::*;
 pub use file_path_displayer::*;
 pub use file_system::*;
+pub use foreign_library_loader::*;
 pub use logger::*;
-pub use prelude_package_downloader::*;
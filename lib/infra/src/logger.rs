@@ -12,11 +12,27 @@ impl Logger {
     pub fn log_error(
         &self,
         error: &dyn std::error::Error,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.log_problem("error", Color::Red, error)
+    }
+
+    pub fn log_warning(
+        &self,
+        warning: &dyn std::error::Error,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.log_problem("warning", Color::Yellow, warning)
+    }
+
+    fn log_problem(
+        &self,
+        label: &str,
+        color: Color,
+        error: &dyn std::error::Error,
     ) -> Result<(), Box<dyn std::error::Error>> {
         let mut stderr = StandardStream::stderr(ColorChoice::Auto);
 
-        stderr.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
-        write!(&mut stderr, "error")?;
+        stderr.set_color(ColorSpec::new().set_fg(Some(color)))?;
+        write!(&mut stderr, "{}", label)?;
         stderr.set_color(ColorSpec::new().set_fg(None))?;
 
         writeln!(
@@ -26,7 +42,7 @@ impl Logger {
         )?;
 
         if let Some(error) = error.source() {
-            self.log_error(error)?;
+            self.log_problem(label, color, error)?;
         }
 
         Ok(())
@@ -0,0 +1,405 @@
+use std::{os::raw::c_void, ptr::addr_of_mut, rc::Rc};
+
+// Interpreted values are passed to foreign functions as Any values with their
+// own type information.
+static VALUE_TYPE_INFORMATION: ffi::TypeInformation = ffi::TypeInformation {
+    clone: clone_value,
+    drop: drop_value,
+};
+
+extern "C" fn clone_value(payload: u64) {
+    unsafe { Rc::increment_strong_count(payload as *const lang::Value) }
+}
+
+extern "C" fn drop_value(payload: u64) {
+    unsafe { Rc::decrement_strong_count(payload as *const lang::Value) }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum NativeType {
+    Any,
+    Boolean,
+    Integer,
+    None,
+    Number,
+    String,
+}
+
+impl NativeType {
+    // Type parameters are erased into Any types as in compiled code.
+    fn new(type_: &lang::types::Type) -> Option<Self> {
+        match type_ {
+            lang::types::Type::Any(_) | lang::types::Type::Parameter(_) => Some(Self::Any),
+            lang::types::Type::Boolean(_) => Some(Self::Boolean),
+            lang::types::Type::Integer(_) => Some(Self::Integer),
+            lang::types::Type::None(_) => Some(Self::None),
+            lang::types::Type::Number(_) => Some(Self::Number),
+            lang::types::Type::String(_) => Some(Self::String),
+            _ => None,
+        }
+    }
+}
+
+enum NativeValue {
+    Any(ffi::Any),
+    Boolean(ffi::Boolean),
+    Integer(ffi::Integer),
+    Number(ffi::Number),
+    String(ffi::EinString),
+}
+
+impl NativeValue {
+    fn as_mut_ptr(&mut self) -> *mut c_void {
+        match self {
+            Self::Any(any) => any as *mut _ as *mut c_void,
+            Self::Boolean(boolean) => boolean as *mut _ as *mut c_void,
+            Self::Integer(integer) => integer as *mut _ as *mut c_void,
+            Self::Number(number) => number as *mut _ as *mut c_void,
+            Self::String(string) => string as *mut _ as *mut c_void,
+        }
+    }
+}
+
+// Call interfaces refer to type descriptions by pointers and are not moved
+// once they are prepared.
+struct CallInterface {
+    cif: libffi_sys::ffi_cif,
+    any_type: libffi_sys::ffi_type,
+    any_type_elements: [*mut libffi_sys::ffi_type; 3],
+    argument_types: Vec<*mut libffi_sys::ffi_type>,
+}
+
+pub(crate) struct NativeFunction {
+    pointer: *const c_void,
+    argument_types: Vec<NativeType>,
+    result_type: NativeType,
+    call_interface: Box<CallInterface>,
+}
+
+impl NativeFunction {
+    pub fn new(
+        pointer: *const c_void,
+        argument_types: &[lang::types::Type],
+        result_type: &lang::types::Type,
+    ) -> Result<Self, String> {
+        let argument_types = argument_types
+            .iter()
+            .map(|type_| match NativeType::new(type_) {
+                Some(NativeType::None) | None => Err(UNSUPPORTED_TYPE_MESSAGE.to_string()),
+                Some(type_) => Ok(type_),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let result_type =
+            NativeType::new(result_type).ok_or_else(|| UNSUPPORTED_TYPE_MESSAGE.to_string())?;
+
+        let mut call_interface = Box::new(CallInterface {
+            cif: Default::default(),
+            any_type: libffi_sys::ffi_type {
+                type_: libffi_sys::FFI_TYPE_STRUCT as u16,
+                ..Default::default()
+            },
+            any_type_elements: [
+                addr_of_mut!(libffi_sys::ffi_type_pointer),
+                addr_of_mut!(libffi_sys::ffi_type_uint64),
+                std::ptr::null_mut(),
+            ],
+            argument_types: vec![],
+        });
+        let call_interface_ref = call_interface.as_mut();
+
+        call_interface_ref.any_type.elements = call_interface_ref.any_type_elements.as_mut_ptr();
+        call_interface_ref.argument_types = argument_types
+            .iter()
+            .map(|&type_| resolve_ffi_type(type_, &mut call_interface_ref.any_type))
+            .collect();
+
+        let status = unsafe {
+            libffi_sys::ffi_prep_cif(
+                &mut call_interface_ref.cif,
+                libffi_sys::ffi_abi_FFI_DEFAULT_ABI,
+                argument_types.len() as u32,
+                resolve_ffi_type(result_type, &mut call_interface_ref.any_type),
+                call_interface_ref.argument_types.as_mut_ptr(),
+            )
+        };
+
+        if status != libffi_sys::ffi_status_FFI_OK {
+            return Err(format!("failed to prepare call interface ({})", status));
+        }
+
+        Ok(Self {
+            pointer,
+            argument_types,
+            result_type,
+            call_interface,
+        })
+    }
+
+    pub fn call(&self, arguments: &[lang::Value]) -> Result<lang::Value, String> {
+        let mut arguments = arguments
+            .iter()
+            .zip(&self.argument_types)
+            .map(|(value, &type_)| convert_to_native_value(value, type_))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut argument_pointers = arguments
+            .iter_mut()
+            .map(NativeValue::as_mut_ptr)
+            .collect::<Vec<_>>();
+        // Results are written into buffers of at least register sizes.
+        let mut result = std::mem::MaybeUninit::<[u64; 2]>::zeroed();
+
+        unsafe {
+            libffi_sys::ffi_call(
+                &self.call_interface.cif as *const _ as *mut _,
+                Some(std::mem::transmute::<*const c_void, unsafe extern "C" fn()>(self.pointer)),
+                result.as_mut_ptr() as *mut c_void,
+                argument_pointers.as_mut_ptr(),
+            );
+        }
+
+        // Arguments are owned by callees once they are passed.
+        std::mem::forget(arguments);
+
+        let result = result.as_ptr();
+
+        Ok(unsafe {
+            match self.result_type {
+                NativeType::Any => convert_from_any(std::ptr::read(result as *const ffi::Any)),
+                NativeType::Boolean => {
+                    (usize::from(std::ptr::read(result as *const ffi::Boolean)) != 0).into()
+                }
+                NativeType::Integer => {
+                    i64::from(std::ptr::read(result as *const ffi::Integer)).into()
+                }
+                NativeType::None => lang::Value::None,
+                NativeType::Number => {
+                    f64::from(std::ptr::read(result as *const ffi::Number)).into()
+                }
+                NativeType::String => {
+                    let string = std::ptr::read(result as *const ffi::EinString);
+
+                    std::str::from_utf8(string.as_slice())
+                        .map_err(|error| error.to_string())?
+                        .into()
+                }
+            }
+        })
+    }
+}
+
+const UNSUPPORTED_TYPE_MESSAGE: &str = "only functions of Any, Boolean, Integer, Number and String arguments and None results are supported in interpreters";
+
+// Booleans are pointer-sized integers and integers are bits of
+// floating-point numbers in FFI.
+fn resolve_ffi_type(
+    type_: NativeType,
+    any_type: &mut libffi_sys::ffi_type,
+) -> *mut libffi_sys::ffi_type {
+    match type_ {
+        NativeType::Any => any_type,
+        NativeType::Boolean | NativeType::String => addr_of_mut!(libffi_sys::ffi_type_pointer),
+        NativeType::Integer | NativeType::Number => addr_of_mut!(libffi_sys::ffi_type_double),
+        NativeType::None => addr_of_mut!(libffi_sys::ffi_type_void),
+    }
+}
+
+fn convert_to_native_value(value: &lang::Value, type_: NativeType) -> Result<NativeValue, String> {
+    Ok(match (type_, value) {
+        (NativeType::Any, value) => NativeValue::Any(convert_to_any(value)),
+        (NativeType::Boolean, lang::Value::Boolean(boolean)) => {
+            NativeValue::Boolean((*boolean).into())
+        }
+        (NativeType::Integer, lang::Value::Integer(integer)) => {
+            NativeValue::Integer((*integer).into())
+        }
+        (NativeType::Number, lang::Value::Number(number)) => NativeValue::Number((*number).into()),
+        (NativeType::String, lang::Value::String(string)) => {
+            NativeValue::String(string.as_ref().into())
+        }
+        _ => return Err(format!("{:?} value expected", type_)),
+    })
+}
+
+// Foreign values are passed back to foreign functions as they are.
+fn convert_to_any(value: &lang::Value) -> ffi::Any {
+    match value {
+        lang::Value::ForeignValue(value) => value.downcast_ref::<ffi::Any>().cloned(),
+        _ => None,
+    }
+    .unwrap_or_else(|| {
+        ffi::Any::new(
+            &VALUE_TYPE_INFORMATION,
+            Rc::into_raw(Rc::new(value.clone())) as u64,
+        )
+    })
+}
+
+fn convert_from_any(any: ffi::Any) -> lang::Value {
+    if std::ptr::eq(any.type_information(), &VALUE_TYPE_INFORMATION) {
+        unsafe { (*(any.payload() as *const lang::Value)).clone() }
+    } else {
+        lang::ForeignValue::new(any).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lang::{debug::*, types};
+
+    fn create_source_information() -> SourceInformation {
+        SourceInformation::new("", Location::new(1, 1), "")
+    }
+
+    fn call(
+        pointer: *const c_void,
+        argument_types: &[types::Type],
+        result_type: types::Type,
+        arguments: &[lang::Value],
+    ) -> Result<lang::Value, String> {
+        NativeFunction::new(pointer, argument_types, &result_type)?.call(arguments)
+    }
+
+    extern "C" fn add_numbers(one: ffi::Number, other: ffi::Number) -> ffi::Number {
+        (f64::from(one) + f64::from(other)).into()
+    }
+
+    extern "C" fn equal_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Boolean {
+        (i64::from(one) == i64::from(other)).into()
+    }
+
+    extern "C" fn negate_integer(integer: ffi::Integer) -> ffi::Integer {
+        (-i64::from(integer)).into()
+    }
+
+    extern "C" fn concatenate_strings(
+        one: ffi::EinString,
+        other: ffi::EinString,
+    ) -> ffi::EinString {
+        one.join(&other)
+    }
+
+    extern "C" fn create_array(element: ffi::Any) -> ffi::Any {
+        ffi::Array::default().push(element).into()
+    }
+
+    extern "C" fn get_first_element(array: ffi::Any) -> ffi::Any {
+        ffi::Array::from(array).get(1.0.into()).cloned().unwrap()
+    }
+
+    extern "C" fn return_none(_: ffi::Number) -> ffi::None {
+        ffi::None::new()
+    }
+
+    #[test]
+    fn call_function_with_numbers() {
+        assert_eq!(
+            call(
+                add_numbers as *const c_void,
+                &[
+                    types::Number::new(create_source_information()).into(),
+                    types::Number::new(create_source_information()).into(),
+                ],
+                types::Number::new(create_source_information()).into(),
+                &[1.0.into(), 2.0.into()],
+            ),
+            Ok(3.0.into())
+        );
+    }
+
+    #[test]
+    fn call_function_with_integers() {
+        assert_eq!(
+            call(
+                negate_integer as *const c_void,
+                &[types::Integer::new(create_source_information()).into()],
+                types::Integer::new(create_source_information()).into(),
+                &[42i64.into()],
+            ),
+            Ok((-42i64).into())
+        );
+    }
+
+    #[test]
+    fn call_function_returning_booleans() {
+        for (arguments, result) in &[([1i64, 1], true), ([1, 2], false)] {
+            assert_eq!(
+                call(
+                    equal_integers as *const c_void,
+                    &[
+                        types::Integer::new(create_source_information()).into(),
+                        types::Integer::new(create_source_information()).into(),
+                    ],
+                    types::Boolean::new(create_source_information()).into(),
+                    &[arguments[0].into(), arguments[1].into()],
+                ),
+                Ok((*result).into())
+            );
+        }
+    }
+
+    #[test]
+    fn call_function_with_strings() {
+        assert_eq!(
+            call(
+                concatenate_strings as *const c_void,
+                &[
+                    types::EinString::new(create_source_information()).into(),
+                    types::EinString::new(create_source_information()).into(),
+                ],
+                types::EinString::new(create_source_information()).into(),
+                &["foo".into(), "bar".into()],
+            ),
+            Ok("foobar".into())
+        );
+    }
+
+    #[test]
+    fn call_function_returning_none() {
+        assert_eq!(
+            call(
+                return_none as *const c_void,
+                &[types::Number::new(create_source_information()).into()],
+                types::None::new(create_source_information()).into(),
+                &[42.0.into()],
+            ),
+            Ok(lang::Value::None)
+        );
+    }
+
+    #[test]
+    fn pass_foreign_values_back_to_foreign_functions() {
+        let array = call(
+            create_array as *const c_void,
+            &[types::Any::new(create_source_information()).into()],
+            types::Any::new(create_source_information()).into(),
+            &["foo".into()],
+        )
+        .unwrap();
+
+        assert!(matches!(array, lang::Value::ForeignValue(_)));
+        assert_eq!(
+            call(
+                get_first_element as *const c_void,
+                &[types::Any::new(create_source_information()).into()],
+                types::Any::new(create_source_information()).into(),
+                &[array],
+            ),
+            Ok("foo".into())
+        );
+    }
+
+    #[test]
+    fn fail_to_create_function_with_unsupported_types() {
+        assert!(NativeFunction::new(
+            add_numbers as *const c_void,
+            &[types::List::new(
+                types::Number::new(create_source_information()),
+                create_source_information()
+            )
+            .into()],
+            &types::Number::new(create_source_information()).into(),
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,42 @@
+// glibc provides functions of dynamic loading and threads in separate
+// libraries while other C standard libraries, e.g. ones of macOS, musl and
+// WASI, provide them in themselves or not at all.
+pub(crate) fn system_library_arguments(target_triple: Option<&str>) -> Vec<&'static str> {
+    if match target_triple {
+        Some(triple) => triple.contains("-linux-gnu"),
+        None => cfg!(all(target_os = "linux", target_env = "gnu")),
+    } {
+        vec!["-ldl", "-lpthread"]
+    } else {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_arguments_for_glibc() {
+        assert_eq!(
+            system_library_arguments(Some("x86_64-unknown-linux-gnu")),
+            vec!["-ldl", "-lpthread"]
+        );
+        assert_eq!(
+            system_library_arguments(Some("arm-unknown-linux-gnueabihf")),
+            vec!["-ldl", "-lpthread"]
+        );
+    }
+
+    #[test]
+    fn create_no_argument_for_other_c_libraries() {
+        for triple in &[
+            "x86_64-unknown-linux-musl",
+            "x86_64-apple-darwin",
+            "aarch64-apple-darwin",
+            "wasm32-wasi",
+        ] {
+            assert_eq!(system_library_arguments(Some(triple)), Vec::<&str>::new());
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use super::error::InfrastructureError;
+
+const MAGIC_NUMBER: &[u8] = b"\0asm";
+const VERSION: &[u8] = &[1, 0, 0, 0];
+const EXPORT_SECTION_ID: u8 = 7;
+const FUNCTION_EXPORT_KIND: u8 = 0;
+const MEMORY_EXPORT_KIND: u8 = 2;
+// WASI runtimes call the function and pass the memory to system calls.
+const COMMAND_EXPORTS: &[(&str, u8)] = &[
+    ("_start", FUNCTION_EXPORT_KIND),
+    ("memory", MEMORY_EXPORT_KIND),
+];
+
+pub(crate) fn is_wasm_target(target_configuration: Option<&app::TargetConfiguration>) -> bool {
+    target_configuration
+        .map(|configuration| configuration.is_wasm())
+        .unwrap_or_default()
+}
+
+pub(crate) fn validate_wasi_command(module: &[u8]) -> Result<(), InfrastructureError> {
+    let exports = read_exports(module).ok_or_else(|| {
+        InfrastructureError::InvalidWasiCommand("malformed WebAssembly module".into())
+    })?;
+
+    for (name, kind) in COMMAND_EXPORTS {
+        if !exports.contains(&(name.as_bytes(), *kind)) {
+            return Err(InfrastructureError::InvalidWasiCommand(format!(
+                "export \"{}\" not found",
+                name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn read_exports(module: &[u8]) -> Option<Vec<(&[u8], u8)>> {
+    let mut reader = Reader::new(module);
+
+    if reader.read_bytes(MAGIC_NUMBER.len())? != MAGIC_NUMBER
+        || reader.read_bytes(VERSION.len())? != VERSION
+    {
+        return None;
+    }
+
+    let mut exports = vec![];
+
+    while !reader.is_empty() {
+        let id = reader.read_byte()?;
+        let size = reader.read_unsigned_integer()?;
+        let mut section = Reader::new(reader.read_bytes(size)?);
+
+        if id == EXPORT_SECTION_ID {
+            for _ in 0..section.read_unsigned_integer()? {
+                let size = section.read_unsigned_integer()?;
+                let name = section.read_bytes(size)?;
+                let kind = section.read_byte()?;
+
+                section.read_unsigned_integer()?;
+                exports.push((name, kind));
+            }
+        }
+    }
+
+    Some(exports)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn read_byte(&mut self) -> Option<u8> {
+        Some(self.read_bytes(1)?[0])
+    }
+
+    pub fn read_bytes(&mut self, size: usize) -> Option<&'a [u8]> {
+        if size > self.bytes.len() {
+            return None;
+        }
+
+        let (bytes, rest) = self.bytes.split_at(size);
+        self.bytes = rest;
+
+        Some(bytes)
+    }
+
+    // Unsigned LEB128 integers of 32 bits
+    pub fn read_unsigned_integer(&mut self) -> Option<usize> {
+        let mut integer = 0;
+
+        for index in 0..5 {
+            let byte = self.read_byte()?;
+
+            integer |= ((byte & 0x7f) as usize) << (7 * index);
+
+            if byte & 0x80 == 0 {
+                return Some(integer);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_module(exports: &[(&str, u8)]) -> Vec<u8> {
+        let mut section = vec![exports.len() as u8];
+
+        for (name, kind) in exports {
+            section.push(name.len() as u8);
+            section.extend(name.as_bytes());
+            section.extend(&[*kind, 0]);
+        }
+
+        [
+            MAGIC_NUMBER,
+            VERSION,
+            &[EXPORT_SECTION_ID, section.len() as u8],
+        ]
+        .concat()
+        .into_iter()
+        .chain(section)
+        .collect()
+    }
+
+    #[test]
+    fn check_wasm_target() {
+        assert!(is_wasm_target(Some(&app::TargetConfiguration::new(
+            "wasm32-wasi",
+            None
+        ))));
+        assert!(!is_wasm_target(Some(&app::TargetConfiguration::new(
+            "x86_64-unknown-linux-gnu",
+            None
+        ))));
+        assert!(!is_wasm_target(None));
+    }
+
+    #[test]
+    fn validate_command() {
+        assert!(validate_wasi_command(&create_module(&[
+            ("memory", MEMORY_EXPORT_KIND),
+            ("_start", FUNCTION_EXPORT_KIND),
+        ]))
+        .is_ok());
+    }
+
+    #[test]
+    fn validate_command_with_other_sections() {
+        let mut module = create_module(&[
+            ("memory", MEMORY_EXPORT_KIND),
+            ("_start", FUNCTION_EXPORT_KIND),
+        ]);
+
+        // An empty custom section with a multi-byte size
+        module.extend(&[0, 0x81, 0x00, 0]);
+
+        assert!(validate_wasi_command(&module).is_ok());
+    }
+
+    #[test]
+    fn fail_to_validate_command_without_start_function() {
+        assert_eq!(
+            validate_wasi_command(&create_module(&[("memory", MEMORY_EXPORT_KIND)]))
+                .unwrap_err()
+                .to_string(),
+            "invalid WASI command module: export \"_start\" not found"
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_command_with_start_memory() {
+        assert!(validate_wasi_command(&create_module(&[
+            ("memory", MEMORY_EXPORT_KIND),
+            ("_start", MEMORY_EXPORT_KIND),
+        ]))
+        .is_err());
+    }
+
+    #[test]
+    fn fail_to_validate_malformed_module() {
+        assert!(validate_wasi_command(b"\x7fELF").is_err());
+        assert!(validate_wasi_command(&create_module(&[])[..10]).is_err());
+    }
+}
@@ -1,9 +1,10 @@
-use super::expression::Expression;
+use super::{expression::Expression, pattern::Pattern};
 use crate::types::Type;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Alternative {
     type_: Type,
+    pattern: Option<Pattern>,
     expression: Expression,
 }
 
@@ -11,6 +12,20 @@ impl Alternative {
     pub fn new(type_: impl Into<Type>, expression: impl Into<Expression>) -> Self {
         Self {
             type_: type_.into(),
+            pattern: None,
+            expression: expression.into(),
+        }
+    }
+
+    // Patterns are matched with values of the given types.
+    pub fn with_pattern(
+        type_: impl Into<Type>,
+        pattern: impl Into<Pattern>,
+        expression: impl Into<Expression>,
+    ) -> Self {
+        Self {
+            type_: type_.into(),
+            pattern: Some(pattern.into()),
             expression: expression.into(),
         }
     }
@@ -19,6 +34,10 @@ impl Alternative {
         &self.type_
     }
 
+    pub fn pattern(&self) -> Option<&Pattern> {
+        self.pattern.as_ref()
+    }
+
     pub fn expression(&self) -> &Expression {
         &self.expression
     }
@@ -27,19 +46,25 @@ impl Alternative {
         &self,
         transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
     ) -> Result<Self, E> {
-        Ok(Self::new(
-            self.type_.clone(),
-            self.expression.transform_expressions(transform)?,
-        ))
+        Ok(Self {
+            type_: self.type_.clone(),
+            pattern: self.pattern.clone(),
+            expression: self.expression.transform_expressions(transform)?,
+        })
     }
 
     pub fn transform_types<E>(
         &self,
         transform: &mut impl FnMut(&Type) -> Result<Type, E>,
     ) -> Result<Self, E> {
-        Ok(Self::new(
-            self.type_.transform_types(transform)?,
-            self.expression.transform_types(transform)?,
-        ))
+        Ok(Self {
+            type_: self.type_.transform_types(transform)?,
+            pattern: self
+                .pattern
+                .as_ref()
+                .map(|pattern| pattern.transform_types(transform))
+                .transpose()?,
+            expression: self.expression.transform_types(transform)?,
+        })
     }
 }
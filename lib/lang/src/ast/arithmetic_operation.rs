@@ -1,5 +1,8 @@
 use super::expression::Expression;
-use crate::{debug::SourceInformation, types::Type};
+use crate::{
+    debug::SourceInformation,
+    types::{self, Type},
+};
 use std::sync::Arc;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -8,10 +11,13 @@ pub enum ArithmeticOperator {
     Subtract,
     Multiply,
     Divide,
+    IntegerDivide,
+    Remainder,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ArithmeticOperation {
+    type_: Type,
     operator: ArithmeticOperator,
     lhs: Arc<Expression>,
     rhs: Arc<Expression>,
@@ -24,8 +30,27 @@ impl ArithmeticOperation {
         lhs: impl Into<Expression>,
         rhs: impl Into<Expression>,
         source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        let source_information: Arc<_> = source_information.into();
+
+        Self::with_type(
+            types::Unknown::new(source_information.clone()),
+            operator,
+            lhs,
+            rhs,
+            source_information,
+        )
+    }
+
+    pub fn with_type(
+        type_: impl Into<Type>,
+        operator: ArithmeticOperator,
+        lhs: impl Into<Expression>,
+        rhs: impl Into<Expression>,
+        source_information: impl Into<Arc<SourceInformation>>,
     ) -> Self {
         Self {
+            type_: type_.into(),
             operator,
             lhs: lhs.into().into(),
             rhs: rhs.into().into(),
@@ -33,6 +58,10 @@ impl ArithmeticOperation {
         }
     }
 
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
     pub fn operator(&self) -> ArithmeticOperator {
         self.operator
     }
@@ -53,7 +82,8 @@ impl ArithmeticOperation {
         &self,
         transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
     ) -> Result<Self, E> {
-        Ok(Self::new(
+        Ok(Self::with_type(
+            self.type_.clone(),
             self.operator,
             self.lhs.transform_expressions(transform)?,
             self.rhs.transform_expressions(transform)?,
@@ -65,7 +95,8 @@ impl ArithmeticOperation {
         &self,
         transform: &mut impl FnMut(&Type) -> Result<Type, E>,
     ) -> Result<Self, E> {
-        Ok(Self::new(
+        Ok(Self::with_type(
+            self.type_.transform_types(transform)?,
             self.operator,
             self.lhs.transform_types(transform)?,
             self.rhs.transform_types(transform)?,
@@ -0,0 +1,55 @@
+use super::expression::Expression;
+use crate::{debug::SourceInformation, types::Type};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Array {
+    elements: Vec<Expression>,
+    source_information: Arc<SourceInformation>,
+}
+
+impl Array {
+    pub fn new(
+        elements: Vec<Expression>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            elements,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn elements(&self) -> &[Expression] {
+        &self.elements
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.elements
+                .iter()
+                .map(|element| element.transform_expressions(transform))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.elements
+                .iter()
+                .map(|element| element.transform_types(transform))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
+}
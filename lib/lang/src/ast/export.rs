@@ -3,14 +3,27 @@ use std::collections::HashSet;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Export {
     names: HashSet<String>,
+    opaque_names: HashSet<String>,
 }
 
 impl Export {
     pub fn new(names: HashSet<String>) -> Self {
-        Self { names }
+        Self::with_opaque_names(names, Default::default())
+    }
+
+    // Opaque names are included in exported names as well.
+    pub fn with_opaque_names(names: HashSet<String>, opaque_names: HashSet<String>) -> Self {
+        Self {
+            names,
+            opaque_names,
+        }
     }
 
     pub fn names(&self) -> &HashSet<String> {
         &self.names
     }
+
+    pub fn opaque_names(&self) -> &HashSet<String> {
+        &self.opaque_names
+    }
 }
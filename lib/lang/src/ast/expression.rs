@@ -1,9 +1,11 @@
 use super::{
-    application::Application, boolean::Boolean, case::Case, if_::If, let_::Let,
-    let_error::LetError, list::List, list_case::ListCase, none::None, number::Number,
-    operation::Operation, record_construction::RecordConstruction,
-    record_element_operation::RecordElementOperation, record_update::RecordUpdate,
-    string::EinString, type_coercion::TypeCoercion, variable::Variable,
+    application::Application, array::Array, boolean::Boolean, case::Case,
+    hash_operation::HashOperation, if_::If, integer::Integer, lambda::Lambda, let_::Let,
+    let_error::LetError, let_pattern::LetPattern, list::List, list_case::ListCase,
+    list_pattern_case::ListPatternCase, map::Map, none::None, number::Number, operation::Operation,
+    record_construction::RecordConstruction, record_element_operation::RecordElementOperation,
+    record_update::RecordUpdate, string::EinString, string_interpolation::StringInterpolation,
+    type_coercion::TypeCoercion, variable::Variable,
 };
 use crate::{debug::SourceInformation, types::Type};
 use std::sync::Arc;
@@ -11,13 +13,20 @@ use std::sync::Arc;
 #[derive(Clone, Debug, PartialEq)]
 pub enum Expression {
     Application(Application),
+    Array(Array),
     Boolean(Boolean),
     Case(Case),
+    HashOperation(HashOperation),
     If(If),
+    Integer(Integer),
+    Lambda(Lambda),
     Let(Let),
     LetError(LetError),
+    LetPattern(LetPattern),
     List(List),
     ListCase(ListCase),
+    ListPatternCase(ListPatternCase),
+    Map(Map),
     None(None),
     Number(Number),
     Operation(Operation),
@@ -25,6 +34,7 @@ pub enum Expression {
     RecordElementOperation(RecordElementOperation),
     RecordUpdate(RecordUpdate),
     String(EinString),
+    StringInterpolation(StringInterpolation),
     TypeCoercion(TypeCoercion),
     Variable(Variable),
 }
@@ -33,20 +43,28 @@ impl Expression {
     pub fn source_information(&self) -> &Arc<SourceInformation> {
         match self {
             Self::Application(application) => application.source_information(),
+            Self::Array(array) => array.source_information(),
             Self::Boolean(boolean) => boolean.source_information(),
             Self::Case(case) => case.source_information(),
+            Self::HashOperation(operation) => operation.source_information(),
             Self::RecordConstruction(record_construction) => {
                 record_construction.source_information()
             }
             Self::RecordElementOperation(operation) => operation.source_information(),
             Self::RecordUpdate(record_update) => record_update.source_information(),
             Self::If(if_) => if_.source_information(),
+            Self::Integer(integer) => integer.source_information(),
+            Self::Lambda(lambda) => lambda.source_information(),
             Self::Let(let_) => let_.source_information(),
             Self::LetError(let_) => let_.source_information(),
+            Self::LetPattern(let_) => let_.source_information(),
             Self::List(list) => list.source_information(),
             Self::ListCase(case) => case.source_information(),
+            Self::ListPatternCase(case) => case.source_information(),
+            Self::Map(map) => map.source_information(),
             Self::Operation(operation) => operation.source_information(),
             Self::String(string) => string.source_information(),
+            Self::StringInterpolation(interpolation) => interpolation.source_information(),
             Self::TypeCoercion(coercion) => coercion.source_information(),
             Self::None(none) => none.source_information(),
             Self::Number(number) => number.source_information(),
@@ -60,7 +78,9 @@ impl Expression {
     ) -> Result<Self, E> {
         let expression = match self {
             Self::Application(application) => application.transform_expressions(transform)?.into(),
+            Self::Array(array) => array.transform_expressions(transform)?.into(),
             Self::Case(case) => case.transform_expressions(transform)?.into(),
+            Self::HashOperation(operation) => operation.transform_expressions(transform)?.into(),
             Self::RecordConstruction(record_construction) => {
                 record_construction.transform_expressions(transform)?.into()
             }
@@ -72,12 +92,20 @@ impl Expression {
             }
             Self::If(if_) => if_.transform_expressions(transform)?.into(),
             Self::Let(let_) => let_.transform_expressions(transform)?.into(),
+            Self::Lambda(lambda) => lambda.transform_expressions(transform)?.into(),
             Self::LetError(let_) => let_.transform_expressions(transform)?.into(),
+            Self::LetPattern(let_) => let_.transform_expressions(transform)?.into(),
             Self::List(list) => list.transform_expressions(transform)?.into(),
             Self::ListCase(case) => case.transform_expressions(transform)?.into(),
+            Self::ListPatternCase(case) => case.transform_expressions(transform)?.into(),
+            Self::Map(map) => map.transform_expressions(transform)?.into(),
             Self::Operation(operation) => operation.transform_expressions(transform)?.into(),
+            Self::StringInterpolation(interpolation) => {
+                interpolation.transform_expressions(transform)?.into()
+            }
             Self::TypeCoercion(coercion) => coercion.transform_expressions(transform)?.into(),
             Self::Boolean(_)
+            | Self::Integer(_)
             | Self::None(_)
             | Self::Number(_)
             | Self::String(_)
@@ -93,7 +121,9 @@ impl Expression {
     ) -> Result<Self, E> {
         Ok(match self {
             Self::Application(application) => application.transform_types(transform)?.into(),
+            Self::Array(array) => array.transform_types(transform)?.into(),
             Self::Case(case) => case.transform_types(transform)?.into(),
+            Self::HashOperation(operation) => operation.transform_types(transform)?.into(),
             Self::RecordConstruction(record_construction) => {
                 record_construction.transform_types(transform)?.into()
             }
@@ -101,12 +131,20 @@ impl Expression {
             Self::RecordUpdate(record_update) => record_update.transform_types(transform)?.into(),
             Self::If(if_) => if_.transform_types(transform)?.into(),
             Self::Let(let_) => let_.transform_types(transform)?.into(),
+            Self::Lambda(lambda) => lambda.transform_types(transform)?.into(),
             Self::LetError(let_) => let_.transform_types(transform)?.into(),
+            Self::LetPattern(let_) => let_.transform_types(transform)?.into(),
             Self::List(list) => list.transform_types(transform)?.into(),
             Self::ListCase(case) => case.transform_types(transform)?.into(),
+            Self::ListPatternCase(case) => case.transform_types(transform)?.into(),
+            Self::Map(map) => map.transform_types(transform)?.into(),
             Self::Operation(operation) => operation.transform_types(transform)?.into(),
+            Self::StringInterpolation(interpolation) => {
+                interpolation.transform_types(transform)?.into()
+            }
             Self::TypeCoercion(coercion) => coercion.transform_types(transform)?.into(),
             Self::Boolean(_)
+            | Self::Integer(_)
             | Self::None(_)
             | Self::Number(_)
             | Self::String(_)
@@ -121,6 +159,12 @@ impl From<Application> for Expression {
     }
 }
 
+impl From<Array> for Expression {
+    fn from(array: Array) -> Self {
+        Self::Array(array)
+    }
+}
+
 impl From<Boolean> for Expression {
     fn from(boolean: Boolean) -> Self {
         Self::Boolean(boolean)
@@ -157,6 +201,12 @@ impl From<RecordUpdate> for Expression {
     }
 }
 
+impl From<HashOperation> for Expression {
+    fn from(operation: HashOperation) -> Self {
+        Self::HashOperation(operation)
+    }
+}
+
 impl From<If> for Expression {
     fn from(if_: If) -> Self {
         Self::If(if_)
@@ -169,12 +219,30 @@ impl From<Let> for Expression {
     }
 }
 
+impl From<Integer> for Expression {
+    fn from(integer: Integer) -> Self {
+        Self::Integer(integer)
+    }
+}
+
+impl From<Lambda> for Expression {
+    fn from(lambda: Lambda) -> Self {
+        Self::Lambda(lambda)
+    }
+}
+
 impl From<LetError> for Expression {
     fn from(let_: LetError) -> Self {
         Self::LetError(let_)
     }
 }
 
+impl From<LetPattern> for Expression {
+    fn from(let_: LetPattern) -> Self {
+        Self::LetPattern(let_)
+    }
+}
+
 impl From<List> for Expression {
     fn from(list: List) -> Self {
         Self::List(list)
@@ -187,6 +255,18 @@ impl From<ListCase> for Expression {
     }
 }
 
+impl From<ListPatternCase> for Expression {
+    fn from(case: ListPatternCase) -> Self {
+        Self::ListPatternCase(case)
+    }
+}
+
+impl From<Map> for Expression {
+    fn from(map: Map) -> Self {
+        Self::Map(map)
+    }
+}
+
 impl From<None> for Expression {
     fn from(none: None) -> Self {
         Self::None(none)
@@ -205,6 +285,12 @@ impl<T: Into<Operation>> From<T> for Expression {
     }
 }
 
+impl From<StringInterpolation> for Expression {
+    fn from(interpolation: StringInterpolation) -> Self {
+        Self::StringInterpolation(interpolation)
+    }
+}
+
 impl From<TypeCoercion> for Expression {
     fn from(coercion: TypeCoercion) -> Self {
         Self::TypeCoercion(coercion)
@@ -6,9 +6,10 @@ use std::sync::Arc;
 pub struct FunctionDefinition {
     name: String,
     arguments: Vec<String>,
-    body: Expression,
+    body: Arc<Expression>,
     type_: Type,
     source_information: Arc<SourceInformation>,
+    documentation: Option<String>,
 }
 
 impl FunctionDefinition {
@@ -22,9 +23,17 @@ impl FunctionDefinition {
         Self {
             name: name.into(),
             arguments,
-            body: body.into(),
+            body: Arc::new(body.into()),
             type_: type_.into(),
             source_information: source_information.into(),
+            documentation: None,
+        }
+    }
+
+    pub fn with_documentation(&self, documentation: impl Into<String>) -> Self {
+        Self {
+            documentation: Some(documentation.into()),
+            ..self.clone()
         }
     }
 
@@ -48,29 +57,28 @@ impl FunctionDefinition {
         &self.source_information
     }
 
+    pub fn documentation(&self) -> Option<&str> {
+        self.documentation.as_deref()
+    }
+
     pub fn transform_expressions<E>(
         &self,
         transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
     ) -> Result<Self, E> {
-        Ok(Self::new(
-            self.name.clone(),
-            self.arguments.clone(),
-            self.body.transform_expressions(transform)?,
-            self.type_.clone(),
-            self.source_information.clone(),
-        ))
+        Ok(Self {
+            body: self.body.transform_expressions(transform)?.into(),
+            ..self.clone()
+        })
     }
 
     pub fn transform_types<E>(
         &self,
         transform: &mut impl FnMut(&Type) -> Result<Type, E>,
     ) -> Result<Self, E> {
-        Ok(Self::new(
-            self.name.clone(),
-            self.arguments.clone(),
-            self.body.transform_types(transform)?,
-            self.type_.transform_types(transform)?,
-            self.source_information.clone(),
-        ))
+        Ok(Self {
+            body: self.body.transform_types(transform)?.into(),
+            type_: self.type_.transform_types(transform)?,
+            ..self.clone()
+        })
     }
 }
@@ -0,0 +1,25 @@
+use crate::debug::SourceInformation;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Integer {
+    value: i64,
+    source_information: Arc<SourceInformation>,
+}
+
+impl Integer {
+    pub fn new(value: i64, source_information: impl Into<Arc<SourceInformation>>) -> Self {
+        Self {
+            value,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+}
@@ -0,0 +1,85 @@
+use super::expression::Expression;
+use crate::{
+    debug::SourceInformation,
+    types::{self, Type},
+};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lambda {
+    type_: Type,
+    arguments: Vec<String>,
+    body: Arc<Expression>,
+    source_information: Arc<SourceInformation>,
+}
+
+impl Lambda {
+    pub fn new(
+        arguments: Vec<String>,
+        body: impl Into<Expression>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        let source_information = source_information.into();
+
+        Self::with_type(
+            types::Unknown::new(source_information.clone()),
+            arguments,
+            body,
+            source_information,
+        )
+    }
+
+    pub fn with_type(
+        type_: impl Into<Type>,
+        arguments: Vec<String>,
+        body: impl Into<Expression>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            type_: type_.into(),
+            arguments,
+            body: Arc::new(body.into()),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn arguments(&self) -> &[String] {
+        &self.arguments
+    }
+
+    pub fn body(&self) -> &Expression {
+        &self.body
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::with_type(
+            self.type_.clone(),
+            self.arguments.clone(),
+            self.body.transform_expressions(transform)?,
+            self.source_information.clone(),
+        ))
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::with_type(
+            transform(&self.type_)?,
+            self.arguments.clone(),
+            self.body.transform_types(transform)?,
+            self.source_information.clone(),
+        ))
+    }
+}
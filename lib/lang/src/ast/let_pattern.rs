@@ -0,0 +1,65 @@
+use super::{expression::Expression, pattern_definition::PatternDefinition};
+use crate::{debug::SourceInformation, types::Type};
+use std::sync::Arc;
+
+// Let expressions with patterns are desugared before type inference.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LetPattern {
+    definitions: Vec<PatternDefinition>,
+    expression: Arc<Expression>,
+    source_information: Arc<SourceInformation>,
+}
+
+impl LetPattern {
+    pub fn new(
+        definitions: Vec<PatternDefinition>,
+        expression: impl Into<Expression>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            definitions,
+            expression: Arc::new(expression.into()),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn definitions(&self) -> &[PatternDefinition] {
+        &self.definitions
+    }
+
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.definitions
+                .iter()
+                .map(|definition| definition.transform_expressions(transform))
+                .collect::<Result<_, _>>()?,
+            self.expression.transform_expressions(transform)?,
+            self.source_information.clone(),
+        ))
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.definitions
+                .iter()
+                .map(|definition| definition.transform_types(transform))
+                .collect::<Result<_, _>>()?,
+            self.expression.transform_types(transform)?,
+            self.source_information.clone(),
+        ))
+    }
+}
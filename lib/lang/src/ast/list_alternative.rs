@@ -0,0 +1,45 @@
+use super::{expression::Expression, list_pattern::ListPattern};
+use crate::types::Type;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListAlternative {
+    pattern: ListPattern,
+    expression: Expression,
+}
+
+impl ListAlternative {
+    pub fn new(pattern: ListPattern, expression: impl Into<Expression>) -> Self {
+        Self {
+            pattern,
+            expression: expression.into(),
+        }
+    }
+
+    pub fn pattern(&self) -> &ListPattern {
+        &self.pattern
+    }
+
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.pattern.clone(),
+            self.expression.transform_expressions(transform)?,
+        ))
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.pattern.transform_types(transform)?,
+            self.expression.transform_types(transform)?,
+        ))
+    }
+}
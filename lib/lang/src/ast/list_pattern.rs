@@ -0,0 +1,58 @@
+use super::pattern::Pattern;
+use crate::{debug::SourceInformation, types::Type};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListPattern {
+    elements: Vec<Pattern>,
+    rest_name: Option<String>,
+    source_information: Arc<SourceInformation>,
+}
+
+impl ListPattern {
+    pub fn new(
+        elements: Vec<Pattern>,
+        rest_name: Option<String>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            elements,
+            rest_name,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn elements(&self) -> &[Pattern] {
+        &self.elements
+    }
+
+    pub fn rest_name(&self) -> Option<&str> {
+        self.rest_name.as_deref()
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.elements
+            .iter()
+            .flat_map(|pattern| pattern.names())
+            .chain(self.rest_name())
+            .collect()
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.elements
+                .iter()
+                .map(|pattern| pattern.transform_types(transform))
+                .collect::<Result<_, _>>()?,
+            self.rest_name.clone(),
+            self.source_information.clone(),
+        ))
+    }
+}
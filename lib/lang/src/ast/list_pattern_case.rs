@@ -0,0 +1,66 @@
+use super::{expression::Expression, list_alternative::ListAlternative};
+use crate::{debug::SourceInformation, types::Type};
+use std::sync::Arc;
+
+// List cases with patterns are desugared into list cases before type
+// inference.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListPatternCase {
+    argument: Arc<Expression>,
+    alternatives: Vec<ListAlternative>,
+    source_information: Arc<SourceInformation>,
+}
+
+impl ListPatternCase {
+    pub fn new(
+        argument: impl Into<Expression>,
+        alternatives: Vec<ListAlternative>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            argument: Arc::new(argument.into()),
+            alternatives,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn argument(&self) -> &Expression {
+        &self.argument
+    }
+
+    pub fn alternatives(&self) -> &[ListAlternative] {
+        &self.alternatives
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.argument.transform_expressions(transform)?,
+            self.alternatives
+                .iter()
+                .map(|alternative| alternative.transform_expressions(transform))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.argument.transform_types(transform)?,
+            self.alternatives
+                .iter()
+                .map(|alternative| alternative.transform_types(transform))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
+}
@@ -0,0 +1,55 @@
+use super::{expression::Expression, map_entry::MapEntry};
+use crate::{debug::SourceInformation, types::Type};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Map {
+    entries: Vec<MapEntry>,
+    source_information: Arc<SourceInformation>,
+}
+
+impl Map {
+    pub fn new(
+        entries: Vec<MapEntry>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            entries,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn entries(&self) -> &[MapEntry] {
+        &self.entries
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.entries
+                .iter()
+                .map(|entry| entry.transform_expressions(transform))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.entries
+                .iter()
+                .map(|entry| entry.transform_types(transform))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
+}
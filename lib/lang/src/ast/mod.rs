@@ -1,6 +1,7 @@
 mod alternative;
 mod application;
 mod arithmetic_operation;
+mod array;
 mod boolean;
 mod boolean_operation;
 mod calling_convention;
@@ -11,25 +12,40 @@ mod export;
 mod export_foreign;
 mod expression;
 mod function_definition;
+mod hash_operation;
 mod if_;
 mod import;
 mod import_foreign;
+mod integer;
+mod lambda;
 mod let_;
 mod let_error;
+mod let_pattern;
 mod list;
+mod list_alternative;
 mod list_case;
 mod list_element;
+mod list_pattern;
+mod list_pattern_case;
+mod map;
+mod map_entry;
 mod module;
 mod module_interface;
 mod none;
 mod number;
 mod operation;
 mod order_operation;
+mod pattern;
+mod pattern_definition;
 mod pipe_operation;
 mod record_construction;
 mod record_element_operation;
+mod record_pattern;
 mod record_update;
+mod repl_input;
 mod string;
+mod string_interpolation;
+mod string_interpolation_part;
 mod type_coercion;
 mod type_definition;
 mod unresolved_import;
@@ -40,6 +56,7 @@ mod variable_definition;
 pub use alternative::*;
 pub use application::*;
 pub use arithmetic_operation::*;
+pub use array::*;
 pub use boolean::*;
 pub use boolean_operation::*;
 pub use calling_convention::*;
@@ -50,25 +67,40 @@ pub use export::*;
 pub use export_foreign::*;
 pub use expression::*;
 pub use function_definition::*;
+pub use hash_operation::*;
 pub use if_::*;
 pub use import::*;
 pub use import_foreign::*;
+pub use integer::*;
+pub use lambda::*;
 pub use let_::*;
 pub use let_error::*;
+pub use let_pattern::*;
 pub use list::*;
+pub use list_alternative::*;
 pub use list_case::*;
 pub use list_element::*;
+pub use list_pattern::*;
+pub use list_pattern_case::*;
+pub use map::*;
+pub use map_entry::*;
 pub use module::*;
 pub use module_interface::*;
 pub use none::*;
 pub use number::*;
 pub use operation::*;
 pub use order_operation::*;
+pub use pattern::*;
+pub use pattern_definition::*;
 pub use pipe_operation::*;
 pub use record_construction::*;
 pub use record_element_operation::*;
+pub use record_pattern::*;
 pub use record_update::*;
+pub use repl_input::*;
 pub use string::*;
+pub use string_interpolation::*;
+pub use string_interpolation_part::*;
 pub use type_coercion::*;
 pub use type_definition::*;
 pub use unresolved_import::*;
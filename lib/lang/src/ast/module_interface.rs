@@ -5,23 +5,31 @@ use std::collections::{BTreeMap, BTreeSet};
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub struct ModuleInterface {
     path: ModulePath,
-    exported_names: BTreeSet<String>,  // Unqualified
-    types: BTreeMap<String, Type>,     // Fully-qualified
+    exported_names: BTreeSet<String>, // Unqualified
+    #[serde(default)]
+    opaque_names: BTreeSet<String>, // Unqualified
+    types: BTreeMap<String, Type>,    // Fully-qualified
     variables: BTreeMap<String, Type>, // Fully-qualified
+    #[serde(default)]
+    documentation: BTreeMap<String, String>, // Fully-qualified
 }
 
 impl ModuleInterface {
     pub fn new(
         path: ModulePath,
         exported_names: BTreeSet<String>,
+        opaque_names: BTreeSet<String>,
         types: BTreeMap<String, Type>,
         variables: BTreeMap<String, Type>,
+        documentation: BTreeMap<String, String>,
     ) -> Self {
         Self {
             path,
             exported_names,
+            opaque_names,
             types,
             variables,
+            documentation,
         }
     }
 
@@ -33,6 +41,11 @@ impl ModuleInterface {
         &self.exported_names
     }
 
+    // Types of opaque names are exported without their elements.
+    pub fn opaque_names(&self) -> &BTreeSet<String> {
+        &self.opaque_names
+    }
+
     pub fn types(&self) -> &BTreeMap<String, Type> {
         &self.types
     }
@@ -40,4 +53,8 @@ impl ModuleInterface {
     pub fn variables(&self) -> &BTreeMap<String, Type> {
         &self.variables
     }
+
+    pub fn documentation(&self) -> &BTreeMap<String, String> {
+        &self.documentation
+    }
 }
@@ -0,0 +1,82 @@
+use super::{
+    list_pattern::ListPattern, number::Number, record_pattern::RecordPattern, string::EinString,
+    variable::Variable,
+};
+use crate::{debug::SourceInformation, types::Type};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pattern {
+    List(ListPattern),
+    Number(Number),
+    Record(RecordPattern),
+    String(EinString),
+    Variable(Variable),
+}
+
+impl Pattern {
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        match self {
+            Self::List(list) => list.source_information(),
+            Self::Number(number) => number.source_information(),
+            Self::Record(record) => record.source_information(),
+            Self::String(string) => string.source_information(),
+            Self::Variable(variable) => variable.source_information(),
+        }
+    }
+
+    // Names bound by patterns are returned in order.
+    pub fn names(&self) -> Vec<&str> {
+        match self {
+            Self::List(list) => list.names(),
+            Self::Record(record) => record
+                .elements()
+                .values()
+                .flat_map(|pattern| pattern.names())
+                .collect(),
+            Self::Variable(variable) => vec![variable.name()],
+            Self::Number(_) | Self::String(_) => vec![],
+        }
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(match self {
+            Self::List(list) => list.transform_types(transform)?.into(),
+            Self::Record(record) => record.transform_types(transform)?.into(),
+            Self::Number(_) | Self::String(_) | Self::Variable(_) => self.clone(),
+        })
+    }
+}
+
+impl From<ListPattern> for Pattern {
+    fn from(list: ListPattern) -> Self {
+        Self::List(list)
+    }
+}
+
+impl From<Number> for Pattern {
+    fn from(number: Number) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<RecordPattern> for Pattern {
+    fn from(record: RecordPattern) -> Self {
+        Self::Record(record)
+    }
+}
+
+impl From<EinString> for Pattern {
+    fn from(string: EinString) -> Self {
+        Self::String(string)
+    }
+}
+
+impl From<Variable> for Pattern {
+    fn from(variable: Variable) -> Self {
+        Self::Variable(variable)
+    }
+}
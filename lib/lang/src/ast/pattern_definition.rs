@@ -0,0 +1,58 @@
+use super::{expression::Expression, pattern::Pattern};
+use crate::{debug::SourceInformation, types::Type};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PatternDefinition {
+    pattern: Pattern,
+    body: Expression,
+    source_information: Arc<SourceInformation>,
+}
+
+impl PatternDefinition {
+    pub fn new(
+        pattern: impl Into<Pattern>,
+        body: impl Into<Expression>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            pattern: pattern.into(),
+            body: body.into(),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    pub fn body(&self) -> &Expression {
+        &self.body
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.pattern.clone(),
+            self.body.transform_expressions(transform)?,
+            self.source_information.clone(),
+        ))
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.pattern.transform_types(transform)?,
+            self.body.transform_types(transform)?,
+            self.source_information.clone(),
+        ))
+    }
+}
@@ -0,0 +1,50 @@
+use super::pattern::Pattern;
+use crate::{debug::SourceInformation, types::Type};
+use std::{collections::BTreeMap, sync::Arc};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordPattern {
+    type_: Type,
+    elements: BTreeMap<String, Pattern>,
+    source_information: Arc<SourceInformation>,
+}
+
+impl RecordPattern {
+    pub fn new(
+        type_: impl Into<Type>,
+        elements: BTreeMap<String, Pattern>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            type_: type_.into(),
+            elements,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn elements(&self) -> &BTreeMap<String, Pattern> {
+        &self.elements
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.type_.transform_types(transform)?,
+            self.elements
+                .iter()
+                .map(|(name, pattern)| Ok((name.clone(), pattern.transform_types(transform)?)))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
+}
@@ -0,0 +1,26 @@
+use super::{definition::Definition, expression::Expression, type_definition::TypeDefinition};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReplInput {
+    Definition(Definition),
+    Expression(Expression),
+    TypeDefinition(TypeDefinition),
+}
+
+impl From<Definition> for ReplInput {
+    fn from(definition: Definition) -> Self {
+        Self::Definition(definition)
+    }
+}
+
+impl From<Expression> for ReplInput {
+    fn from(expression: Expression) -> Self {
+        Self::Expression(expression)
+    }
+}
+
+impl From<TypeDefinition> for ReplInput {
+    fn from(type_definition: TypeDefinition) -> Self {
+        Self::TypeDefinition(type_definition)
+    }
+}
@@ -0,0 +1,55 @@
+use super::{expression::Expression, string_interpolation_part::StringInterpolationPart};
+use crate::{debug::SourceInformation, types::Type};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StringInterpolation {
+    parts: Vec<StringInterpolationPart>,
+    source_information: Arc<SourceInformation>,
+}
+
+impl StringInterpolation {
+    pub fn new(
+        parts: Vec<StringInterpolationPart>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            parts,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn parts(&self) -> &[StringInterpolationPart] {
+        &self.parts
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.parts
+                .iter()
+                .map(|part| part.transform_expressions(transform))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.parts
+                .iter()
+                .map(|part| part.transform_types(transform))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
+}
@@ -0,0 +1,34 @@
+use super::expression::Expression;
+use crate::types::Type;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StringInterpolationPart {
+    Expression(Expression),
+    String(String),
+}
+
+impl StringInterpolationPart {
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(match self {
+            Self::Expression(expression) => {
+                Self::Expression(expression.transform_expressions(transform)?)
+            }
+            Self::String(_) => self.clone(),
+        })
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(match self {
+            Self::Expression(expression) => {
+                Self::Expression(expression.transform_types(transform)?)
+            }
+            Self::String(_) => self.clone(),
+        })
+    }
+}
@@ -4,6 +4,7 @@ use crate::types::Type;
 pub struct TypeDefinition {
     name: String,
     type_: Type,
+    documentation: Option<String>,
 }
 
 impl TypeDefinition {
@@ -11,6 +12,14 @@ impl TypeDefinition {
         Self {
             name: name.into(),
             type_: type_.into(),
+            documentation: None,
+        }
+    }
+
+    pub fn with_documentation(&self, documentation: impl Into<String>) -> Self {
+        Self {
+            documentation: Some(documentation.into()),
+            ..self.clone()
         }
     }
 
@@ -22,13 +31,18 @@ impl TypeDefinition {
         &self.type_
     }
 
+    pub fn documentation(&self) -> Option<&str> {
+        self.documentation.as_deref()
+    }
+
     pub fn transform_types<E>(
         &self,
         transform: &mut impl FnMut(&Type) -> Result<Type, E>,
     ) -> Result<Self, E> {
-        Ok(Self::new(
-            self.name.clone(),
-            self.type_.transform_types(transform)?,
-        ))
+        Ok(Self {
+            name: self.name.clone(),
+            type_: self.type_.transform_types(transform)?,
+            documentation: self.documentation.clone(),
+        })
     }
 }
@@ -0,0 +1,234 @@
+use super::{
+    error::CompileError, reference_type_resolver::ReferenceTypeResolver,
+    type_canonicalizer::TypeCanonicalizer, type_equality_checker::TypeEqualityChecker,
+    warning::CompileWarning,
+};
+use crate::{ast::*, format::format_type_with_names, types::Type};
+use std::{collections::HashMap, sync::Arc};
+
+// Case expressions are checked after type inference so that their argument
+// types are known.
+pub struct CaseExhaustivenessChecker {
+    reference_type_resolver: Arc<ReferenceTypeResolver>,
+    type_canonicalizer: Arc<TypeCanonicalizer>,
+    type_equality_checker: Arc<TypeEqualityChecker>,
+    names: HashMap<String, String>,
+}
+
+impl CaseExhaustivenessChecker {
+    pub fn new(
+        reference_type_resolver: Arc<ReferenceTypeResolver>,
+        type_canonicalizer: Arc<TypeCanonicalizer>,
+        type_equality_checker: Arc<TypeEqualityChecker>,
+        global_names: &HashMap<String, String>,
+    ) -> Self {
+        Self {
+            reference_type_resolver,
+            type_canonicalizer,
+            type_equality_checker,
+            names: global_names
+                .iter()
+                .map(|(name, global_name)| (global_name.clone(), name.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn check(&self, module: &Module) -> Result<Vec<CompileWarning>, CompileError> {
+        let mut warnings = vec![];
+
+        module.transform_expressions(&mut |expression| -> Result<_, CompileError> {
+            if let Expression::Case(case) = expression {
+                warnings.extend(self.check_case(case)?);
+            }
+
+            Ok(expression.clone())
+        })?;
+
+        Ok(warnings)
+    }
+
+    fn check_case(&self, case: &Case) -> Result<Vec<CompileWarning>, CompileError> {
+        let mut covered_types = vec![];
+        let mut warnings = vec![];
+
+        for alternative in case.alternatives() {
+            let types = self.get_member_types(alternative.type_())?;
+
+            if types
+                .iter()
+                .map(|type_| self.is_covered(type_, &covered_types))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .all(|covered| covered)
+            {
+                warnings.push(CompileWarning::UnreachableAlternative(
+                    alternative.type_().source_information().clone(),
+                ));
+            }
+
+            covered_types.extend(types);
+        }
+
+        let mut missing_types = vec![];
+
+        for type_ in self.get_member_types(case.type_())? {
+            if !self.is_covered(&type_, &covered_types)? {
+                missing_types.push(format_type_with_names(&type_, &|name| {
+                    self.names.get(name).cloned().unwrap_or_else(|| name.into())
+                }));
+            }
+        }
+
+        if missing_types.is_empty() {
+            Ok(warnings)
+        } else {
+            Err(CompileError::CaseAlternativesNotExhaustive {
+                types: missing_types,
+                source_information: case.source_information().clone(),
+            })
+        }
+    }
+
+    fn get_member_types(&self, type_: &Type) -> Result<Vec<Type>, CompileError> {
+        let type_ = self.type_canonicalizer.canonicalize(type_)?;
+
+        Ok(match self.reference_type_resolver.resolve(&type_)? {
+            Type::Union(union) => union.types().iter().cloned().collect(),
+            _ => vec![type_],
+        })
+    }
+
+    fn is_covered(&self, type_: &Type, covered_types: &[Type]) -> Result<bool, CompileError> {
+        for covered_type in covered_types {
+            if self.reference_type_resolver.is_any(covered_type)?
+                || self.type_equality_checker.equal(type_, covered_type)?
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, types};
+    use pretty_assertions::assert_eq;
+
+    fn check(case: Case) -> Result<Vec<CompileWarning>, CompileError> {
+        let module = Module::from_definitions(vec![VariableDefinition::new(
+            "x",
+            case,
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+        let reference_type_resolver = ReferenceTypeResolver::new(&module);
+        let type_equality_checker = TypeEqualityChecker::new(reference_type_resolver.clone());
+
+        CaseExhaustivenessChecker::new(
+            reference_type_resolver.clone(),
+            TypeCanonicalizer::new(reference_type_resolver, type_equality_checker.clone()),
+            type_equality_checker,
+            &Default::default(),
+        )
+        .check(&module)
+    }
+
+    fn create_case(argument_type: impl Into<Type>, alternative_types: Vec<Type>) -> Case {
+        Case::with_type(
+            argument_type,
+            "y",
+            Variable::new("z", SourceInformation::dummy()),
+            alternative_types
+                .into_iter()
+                .map(|type_| Alternative::new(type_, Number::new(42.0, SourceInformation::dummy())))
+                .collect(),
+            SourceInformation::dummy(),
+        )
+    }
+
+    fn create_union_type() -> types::Union {
+        types::Union::new(
+            vec![
+                types::Number::new(SourceInformation::dummy()).into(),
+                types::None::new(SourceInformation::dummy()).into(),
+            ],
+            SourceInformation::dummy(),
+        )
+    }
+
+    #[test]
+    fn check_exhaustive_case() {
+        assert_eq!(
+            check(create_case(
+                create_union_type(),
+                vec![
+                    types::Number::new(SourceInformation::dummy()).into(),
+                    types::None::new(SourceInformation::dummy()).into(),
+                ]
+            )),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn check_case_with_any_alternative() {
+        assert_eq!(
+            check(create_case(
+                types::Any::new(SourceInformation::dummy()),
+                vec![
+                    types::Number::new(SourceInformation::dummy()).into(),
+                    types::Any::new(SourceInformation::dummy()).into(),
+                ]
+            )),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn fail_to_check_non_exhaustive_case() {
+        assert_eq!(
+            check(create_case(
+                create_union_type(),
+                vec![types::Number::new(SourceInformation::dummy()).into()]
+            )),
+            Err(CompileError::CaseAlternativesNotExhaustive {
+                types: vec!["None".into()],
+                source_information: SourceInformation::dummy().into()
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_check_case_of_any_without_any_alternative() {
+        assert_eq!(
+            check(create_case(
+                types::Any::new(SourceInformation::dummy()),
+                vec![types::Number::new(SourceInformation::dummy()).into()]
+            )),
+            Err(CompileError::CaseAlternativesNotExhaustive {
+                types: vec!["Any".into()],
+                source_information: SourceInformation::dummy().into()
+            })
+        );
+    }
+
+    #[test]
+    fn check_unreachable_alternative() {
+        assert_eq!(
+            check(create_case(
+                create_union_type(),
+                vec![
+                    types::Any::new(SourceInformation::dummy()).into(),
+                    types::Number::new(SourceInformation::dummy()).into(),
+                ]
+            )),
+            Ok(vec![CompileWarning::UnreachableAlternative(
+                SourceInformation::dummy().into()
+            )])
+        );
+    }
+}
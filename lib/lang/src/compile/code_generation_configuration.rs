@@ -0,0 +1,41 @@
+#[cfg(test)]
+use once_cell::sync::Lazy;
+#[cfg(test)]
+use std::sync::Arc;
+
+#[cfg(test)]
+pub static CODE_GENERATION_CONFIGURATION: Lazy<Arc<CodeGenerationConfiguration>> =
+    Lazy::new(|| {
+        CodeGenerationConfiguration {
+            output_format: OutputFormat::Object,
+            optimization_level: OptimizationLevel::None,
+            tail_call_optimization: true,
+            debug_information: false,
+            target_triple: None,
+        }
+        .into()
+    });
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OutputFormat {
+    BitCode,
+    C,
+    Object,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OptimizationLevel {
+    None,
+    Less,
+    Default,
+    Aggressive,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CodeGenerationConfiguration {
+    pub output_format: OutputFormat,
+    pub optimization_level: OptimizationLevel,
+    pub tail_call_optimization: bool,
+    pub debug_information: bool,
+    pub target_triple: Option<String>,
+}
@@ -1,7 +1,12 @@
 use super::{
+    array_type_configuration::ArrayTypeConfiguration,
+    code_generation_configuration::CodeGenerationConfiguration,
     error_type_configuration::ErrorTypeConfiguration,
+    integer_type_configuration::IntegerTypeConfiguration,
     list_type_configuration::ListTypeConfiguration,
     main_module_configuration::MainModuleConfiguration,
+    map_type_configuration::MapTypeConfiguration,
+    number_type_configuration::NumberTypeConfiguration,
     string_type_configuration::StringTypeConfiguration,
 };
 #[cfg(test)]
@@ -14,11 +19,19 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<CompileConfiguration>> = Lazy::new(||
         malloc_function_name: "foo_malloc".into(),
         realloc_function_name: "foo_realloc".into(),
         free_function_name: "foo_free".into(),
+        array_type_configuration: super::array_type_configuration::ARRAY_TYPE_CONFIGURATION.clone(),
+        integer_type_configuration: super::integer_type_configuration::INTEGER_TYPE_CONFIGURATION
+            .clone(),
         list_type_configuration: super::list_type_configuration::LIST_TYPE_CONFIGURATION.clone(),
+        map_type_configuration: super::map_type_configuration::MAP_TYPE_CONFIGURATION.clone(),
+        number_type_configuration: super::number_type_configuration::NUMBER_TYPE_CONFIGURATION
+            .clone(),
         string_type_configuration: super::string_type_configuration::STRING_TYPE_CONFIGURATION
             .clone(),
         error_type_configuration: super::error_type_configuration::ERROR_TYPE_CONFIGURATION.clone(),
         main_module_configuration: None,
+        code_generation_configuration:
+            super::code_generation_configuration::CODE_GENERATION_CONFIGURATION.clone(),
     }
     .into()
 });
@@ -29,9 +42,14 @@ pub struct CompileConfiguration {
     pub realloc_function_name: String,
     pub free_function_name: String,
     pub error_type_configuration: Arc<ErrorTypeConfiguration>,
+    pub array_type_configuration: Arc<ArrayTypeConfiguration>,
+    pub integer_type_configuration: Arc<IntegerTypeConfiguration>,
     pub list_type_configuration: Arc<ListTypeConfiguration>,
+    pub map_type_configuration: Arc<MapTypeConfiguration>,
+    pub number_type_configuration: Arc<NumberTypeConfiguration>,
     pub string_type_configuration: Arc<StringTypeConfiguration>,
     pub main_module_configuration: Option<Arc<MainModuleConfiguration>>,
+    pub code_generation_configuration: Arc<CodeGenerationConfiguration>,
 }
 
 impl CompileConfiguration {
@@ -40,7 +58,14 @@ impl CompileConfiguration {
 
         configuration.error_type_configuration =
             self.error_type_configuration.qualify(names).into();
+        configuration.array_type_configuration =
+            self.array_type_configuration.qualify(names).into();
+        configuration.integer_type_configuration =
+            self.integer_type_configuration.qualify(names).into();
         configuration.list_type_configuration = self.list_type_configuration.qualify(names).into();
+        configuration.map_type_configuration = self.map_type_configuration.qualify(names).into();
+        configuration.number_type_configuration =
+            self.number_type_configuration.qualify(names).into();
         configuration.string_type_configuration =
             self.string_type_configuration.qualify(names).into();
         configuration.main_module_configuration = self
@@ -0,0 +1,220 @@
+use crate::{ast::*, debug::SourceInformation};
+use std::{collections::HashMap, sync::Arc};
+
+// Debug symbols map global names of definitions to their source information
+// so that code generation can attach source locations to functions compiled
+// from them. Local functions including ones converted from lambdas are named
+// after their parent functions with dots as closures are.
+pub struct DebugSymbolMapCreator {}
+
+impl DebugSymbolMapCreator {
+    pub fn create(module: &Module) -> Arc<HashMap<String, Arc<SourceInformation>>> {
+        let mut symbols = HashMap::new();
+
+        for definition in module.definitions() {
+            symbols.insert(
+                definition.name().into(),
+                definition.source_information().clone(),
+            );
+
+            Self::collect_definition(definition, definition.name(), &mut symbols);
+        }
+
+        symbols.into()
+    }
+
+    fn collect_definition(
+        definition: &Definition,
+        name: &str,
+        symbols: &mut HashMap<String, Arc<SourceInformation>>,
+    ) {
+        match definition {
+            Definition::FunctionDefinition(definition) => {
+                Self::collect_expression(definition.body(), name, symbols)
+            }
+            Definition::VariableDefinition(definition) => {
+                Self::collect_expression(definition.body(), name, symbols)
+            }
+        }
+    }
+
+    fn collect_expression(
+        expression: &Expression,
+        parent_name: &str,
+        symbols: &mut HashMap<String, Arc<SourceInformation>>,
+    ) {
+        let mut collect =
+            |expression: &Expression| Self::collect_expression(expression, parent_name, symbols);
+
+        match expression {
+            Expression::Application(application) => {
+                collect(application.function());
+                collect(application.argument());
+            }
+            Expression::Case(case) => {
+                collect(case.argument());
+
+                for alternative in case.alternatives() {
+                    collect(alternative.expression());
+                }
+            }
+            Expression::If(if_) => {
+                collect(if_.condition());
+                collect(if_.then());
+                collect(if_.else_());
+            }
+            Expression::Let(let_) => {
+                for definition in let_.definitions() {
+                    let name = match definition {
+                        Definition::FunctionDefinition(function_definition) => {
+                            let name = format!("{}.{}", parent_name, function_definition.name());
+
+                            symbols.insert(
+                                name.clone(),
+                                function_definition.source_information().clone(),
+                            );
+
+                            name
+                        }
+                        Definition::VariableDefinition(_) => parent_name.into(),
+                    };
+
+                    Self::collect_definition(definition, &name, symbols);
+                }
+
+                Self::collect_expression(let_.expression(), parent_name, symbols);
+            }
+            Expression::LetError(let_) => {
+                for definition in let_.definitions() {
+                    collect(definition.body());
+                }
+
+                collect(let_.expression());
+            }
+            Expression::List(list) => {
+                for element in list.elements() {
+                    match element {
+                        ListElement::Multiple(expression) | ListElement::Single(expression) => {
+                            collect(expression)
+                        }
+                    }
+                }
+            }
+            Expression::ListCase(case) => {
+                collect(case.argument());
+                collect(case.empty_alternative());
+                collect(case.non_empty_alternative());
+            }
+            Expression::Operation(operation) => {
+                let (lhs, rhs) = match operation {
+                    Operation::Arithmetic(operation) => (operation.lhs(), operation.rhs()),
+                    Operation::Boolean(operation) => (operation.lhs(), operation.rhs()),
+                    Operation::Equality(operation) => (operation.lhs(), operation.rhs()),
+                    Operation::Order(operation) => (operation.lhs(), operation.rhs()),
+                    Operation::Pipe(operation) => (operation.lhs(), operation.rhs()),
+                };
+
+                collect(lhs);
+                collect(rhs);
+            }
+            Expression::RecordConstruction(record) => {
+                for expression in record.elements().values() {
+                    collect(expression);
+                }
+            }
+            Expression::RecordElementOperation(operation) => collect(operation.argument()),
+            Expression::TypeCoercion(coercion) => collect(coercion.argument()),
+            Expression::Boolean(_)
+            | Expression::Integer(_)
+            | Expression::None(_)
+            | Expression::Number(_)
+            | Expression::String(_)
+            | Expression::Variable(_) => {}
+            Expression::Array(_)
+            | Expression::HashOperation(_)
+            | Expression::Lambda(_)
+            | Expression::LetPattern(_)
+            | Expression::ListPatternCase(_)
+            | Expression::Map(_)
+            | Expression::RecordUpdate(_)
+            | Expression::StringInterpolation(_) => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::Location, types};
+
+    #[test]
+    fn create_debug_symbol_map() {
+        let symbols = DebugSymbolMapCreator::create(&Module::from_definitions(vec![
+            FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                None::new(SourceInformation::dummy()),
+                types::Function::new(
+                    types::None::new(SourceInformation::dummy()),
+                    types::None::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::new("Foo.ein", Location::new(2, 1), "f x = none"),
+            )
+            .into(),
+        ]));
+
+        assert_eq!(symbols["f"].source_name(), "Foo.ein");
+        assert_eq!(symbols["f"].location().line_number(), 2);
+    }
+
+    #[test]
+    fn create_debug_symbol_map_with_local_functions() {
+        let function_type = types::Function::new(
+            types::None::new(SourceInformation::dummy()),
+            types::None::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+        let symbols = DebugSymbolMapCreator::create(&Module::from_definitions(vec![
+            FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "g",
+                        vec!["y".into()],
+                        Let::new(
+                            vec![FunctionDefinition::new(
+                                "h",
+                                vec!["z".into()],
+                                None::new(SourceInformation::dummy()),
+                                function_type.clone(),
+                                SourceInformation::new(
+                                    "Foo.ein",
+                                    Location::new(4, 5),
+                                    "h z = none",
+                                ),
+                            )
+                            .into()],
+                            Variable::new("h", SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        function_type.clone(),
+                        SourceInformation::new("Foo.ein", Location::new(3, 3), "g y ="),
+                    )
+                    .into()],
+                    Variable::new("g", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                function_type,
+                SourceInformation::new("Foo.ein", Location::new(1, 1), "f x ="),
+            )
+            .into(),
+        ]));
+
+        assert_eq!(symbols["f"].location().line_number(), 1);
+        assert_eq!(symbols["f.g"].location().line_number(), 3);
+        assert_eq!(symbols["f.g.h"].location().line_number(), 4);
+        assert_eq!(symbols.len(), 3);
+    }
+}
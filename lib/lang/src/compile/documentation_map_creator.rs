@@ -0,0 +1,76 @@
+use crate::ast::*;
+use std::collections::HashMap;
+
+// Documentation is collected from modules before compilation as their
+// definitions are reconstructed without it in some transformations.
+pub struct DocumentationMapCreator {}
+
+impl DocumentationMapCreator {
+    pub fn create(module: &Module) -> HashMap<String, String> {
+        module
+            .type_definitions()
+            .iter()
+            .filter_map(|type_definition| {
+                Some((type_definition.name(), type_definition.documentation()?))
+            })
+            .chain(module.definitions().iter().filter_map(|definition| {
+                if let Definition::FunctionDefinition(function_definition) = definition {
+                    Some((
+                        function_definition.name(),
+                        function_definition.documentation()?,
+                    ))
+                } else {
+                    None
+                }
+            }))
+            .map(|(name, documentation)| {
+                (module.path().fully_qualify_name(name), documentation.into())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, package::Package, path::ModulePath, types};
+
+    #[test]
+    fn create_documentation_map() {
+        let documentation = DocumentationMapCreator::create(&Module::new(
+            ModulePath::new(Package::new("P", ""), vec!["M".into()]),
+            Export::new(Default::default()),
+            ExportForeign::new(Default::default()),
+            vec![],
+            vec![],
+            vec![
+                TypeDefinition::new("Foo", types::None::new(SourceInformation::dummy()))
+                    .with_documentation("foo"),
+                TypeDefinition::new("Bar", types::None::new(SourceInformation::dummy())),
+            ],
+            vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                None::new(SourceInformation::dummy()),
+                types::Function::new(
+                    types::None::new(SourceInformation::dummy()),
+                    types::None::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .with_documentation("f")
+            .into()],
+        ));
+
+        assert_eq!(
+            documentation,
+            vec![
+                ("P().M.Foo".into(), "foo".into()),
+                ("P().M.f".into(), "f".into())
+            ]
+            .into_iter()
+            .collect()
+        );
+    }
+}
@@ -4,6 +4,10 @@ use std::{error::Error, fmt::Display, sync::Arc};
 #[derive(Debug, PartialEq)]
 pub enum CompileError {
     AnyEqualOperation(Arc<SourceInformation>),
+    CaseAlternativesNotExhaustive {
+        types: Vec<String>,
+        source_information: Arc<SourceInformation>,
+    },
     CaseArgumentTypeInvalid(Arc<SourceInformation>),
     DuplicateNames(Arc<SourceInformation>, Arc<SourceInformation>),
     ExportedNameNotFound {
@@ -11,14 +15,37 @@ pub enum CompileError {
     },
     FunctionEqualOperation(Arc<SourceInformation>),
     FunctionExpected(Arc<SourceInformation>),
+    IntegerDivideOperatorExpected(Arc<SourceInformation>),
+    LlvmCodeGeneration(String),
     MainFunctionNotFound(ModulePath),
+    MapKeyNotComparable(Arc<SourceInformation>),
+    NumericOperandsExpected(Arc<SourceInformation>),
+    OpaqueRecordConstruction {
+        name: String,
+        source_information: Arc<SourceInformation>,
+    },
+    OpaqueRecordElementOperation {
+        name: String,
+        source_information: Arc<SourceInformation>,
+    },
+    OpaqueRecordUpdate {
+        name: String,
+        source_information: Arc<SourceInformation>,
+    },
+    OpaqueTypeNotRecord {
+        name: String,
+    },
+    PatternsNotExhaustive(Arc<SourceInformation>),
     RecordElementNotFound {
         record_type: types::Record,
         name: String,
     },
     RecordEqualOperation(Arc<SourceInformation>),
     EirFmmCompile(eir_fmm::CompileError),
+    TailCallsNotGuaranteed,
+    TypeArgumentsNotMatched(types::Reference),
     TypeNotFound(types::Reference),
+    TypeParameterNotFound(types::Parameter),
     TypesNotMatched(Arc<SourceInformation>, Arc<SourceInformation>),
     TypeNotInferred(Arc<SourceInformation>),
     VariableNotFound(ast::Variable),
@@ -32,6 +59,19 @@ impl Display for CompileError {
                 "cannot compare Any type values\n{}",
                 source_information
             ),
+            Self::CaseAlternativesNotExhaustive {
+                types,
+                source_information,
+            } => write!(
+                formatter,
+                "missing alternatives for types {} in case expression\n{}",
+                types
+                    .iter()
+                    .map(|type_| format!("\"{}\"", type_))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                source_information
+            ),
             Self::CaseArgumentTypeInvalid(source_information) => write!(
                 formatter,
                 "invalid argument type of case expression\n{}",
@@ -51,11 +91,59 @@ impl Display for CompileError {
             Self::FunctionExpected(source_information) => {
                 write!(formatter, "function expected\n{}", source_information)
             }
+            Self::IntegerDivideOperatorExpected(source_information) => write!(
+                formatter,
+                "integers must be divided with // operator\n{}",
+                source_information
+            ),
+            Self::LlvmCodeGeneration(message) => {
+                write!(formatter, "failed to generate code: {}", message)
+            }
             Self::MainFunctionNotFound(path) => write!(
                 formatter,
                 "main function not found in main module {}",
                 &path
             ),
+            Self::MapKeyNotComparable(source_information) => write!(
+                formatter,
+                "map keys must not include functions or Any values\n{}",
+                source_information
+            ),
+            Self::NumericOperandsExpected(source_information) => write!(
+                formatter,
+                "operands must be both numbers or both integers\n{}",
+                source_information
+            ),
+            Self::OpaqueRecordConstruction {
+                name,
+                source_information,
+            } => write!(
+                formatter,
+                "cannot construct record of opaque type \"{}\" outside its module\n{}",
+                name, source_information
+            ),
+            Self::OpaqueRecordElementOperation {
+                name,
+                source_information,
+            } => write!(
+                formatter,
+                "cannot access elements of opaque type \"{}\" outside its module\n{}",
+                name, source_information
+            ),
+            Self::OpaqueRecordUpdate {
+                name,
+                source_information,
+            } => write!(
+                formatter,
+                "cannot update record of opaque type \"{}\" outside its module\n{}",
+                name, source_information
+            ),
+            Self::OpaqueTypeNotRecord { name } => {
+                write!(formatter, "opaque type \"{}\" must be record type", name)
+            }
+            Self::PatternsNotExhaustive(source_information) => {
+                write!(formatter, "patterns not exhaustive\n{}", source_information)
+            }
             Self::RecordElementNotFound { record_type, name } => write!(
                 formatter,
                 "element \"{}\" not found in record type\n{}",
@@ -70,12 +158,28 @@ impl Display for CompileError {
             Self::EirFmmCompile(error) => {
                 write!(formatter, "failed to compile eir to fmm: {:?}", error)
             }
+            Self::TailCallsNotGuaranteed => write!(
+                formatter,
+                "C backend cannot guarantee tail calls\nhint: set tailCallOptimization to false in a profile"
+            ),
+            Self::TypeArgumentsNotMatched(reference) => write!(
+                formatter,
+                "wrong number of type arguments for type \"{}\"\n{}",
+                reference.name(),
+                reference.source_information()
+            ),
             Self::TypeNotFound(reference) => write!(
                 formatter,
                 "type \"{}\" not found\n{}",
                 reference.name(),
                 reference.source_information()
             ),
+            Self::TypeParameterNotFound(parameter) => write!(
+                formatter,
+                "type parameter \"{}\" not found\n{}",
+                parameter.name(),
+                parameter.source_information()
+            ),
             Self::TypeNotInferred(source_information) => {
                 write!(formatter, "failed to infer type\n{}", source_information)
             }
@@ -1,6 +1,8 @@
 use super::{
     error::CompileError,
+    integer_type_configuration::IntegerTypeConfiguration,
     last_result_type_calculator::LastResultTypeCalculator,
+    number_type_configuration::NumberTypeConfiguration,
     reference_type_resolver::ReferenceTypeResolver,
     string_type_configuration::StringTypeConfiguration,
     transform::{
@@ -35,6 +37,8 @@ pub struct ExpressionCompiler {
     reference_type_resolver: Arc<ReferenceTypeResolver>,
     last_result_type_calculator: Arc<LastResultTypeCalculator>,
     type_compiler: Arc<TypeCompiler>,
+    integer_type_configuration: Arc<IntegerTypeConfiguration>,
+    number_type_configuration: Arc<NumberTypeConfiguration>,
     string_type_configuration: Arc<StringTypeConfiguration>,
 }
 
@@ -45,6 +49,8 @@ impl ExpressionCompiler {
         reference_type_resolver: Arc<ReferenceTypeResolver>,
         last_result_type_calculator: Arc<LastResultTypeCalculator>,
         type_compiler: Arc<TypeCompiler>,
+        integer_type_configuration: Arc<IntegerTypeConfiguration>,
+        number_type_configuration: Arc<NumberTypeConfiguration>,
         string_type_configuration: Arc<StringTypeConfiguration>,
     ) -> Arc<Self> {
         Self {
@@ -53,6 +59,8 @@ impl ExpressionCompiler {
             reference_type_resolver,
             last_result_type_calculator,
             type_compiler,
+            integer_type_configuration,
+            number_type_configuration,
             string_type_configuration,
         }
         .into()
@@ -77,6 +85,7 @@ impl ExpressionCompiler {
                 self.compile(if_.else_())?,
             )
             .into(),
+            Expression::Integer(integer) => self.compile_integer(integer.value()),
             Expression::Let(let_) => self.compile_let(let_)?,
             Expression::LetError(let_) => self.compile_let_error(let_)?,
             Expression::None(_) => {
@@ -96,12 +105,7 @@ impl ExpressionCompiler {
             )?,
             Expression::Number(number) => number.value().into(),
             Expression::Operation(operation) => match operation {
-                Operation::Arithmetic(operation) => eir::ir::ArithmeticOperation::new(
-                    Self::compile_arithmetic_operator(operation.operator()),
-                    self.compile(operation.lhs())?,
-                    self.compile(operation.rhs())?,
-                )
-                .into(),
+                Operation::Arithmetic(operation) => self.compile_arithmetic_operation(operation)?,
                 Operation::Boolean(operation) => self.compile(
                     &self
                         .expression_transformer_set
@@ -111,6 +115,14 @@ impl ExpressionCompiler {
                 Operation::Equality(operation) => match operation.operator() {
                     EqualityOperator::Equal => {
                         match self.reference_type_resolver.resolve(operation.type_())? {
+                            Type::Integer(_) => Self::compile_numeric_function_application(
+                                &self.integer_type_configuration.equal_function_name,
+                                vec![
+                                    self.compile(operation.lhs())?,
+                                    self.compile(operation.rhs())?,
+                                ],
+                                eir::types::Type::Boolean,
+                            ),
                             Type::Number(_) => eir::ir::ComparisonOperation::new(
                                 eir::ir::ComparisonOperator::Equal,
                                 self.compile(operation.lhs())?,
@@ -153,12 +165,7 @@ impl ExpressionCompiler {
                             .transform(operation),
                     )?,
                 },
-                Operation::Order(operation) => eir::ir::ComparisonOperation::new(
-                    Self::compile_order_operator(operation.operator()),
-                    self.compile(operation.lhs())?,
-                    self.compile(operation.rhs())?,
-                )
-                .into(),
+                Operation::Order(operation) => self.compile_order_operation(operation)?,
                 Operation::Pipe(operation) => self.compile(
                     &Application::with_type(
                         operation.type_().clone(),
@@ -272,6 +279,14 @@ impl ExpressionCompiler {
                             argument,
                         )
                         .into(),
+                        Type::Integer(_) => eir::ir::Variant::new(
+                            self.type_compiler.compile_integer_record(),
+                            eir::ir::Record::new(
+                                self.type_compiler.compile_integer_record(),
+                                vec![argument],
+                            ),
+                        )
+                        .into(),
                         Type::List(list_type) => eir::ir::Variant::new(
                             self.type_compiler.compile_list(list_type)?,
                             eir::ir::Record::new(
@@ -281,7 +296,10 @@ impl ExpressionCompiler {
                         )
                         .into(),
                         Type::Any(_) | Type::Union(_) => argument,
-                        Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => {
+                        Type::Parameter(_)
+                        | Type::Reference(_)
+                        | Type::Unknown(_)
+                        | Type::Variable(_) => {
                             unreachable!()
                         }
                     }
@@ -291,7 +309,14 @@ impl ExpressionCompiler {
                 .expression_compiler_set
                 .variable_compiler
                 .compile(variable)?,
-            Expression::RecordUpdate(_) => unreachable!(),
+            Expression::Array(_)
+            | Expression::HashOperation(_)
+            | Expression::Lambda(_)
+            | Expression::LetPattern(_)
+            | Expression::ListPatternCase(_)
+            | Expression::Map(_)
+            | Expression::RecordUpdate(_)
+            | Expression::StringInterpolation(_) => unreachable!(),
         })
     }
 
@@ -418,6 +443,24 @@ impl ExpressionCompiler {
                     variable_name,
                     self.compile(alternative.expression())?,
                 )]),
+                Type::Integer(_) => {
+                    let integer_type = self.type_compiler.compile_integer_record();
+
+                    Some(vec![eir::ir::Alternative::new(
+                        integer_type.clone(),
+                        variable_name,
+                        eir::ir::Let::new(
+                            variable_name,
+                            eir::types::Type::Number,
+                            eir::ir::RecordElement::new(
+                                integer_type,
+                                0,
+                                eir::ir::Variable::new(variable_name),
+                            ),
+                            self.compile(alternative.expression())?,
+                        ),
+                    )])
+                }
                 Type::List(list_type) => {
                     let list_type = self.type_compiler.compile_list(list_type)?;
 
@@ -441,7 +484,12 @@ impl ExpressionCompiler {
                         .types()
                         .iter()
                         .map(|type_| -> Result<_, CompileError> {
-                            let type_ = self.type_compiler.compile(type_)?;
+                            let type_ = match self.reference_type_resolver.resolve(type_)? {
+                                Type::Integer(_) => {
+                                    self.type_compiler.compile_integer_record().into()
+                                }
+                                _ => self.type_compiler.compile(type_)?,
+                            };
 
                             Ok(eir::ir::Alternative::new(
                                 type_.clone(),
@@ -459,19 +507,139 @@ impl ExpressionCompiler {
                         })
                         .collect::<Result<Vec<_>, _>>()?,
                 ),
-                Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => {
+                Type::Parameter(_) | Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => {
                     unreachable!()
                 }
             },
         )
     }
 
+    // Integers are represented by bits of numbers in EIR as it has no integer
+    // type. Their bits are never interpreted as numbers but passed to and
+    // returned from FFI functions only. So literals are written as number
+    // constants with the same bits which no floating point operation touches.
+    fn compile_integer(&self, value: i64) -> eir::ir::Expression {
+        f64::from_bits(value as u64).into()
+    }
+
+    fn compile_arithmetic_operation(
+        &self,
+        operation: &ArithmeticOperation,
+    ) -> Result<eir::ir::Expression, CompileError> {
+        let lhs = self.compile(operation.lhs())?;
+        let rhs = self.compile(operation.rhs())?;
+
+        Ok(
+            match self.reference_type_resolver.resolve(operation.type_())? {
+                Type::Integer(_) => {
+                    let configuration = &self.integer_type_configuration;
+
+                    Self::compile_numeric_function_application(
+                        match operation.operator() {
+                            ArithmeticOperator::Add => &configuration.add_function_name,
+                            ArithmeticOperator::Subtract => &configuration.subtract_function_name,
+                            ArithmeticOperator::Multiply => &configuration.multiply_function_name,
+                            ArithmeticOperator::IntegerDivide => {
+                                &configuration.divide_function_name
+                            }
+                            ArithmeticOperator::Remainder => &configuration.remainder_function_name,
+                            ArithmeticOperator::Divide => {
+                                return Err(CompileError::IntegerDivideOperatorExpected(
+                                    operation.source_information().clone(),
+                                ))
+                            }
+                        },
+                        vec![lhs, rhs],
+                        eir::types::Type::Number,
+                    )
+                }
+                Type::Number(_) => match operation.operator() {
+                    ArithmeticOperator::IntegerDivide => {
+                        Self::compile_numeric_function_application(
+                            &self.number_type_configuration.integer_divide_function_name,
+                            vec![lhs, rhs],
+                            eir::types::Type::Number,
+                        )
+                    }
+                    ArithmeticOperator::Remainder => Self::compile_numeric_function_application(
+                        &self.number_type_configuration.remainder_function_name,
+                        vec![lhs, rhs],
+                        eir::types::Type::Number,
+                    ),
+                    operator => eir::ir::ArithmeticOperation::new(
+                        Self::compile_arithmetic_operator(operator),
+                        lhs,
+                        rhs,
+                    )
+                    .into(),
+                },
+                _ => unreachable!(),
+            },
+        )
+    }
+
+    fn compile_order_operation(
+        &self,
+        operation: &OrderOperation,
+    ) -> Result<eir::ir::Expression, CompileError> {
+        let lhs = self.compile(operation.lhs())?;
+        let rhs = self.compile(operation.rhs())?;
+
+        let (lhs, rhs) = match self.reference_type_resolver.resolve(operation.type_())? {
+            // Results of integer comparison are compared with zero.
+            Type::Integer(_) => (
+                Self::compile_numeric_function_application(
+                    &self.integer_type_configuration.compare_function_name,
+                    vec![lhs, rhs],
+                    eir::types::Type::Number,
+                ),
+                0.0.into(),
+            ),
+            _ => (lhs, rhs),
+        };
+
+        Ok(eir::ir::ComparisonOperation::new(
+            Self::compile_order_operator(operation.operator()),
+            lhs,
+            rhs,
+        )
+        .into())
+    }
+
+    // Functions for numeric operations take only numbers as arguments.
+    fn compile_numeric_function_application(
+        function_name: &str,
+        arguments: Vec<eir::ir::Expression>,
+        result_type: eir::types::Type,
+    ) -> eir::ir::Expression {
+        let mut function_types = vec![];
+        let mut type_ = result_type;
+
+        for _ in &arguments {
+            let function_type = eir::types::Function::new(eir::types::Type::Number, type_);
+
+            function_types.push(function_type.clone());
+            type_ = function_type.into();
+        }
+
+        arguments
+            .into_iter()
+            .zip(function_types.into_iter().rev())
+            .fold(
+                eir::ir::Variable::new(function_name).into(),
+                |function, (argument, function_type)| {
+                    eir::ir::FunctionApplication::new(function_type, function, argument).into()
+                },
+            )
+    }
+
     fn compile_arithmetic_operator(operator: ArithmeticOperator) -> eir::ir::ArithmeticOperator {
         match operator {
             ArithmeticOperator::Add => eir::ir::ArithmeticOperator::Add,
             ArithmeticOperator::Subtract => eir::ir::ArithmeticOperator::Subtract,
             ArithmeticOperator::Multiply => eir::ir::ArithmeticOperator::Multiply,
             ArithmeticOperator::Divide => eir::ir::ArithmeticOperator::Divide,
+            ArithmeticOperator::IntegerDivide | ArithmeticOperator::Remainder => unreachable!(),
         }
     }
 
@@ -490,7 +658,9 @@ mod tests {
     use super::{
         super::{
             error_type_configuration::ERROR_TYPE_CONFIGURATION,
+            integer_type_configuration::INTEGER_TYPE_CONFIGURATION,
             list_type_configuration::LIST_TYPE_CONFIGURATION,
+            number_type_configuration::NUMBER_TYPE_CONFIGURATION,
             string_type_configuration::STRING_TYPE_CONFIGURATION,
             type_canonicalizer::TypeCanonicalizer,
             type_comparability_checker::TypeComparabilityChecker,
@@ -573,6 +743,8 @@ mod tests {
                 reference_type_resolver,
                 last_result_type_calculator,
                 type_compiler.clone(),
+                INTEGER_TYPE_CONFIGURATION.clone(),
+                NUMBER_TYPE_CONFIGURATION.clone(),
                 STRING_TYPE_CONFIGURATION.clone(),
             ),
             type_compiler,
@@ -589,7 +761,8 @@ mod tests {
 
             assert_eq!(
                 expression_compiler.compile(
-                    &ArithmeticOperation::new(
+                    &ArithmeticOperation::with_type(
+                        types::Number::new(SourceInformation::dummy()),
                         ArithmeticOperator::Add,
                         Number::new(1.0, SourceInformation::dummy()),
                         Number::new(2.0, SourceInformation::dummy()),
@@ -610,7 +783,8 @@ mod tests {
 
             assert_eq!(
                 expression_compiler.compile(
-                    &OrderOperation::new(
+                    &OrderOperation::with_type(
+                        types::Number::new(SourceInformation::dummy()),
                         OrderOperator::LessThan,
                         Number::new(1.0, SourceInformation::dummy()),
                         Number::new(2.0, SourceInformation::dummy()),
@@ -627,6 +801,104 @@ mod tests {
             );
         }
 
+        #[test]
+        fn compile_integer_arithmetic_operation() {
+            let (expression_compiler, _) = create_expression_compiler(&Module::dummy());
+            let integer = |value: i64| f64::from_bits(value as u64);
+
+            assert_eq!(
+                expression_compiler.compile(
+                    &ArithmeticOperation::with_type(
+                        types::Integer::new(SourceInformation::dummy()),
+                        ArithmeticOperator::Remainder,
+                        Integer::new(1, SourceInformation::dummy()),
+                        Integer::new(2, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into(),
+                ),
+                Ok(eir::ir::FunctionApplication::new(
+                    eir::types::Function::new(eir::types::Type::Number, eir::types::Type::Number),
+                    eir::ir::FunctionApplication::new(
+                        eir::types::Function::new(
+                            eir::types::Type::Number,
+                            eir::types::Function::new(
+                                eir::types::Type::Number,
+                                eir::types::Type::Number
+                            )
+                        ),
+                        eir::ir::Variable::new("remainderIntegers"),
+                        integer(1)
+                    ),
+                    integer(2)
+                )
+                .into())
+            );
+        }
+
+        #[test]
+        fn fail_to_compile_integer_divide_operation() {
+            let (expression_compiler, _) = create_expression_compiler(&Module::dummy());
+
+            assert_eq!(
+                expression_compiler.compile(
+                    &ArithmeticOperation::with_type(
+                        types::Integer::new(SourceInformation::dummy()),
+                        ArithmeticOperator::Divide,
+                        Integer::new(1, SourceInformation::dummy()),
+                        Integer::new(2, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into(),
+                ),
+                Err(CompileError::IntegerDivideOperatorExpected(
+                    SourceInformation::dummy().into()
+                ))
+            );
+        }
+
+        #[test]
+        fn compile_integer_comparison_operation() {
+            let (expression_compiler, _) = create_expression_compiler(&Module::dummy());
+            let integer = |value: i64| f64::from_bits(value as u64);
+
+            assert_eq!(
+                expression_compiler.compile(
+                    &OrderOperation::with_type(
+                        types::Integer::new(SourceInformation::dummy()),
+                        OrderOperator::LessThan,
+                        Integer::new(1, SourceInformation::dummy()),
+                        Integer::new(2, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into(),
+                ),
+                Ok(eir::ir::ComparisonOperation::new(
+                    eir::ir::ComparisonOperator::LessThan,
+                    eir::ir::FunctionApplication::new(
+                        eir::types::Function::new(
+                            eir::types::Type::Number,
+                            eir::types::Type::Number
+                        ),
+                        eir::ir::FunctionApplication::new(
+                            eir::types::Function::new(
+                                eir::types::Type::Number,
+                                eir::types::Function::new(
+                                    eir::types::Type::Number,
+                                    eir::types::Type::Number
+                                )
+                            ),
+                            eir::ir::Variable::new("compareIntegers"),
+                            integer(1)
+                        ),
+                        integer(2)
+                    ),
+                    0.0
+                )
+                .into())
+            );
+        }
+
         #[test]
         fn compile_pipe_operation() {
             let (expression_compiler, _) = create_expression_compiler(&Module::dummy());
@@ -655,6 +927,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compile_zero_integer() {
+        let (expression_compiler, _) = create_expression_compiler(&Module::dummy());
+
+        assert_eq!(
+            expression_compiler.compile(&Integer::new(0, SourceInformation::dummy()).into()),
+            Ok(0.0.into())
+        );
+    }
+
+    #[test]
+    fn compile_negative_integer() {
+        let (expression_compiler, _) = create_expression_compiler(&Module::dummy());
+
+        // Bits of most negative integers are NaNs which are not equal to
+        // themselves.
+        assert_eq!(
+            expression_compiler
+                .compile(&Integer::new(-(1 << 62), SourceInformation::dummy()).into()),
+            Ok((-2.0).into())
+        );
+    }
+
     #[test]
     fn compile_let() {
         let (expression_compiler, _) = create_expression_compiler(&Module::dummy());
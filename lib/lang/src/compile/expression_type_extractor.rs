@@ -60,6 +60,9 @@ impl ExpressionTypeExtractor {
                 )
                 .into(),
             )?,
+            Expression::HashOperation(operation) => {
+                types::Number::new(operation.source_information().clone()).into()
+            }
             Expression::If(if_) => self.type_canonicalizer.canonicalize(
                 &types::Union::new(
                     vec![
@@ -126,14 +129,15 @@ impl ExpressionTypeExtractor {
                 )
                 .into(),
             )?,
+            Expression::Integer(integer) => {
+                types::Integer::new(integer.source_information().clone()).into()
+            }
             Expression::None(none) => types::None::new(none.source_information().clone()).into(),
             Expression::Number(number) => {
                 types::Number::new(number.source_information().clone()).into()
             }
             Expression::Operation(operation) => match operation {
-                Operation::Arithmetic(_) => {
-                    types::Number::new(operation.source_information().clone()).into()
-                }
+                Operation::Arithmetic(operation) => operation.type_().clone(),
                 Operation::Boolean(_) | Operation::Equality(_) | Operation::Order(_) => {
                     types::Boolean::new(operation.source_information().clone()).into()
                 }
@@ -158,7 +162,13 @@ impl ExpressionTypeExtractor {
             }
             Expression::TypeCoercion(coercion) => coercion.to().clone(),
             Expression::Variable(variable) => variables[variable.name()].clone(),
-            Expression::RecordUpdate(_) => unreachable!(),
+            Expression::Array(_)
+            | Expression::Lambda(_)
+            | Expression::LetPattern(_)
+            | Expression::ListPatternCase(_)
+            | Expression::Map(_)
+            | Expression::RecordUpdate(_)
+            | Expression::StringInterpolation(_) => unreachable!(),
         })
     }
 }
@@ -352,6 +362,7 @@ mod tests {
                 ModuleInterface::new(
                     ModulePath::new(Package::new("m", ""), vec![]),
                     Default::default(),
+                    Default::default(),
                     vec![(
                         "Error".into(),
                         types::Record::new("Error", Default::default(), SourceInformation::dummy())
@@ -360,6 +371,7 @@ mod tests {
                     .into_iter()
                     .collect(),
                     Default::default(),
+                    Default::default(),
                 ),
                 false,
             )],
@@ -377,7 +389,8 @@ mod tests {
                         types::Number::new(SourceInformation::dummy()),
                         SourceInformation::dummy(),
                     )],
-                    ArithmeticOperation::new(
+                    ArithmeticOperation::with_type(
+                        types::Number::new(SourceInformation::dummy()),
                         ArithmeticOperator::Add,
                         Variable::new("y", SourceInformation::dummy()),
                         Number::new(42.0, SourceInformation::dummy()),
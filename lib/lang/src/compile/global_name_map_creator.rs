@@ -119,12 +119,14 @@ mod tests {
                         ModulePath::new(Package::new("p", ""), vec!["m".into()]),
                         vec!["x".into()].into_iter().collect(),
                         Default::default(),
+                        Default::default(),
                         vec![(
                             "x".into(),
                             types::None::new(SourceInformation::dummy()).into(),
                         )]
                         .into_iter()
                         .collect(),
+                        Default::default(),
                     ),
                     true,
                 )],
@@ -151,12 +153,14 @@ mod tests {
                         ModulePath::new(Package::new("p", ""), vec!["m".into()]),
                         vec!["x".into()].into_iter().collect(),
                         Default::default(),
+                        Default::default(),
                         vec![(
                             "x".into(),
                             types::None::new(SourceInformation::dummy()).into(),
                         )]
                         .into_iter()
                         .collect(),
+                        Default::default(),
                     ),
                     false,
                 )],
@@ -74,14 +74,16 @@ impl GlobalNameRenamer {
         )
         .transform_types(&mut |type_| -> Result<_, ()> {
             Ok(match type_ {
-                Type::Record(record) => types::Record::new(
+                Type::Record(record) => types::Record::with_arguments(
                     self.rename_name(record.name(), &self.names),
+                    record.arguments().to_vec(),
                     record.elements().to_vec(),
                     record.source_information().clone(),
                 )
                 .into(),
-                Type::Reference(reference) => types::Reference::new(
+                Type::Reference(reference) => types::Reference::with_arguments(
                     self.rename_name(reference.name(), &self.names),
+                    reference.arguments().to_vec(),
                     reference.source_information().clone(),
                 )
                 .into(),
@@ -137,6 +139,15 @@ impl GlobalNameRenamer {
                 application.source_information().clone(),
             )
             .into(),
+            Expression::Array(array) => Array::new(
+                array
+                    .elements()
+                    .iter()
+                    .map(|element| self.rename_expression(element, names))
+                    .collect(),
+                array.source_information().clone(),
+            )
+            .into(),
             Expression::Case(case) => Case::with_type(
                 case.type_().clone(),
                 case.name(),
@@ -149,10 +160,24 @@ impl GlobalNameRenamer {
                     case.alternatives()
                         .iter()
                         .map(|alternative| {
-                            Alternative::new(
-                                alternative.type_().clone(),
-                                self.rename_expression(alternative.expression(), &names),
-                            )
+                            if let Some(pattern) = alternative.pattern() {
+                                let mut names = names.clone();
+
+                                for name in pattern.names() {
+                                    names.remove(name);
+                                }
+
+                                Alternative::with_pattern(
+                                    alternative.type_().clone(),
+                                    pattern.clone(),
+                                    self.rename_expression(alternative.expression(), &names),
+                                )
+                            } else {
+                                Alternative::new(
+                                    alternative.type_().clone(),
+                                    self.rename_expression(alternative.expression(), &names),
+                                )
+                            }
                         })
                         .collect()
                 },
@@ -166,6 +191,21 @@ impl GlobalNameRenamer {
                 if_.source_information().clone(),
             )
             .into(),
+            Expression::Lambda(lambda) => {
+                let mut names = names.clone();
+
+                for name in lambda.arguments() {
+                    names.remove(name);
+                }
+
+                Lambda::with_type(
+                    lambda.type_().clone(),
+                    lambda.arguments().to_vec(),
+                    self.rename_expression(lambda.body(), &names),
+                    lambda.source_information().clone(),
+                )
+                .into()
+            }
             Expression::Let(let_) => {
                 let mut names = names.clone();
                 let mut definitions = vec![];
@@ -210,6 +250,29 @@ impl GlobalNameRenamer {
                 )
                 .into()
             }
+            Expression::LetPattern(let_) => {
+                let mut names = names.clone();
+                let mut definitions = vec![];
+
+                for definition in let_.definitions() {
+                    definitions.push(PatternDefinition::new(
+                        definition.pattern().clone(),
+                        self.rename_expression(definition.body(), &names),
+                        definition.source_information().clone(),
+                    ));
+
+                    for name in definition.pattern().names() {
+                        names.remove(name);
+                    }
+                }
+
+                LetPattern::new(
+                    definitions,
+                    self.rename_expression(let_.expression(), &names),
+                    let_.source_information().clone(),
+                )
+                .into()
+            }
             Expression::List(list) => List::new(
                 list.elements()
                     .iter()
@@ -242,8 +305,42 @@ impl GlobalNameRenamer {
                 case.source_information().clone(),
             )
             .into(),
+            Expression::ListPatternCase(case) => ListPatternCase::new(
+                self.rename_expression(case.argument(), names),
+                case.alternatives()
+                    .iter()
+                    .map(|alternative| {
+                        let mut names = names.clone();
+
+                        for name in alternative.pattern().names() {
+                            names.remove(name);
+                        }
+
+                        ListAlternative::new(
+                            alternative.pattern().clone(),
+                            self.rename_expression(alternative.expression(), &names),
+                        )
+                    })
+                    .collect(),
+                case.source_information().clone(),
+            )
+            .into(),
+            Expression::Map(map) => Map::new(
+                map.entries()
+                    .iter()
+                    .map(|entry| {
+                        MapEntry::new(
+                            self.rename_expression(entry.key(), names),
+                            self.rename_expression(entry.value(), names),
+                        )
+                    })
+                    .collect(),
+                map.source_information().clone(),
+            )
+            .into(),
             Expression::Operation(operation) => match operation {
-                Operation::Arithmetic(operation) => ArithmeticOperation::new(
+                Operation::Arithmetic(operation) => ArithmeticOperation::with_type(
+                    operation.type_().clone(),
                     operation.operator(),
                     self.rename_expression(operation.lhs(), names),
                     self.rename_expression(operation.rhs(), names),
@@ -265,7 +362,8 @@ impl GlobalNameRenamer {
                     operation.source_information().clone(),
                 )
                 .into(),
-                Operation::Order(operation) => OrderOperation::new(
+                Operation::Order(operation) => OrderOperation::with_type(
+                    operation.type_().clone(),
                     operation.operator(),
                     self.rename_expression(operation.lhs(), names),
                     self.rename_expression(operation.rhs(), names),
@@ -312,16 +410,33 @@ impl GlobalNameRenamer {
                 record_update.source_information().clone(),
             )
             .into(),
+            Expression::StringInterpolation(interpolation) => StringInterpolation::new(
+                interpolation
+                    .parts()
+                    .iter()
+                    .map(|part| match part {
+                        StringInterpolationPart::Expression(expression) => {
+                            StringInterpolationPart::Expression(
+                                self.rename_expression(expression, names),
+                            )
+                        }
+                        StringInterpolationPart::String(_) => part.clone(),
+                    })
+                    .collect(),
+                interpolation.source_information().clone(),
+            )
+            .into(),
             Expression::Variable(variable) => Variable::new(
                 self.rename_name(variable.name(), names),
                 variable.source_information().clone(),
             )
             .into(),
             Expression::Boolean(_)
+            | Expression::Integer(_)
             | Expression::None(_)
             | Expression::Number(_)
             | Expression::String(_) => expression.clone(),
-            Expression::TypeCoercion(_) => unreachable!(),
+            Expression::HashOperation(_) | Expression::TypeCoercion(_) => unreachable!(),
         }
     }
 
@@ -0,0 +1,49 @@
+#[cfg(test)]
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+#[cfg(test)]
+use std::sync::Arc;
+
+#[cfg(test)]
+pub static INTEGER_TYPE_CONFIGURATION: Lazy<Arc<IntegerTypeConfiguration>> = Lazy::new(|| {
+    IntegerTypeConfiguration {
+        add_function_name: "addIntegers".into(),
+        subtract_function_name: "subtractIntegers".into(),
+        multiply_function_name: "multiplyIntegers".into(),
+        divide_function_name: "divideIntegers".into(),
+        remainder_function_name: "remainderIntegers".into(),
+        equal_function_name: "equalIntegers".into(),
+        compare_function_name: "compareIntegers".into(),
+    }
+    .into()
+});
+
+pub struct IntegerTypeConfiguration {
+    pub add_function_name: String,
+    pub subtract_function_name: String,
+    pub multiply_function_name: String,
+    pub divide_function_name: String,
+    pub remainder_function_name: String,
+    pub equal_function_name: String,
+    // Comparison functions return negative numbers, zero or positive numbers
+    // like C's ones.
+    pub compare_function_name: String,
+}
+
+impl IntegerTypeConfiguration {
+    pub fn qualify(&self, names: &HashMap<String, String>) -> Self {
+        Self {
+            add_function_name: self.qualify_name(&self.add_function_name, names),
+            subtract_function_name: self.qualify_name(&self.subtract_function_name, names),
+            multiply_function_name: self.qualify_name(&self.multiply_function_name, names),
+            divide_function_name: self.qualify_name(&self.divide_function_name, names),
+            remainder_function_name: self.qualify_name(&self.remainder_function_name, names),
+            equal_function_name: self.qualify_name(&self.equal_function_name, names),
+            compare_function_name: self.qualify_name(&self.compare_function_name, names),
+        }
+    }
+
+    fn qualify_name(&self, name: &str, names: &HashMap<String, String>) -> String {
+        names.get(name).cloned().unwrap_or_else(|| name.into())
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+#[cfg(test)]
+use std::sync::Arc;
+
+#[cfg(test)]
+pub static MAP_TYPE_CONFIGURATION: Lazy<Arc<MapTypeConfiguration>> = Lazy::new(|| {
+    MapTypeConfiguration {
+        empty_map_function_name: "emptyMap".into(),
+        insert_function_name: "insertToMap".into(),
+        hash_integer_function_name: "hashInteger".into(),
+        hash_number_function_name: "hashNumber".into(),
+        hash_string_function_name: "hashString".into(),
+        combine_hashes_function_name: "combineHashes".into(),
+    }
+    .into()
+});
+
+pub struct MapTypeConfiguration {
+    pub empty_map_function_name: String,
+    pub insert_function_name: String,
+    pub hash_integer_function_name: String,
+    pub hash_number_function_name: String,
+    pub hash_string_function_name: String,
+    pub combine_hashes_function_name: String,
+}
+
+impl MapTypeConfiguration {
+    pub fn qualify(&self, names: &HashMap<String, String>) -> Self {
+        Self {
+            empty_map_function_name: self.qualify_name(&self.empty_map_function_name, names),
+            insert_function_name: self.qualify_name(&self.insert_function_name, names),
+            hash_integer_function_name: self.qualify_name(&self.hash_integer_function_name, names),
+            hash_number_function_name: self.qualify_name(&self.hash_number_function_name, names),
+            hash_string_function_name: self.qualify_name(&self.hash_string_function_name, names),
+            combine_hashes_function_name: self
+                .qualify_name(&self.combine_hashes_function_name, names),
+        }
+    }
+
+    fn qualify_name(&self, name: &str, names: &HashMap<String, String>) -> String {
+        names.get(name).cloned().unwrap_or_else(|| name.into())
+    }
+}
@@ -1,4 +1,9 @@
+mod array_type_configuration;
+mod case_exhaustiveness_checker;
+mod code_generation_configuration;
 mod compile_configuration;
+mod debug_symbol_map_creator;
+mod documentation_map_creator;
 mod error;
 mod error_type_configuration;
 mod expression_compiler;
@@ -6,44 +11,70 @@ mod expression_type_extractor;
 mod global_name_map_creator;
 mod global_name_renamer;
 mod global_name_validator;
+mod integer_type_configuration;
 mod last_result_type_calculator;
 mod list_type_configuration;
 mod main_function_definition_transformer;
 mod main_module_configuration;
+mod map_type_configuration;
 mod module_compiler;
 mod module_environment_creator;
 mod module_interface_compiler;
 mod name_generator;
+mod number_type_configuration;
+mod object_compiler;
+mod opaque_type_validator;
 mod reference_type_resolver;
+mod stage;
 mod string_type_configuration;
+mod target;
 mod transform;
 mod type_canonicalizer;
 mod type_comparability_checker;
 mod type_compiler;
 mod type_definition_compiler;
 mod type_equality_checker;
+mod type_eraser;
 mod type_id_calculator;
 mod type_inference;
+mod type_parameter_validator;
 mod utilities;
 mod variable_compiler;
+mod warning;
 
-use crate::ast::*;
+use crate::{ast::*, format::format_module};
+pub use array_type_configuration::ArrayTypeConfiguration;
+use case_exhaustiveness_checker::CaseExhaustivenessChecker;
+pub use code_generation_configuration::{
+    CodeGenerationConfiguration, OptimizationLevel, OutputFormat,
+};
 pub use compile_configuration::CompileConfiguration;
-use error::CompileError;
+#[cfg(test)]
+pub(crate) use compile_configuration::COMPILE_CONFIGURATION;
+use debug_symbol_map_creator::DebugSymbolMapCreator;
+use documentation_map_creator::DocumentationMapCreator;
+pub(crate) use error::CompileError;
 pub use error_type_configuration::ErrorTypeConfiguration;
 use expression_compiler::{ExpressionCompiler, ExpressionCompilerSet, ExpressionTransformerSet};
 use global_name_map_creator::GlobalNameMapCreator;
 use global_name_renamer::GlobalNameRenamer;
 use global_name_validator::GlobalNameValidator;
+pub use integer_type_configuration::IntegerTypeConfiguration;
 use last_result_type_calculator::LastResultTypeCalculator;
 pub use list_type_configuration::ListTypeConfiguration;
 use main_function_definition_transformer::MainFunctionDefinitionTransformer;
 pub use main_module_configuration::MainModuleConfiguration;
+pub use map_type_configuration::MapTypeConfiguration;
 use module_compiler::ModuleCompiler;
 use module_interface_compiler::ModuleInterfaceCompiler;
-use reference_type_resolver::ReferenceTypeResolver;
-use std::sync::Arc;
+pub use number_type_configuration::NumberTypeConfiguration;
+use object_compiler::compile_bit_code;
+use opaque_type_validator::OpaqueTypeValidator;
+pub(crate) use reference_type_resolver::ReferenceTypeResolver;
+pub use stage::{Stage, StageHook};
+use std::{collections::HashMap, sync::Arc};
 pub use string_type_configuration::StringTypeConfiguration;
+pub use target::is_wasm_target;
 use transform::{
     transform_before_name_qualification, transform_with_types, transform_without_types,
     BooleanOperationTransformer, EqualOperationTransformer, FunctionTypeCoercionTransformer,
@@ -54,34 +85,38 @@ use type_canonicalizer::TypeCanonicalizer;
 use type_comparability_checker::TypeComparabilityChecker;
 use type_compiler::TypeCompiler;
 use type_definition_compiler::TypeDefinitionCompiler;
-use type_equality_checker::TypeEqualityChecker;
+pub(crate) use type_equality_checker::TypeEqualityChecker;
 use type_id_calculator::TypeIdCalculator;
 use type_inference::infer_types;
+use type_parameter_validator::TypeParameterValidator;
 use variable_compiler::VariableCompiler;
+pub use warning::CompileWarning;
 
 pub fn compile(
     module: &Module,
     configuration: Arc<CompileConfiguration>,
-) -> Result<(Vec<u8>, ModuleInterface), CompileError> {
-    GlobalNameValidator::new().validate(module)?;
-
-    let module = transform_before_name_qualification(module)?;
+) -> Result<(Vec<u8>, ModuleInterface, Vec<CompileWarning>), CompileError> {
+    compile_with_stage_hook(module, configuration, &mut |_, _| {})
+}
 
-    let module = if let Some(main_module_configuration) = &configuration.main_module_configuration {
-        MainFunctionDefinitionTransformer::new(main_module_configuration.clone())
-            .transform(&module)?
-    } else {
-        module
-    };
+pub fn compile_with_stage_hook(
+    module: &Module,
+    configuration: Arc<CompileConfiguration>,
+    stage_hook: &mut StageHook,
+) -> Result<(Vec<u8>, ModuleInterface, Vec<CompileWarning>), CompileError> {
+    // C compilers optimize tail calls only optionally.
+    if configuration.code_generation_configuration.output_format == OutputFormat::C
+        && configuration
+            .code_generation_configuration
+            .tail_call_optimization
+    {
+        return Err(CompileError::TailCallsNotGuaranteed);
+    }
 
-    let global_names = GlobalNameMapCreator::create(&module);
-    let configuration = Arc::new(configuration.qualify(&global_names));
-    let module = GlobalNameRenamer::new(global_names.clone()).rename(&module);
+    let (module, module_interface, warnings, global_names, configuration) =
+        compile_to_typed_module(module, configuration, stage_hook)?;
 
-    let module = transform_with_types(
-        &infer_types(&transform_without_types(&module)?, configuration.clone())?,
-        configuration.clone(),
-    )?;
+    let debug_symbols = DebugSymbolMapCreator::create(&module);
 
     let reference_type_resolver = ReferenceTypeResolver::new(&module);
     let type_comparability_checker = TypeComparabilityChecker::new(reference_type_resolver.clone());
@@ -154,40 +189,147 @@ pub fn compile(
         reference_type_resolver,
         last_result_type_calculator,
         type_compiler.clone(),
+        configuration.integer_type_configuration.clone(),
+        configuration.number_type_configuration.clone(),
         configuration.string_type_configuration.clone(),
     );
 
+    let eir_module = ModuleCompiler::new(
+        expression_compiler,
+        type_compiler,
+        type_definition_compiler,
+        global_names,
+    )
+    .compile(&module)?;
+
+    stage_hook(Stage::Eir, &|| format!("{:#?}", eir_module));
+
     let fmm_module = fmm::analysis::transform_to_cps(
-        &eir_fmm::compile(
-            &ModuleCompiler::new(
-                expression_compiler,
-                type_compiler,
-                type_definition_compiler,
-                global_names,
-            )
-            .compile(&module)?,
-        )?,
+        &eir_fmm::compile(&eir_module)?,
         fmm::types::Record::new(vec![]),
     )
     .unwrap();
 
-    fmm::analysis::check_types(&fmm_module).unwrap();
-
-    Ok((
-        fmm_llvm::compile_to_bit_code(
+    let compile_to_c = || {
+        fmm_c::compile(
             &fmm_module,
-            &fmm_llvm::HeapConfiguration {
+            &fmm_c::HeapConfiguration {
                 allocate_function_name: configuration.malloc_function_name.clone(),
                 reallocate_function_name: configuration.realloc_function_name.clone(),
                 free_function_name: configuration.free_function_name.clone(),
             },
-            None,
         )
-        .unwrap(),
-        ModuleInterfaceCompiler::new().compile(&module)?,
+    };
+
+    // FMM modules are rendered as C as it is the only textual form of them.
+    stage_hook(Stage::Fmm, &compile_to_c);
+
+    fmm::analysis::check_types(&fmm_module).unwrap();
+
+    Ok((
+        if configuration.code_generation_configuration.output_format == OutputFormat::C {
+            compile_to_c().into_bytes()
+        } else {
+            compile_bit_code(
+                &fmm_llvm::compile_to_bit_code(
+                    &fmm_module,
+                    &fmm_llvm::HeapConfiguration {
+                        allocate_function_name: configuration.malloc_function_name.clone(),
+                        reallocate_function_name: configuration.realloc_function_name.clone(),
+                        free_function_name: configuration.free_function_name.clone(),
+                    },
+                    configuration
+                        .code_generation_configuration
+                        .target_triple
+                        .as_deref(),
+                )
+                .unwrap(),
+                &configuration.code_generation_configuration,
+                &debug_symbols,
+                stage_hook,
+            )?
+        },
+        module_interface,
+        warnings,
+    ))
+}
+
+// Modules are type-checked and transformed with types here so that they can
+// be either compiled into EIR or interpreted. Module interfaces are compiled
+// before type parameters are erased.
+#[allow(clippy::type_complexity)]
+pub(crate) fn compile_to_typed_module(
+    module: &Module,
+    configuration: Arc<CompileConfiguration>,
+    stage_hook: &mut StageHook,
+) -> Result<
+    (
+        Module,
+        ModuleInterface,
+        Vec<CompileWarning>,
+        Arc<HashMap<String, String>>,
+        Arc<CompileConfiguration>,
+    ),
+    CompileError,
+> {
+    GlobalNameValidator::new().validate(module)?;
+    TypeParameterValidator::new().validate(module)?;
+
+    let documentation = DocumentationMapCreator::create(module);
+
+    let module = transform_before_name_qualification(module)?;
+
+    stage_hook(Stage::Ast, &|| format_module(&module));
+
+    let module = if let Some(main_module_configuration) = &configuration.main_module_configuration {
+        MainFunctionDefinitionTransformer::new(main_module_configuration.clone())
+            .transform(&module)?
+    } else {
+        module
+    };
+
+    let global_names = GlobalNameMapCreator::create(&module);
+    let configuration = Arc::new(configuration.qualify(&global_names));
+    let module = GlobalNameRenamer::new(global_names.clone()).rename(&module);
+
+    OpaqueTypeValidator::new(ReferenceTypeResolver::new(&module), &global_names)
+        .validate(&module)?;
+
+    let module = infer_types(
+        &transform_without_types(&module, configuration.clone())?,
+        configuration.clone(),
+    )?;
+    let warnings = check_cases(&module, &global_names)?;
+    let module_interface = ModuleInterfaceCompiler::new().compile(&module, &documentation)?;
+    let module = transform_with_types(&module, configuration.clone())?;
+
+    stage_hook(Stage::TypedAst, &|| format_module(&module));
+
+    Ok((
+        module,
+        module_interface,
+        warnings,
+        global_names,
+        configuration,
     ))
 }
 
+fn check_cases(
+    module: &Module,
+    global_names: &HashMap<String, String>,
+) -> Result<Vec<CompileWarning>, CompileError> {
+    let reference_type_resolver = ReferenceTypeResolver::new(module);
+    let type_equality_checker = TypeEqualityChecker::new(reference_type_resolver.clone());
+
+    CaseExhaustivenessChecker::new(
+        reference_type_resolver.clone(),
+        TypeCanonicalizer::new(reference_type_resolver, type_equality_checker.clone()),
+        type_equality_checker,
+        global_names,
+    )
+    .check(module)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{compile_configuration::COMPILE_CONFIGURATION, *};
@@ -653,6 +795,54 @@ mod tests {
         .unwrap();
     }
 
+    mod integer {
+        use super::*;
+
+        #[test]
+        fn compile_integer_in_union_type() {
+            compile(
+                &Module::from_definitions(vec![
+                    VariableDefinition::new(
+                        "x",
+                        Integer::new(42, SourceInformation::dummy()),
+                        types::Union::new(
+                            vec![
+                                types::Integer::new(SourceInformation::dummy()).into(),
+                                types::Number::new(SourceInformation::dummy()).into(),
+                            ],
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    VariableDefinition::new(
+                        "y",
+                        Case::new(
+                            "z",
+                            Variable::new("x", SourceInformation::dummy()),
+                            vec![
+                                Alternative::new(
+                                    types::Integer::new(SourceInformation::dummy()),
+                                    Variable::new("z", SourceInformation::dummy()),
+                                ),
+                                Alternative::new(
+                                    types::Number::new(SourceInformation::dummy()),
+                                    Integer::new(0, SourceInformation::dummy()),
+                                ),
+                            ],
+                            SourceInformation::dummy(),
+                        ),
+                        types::Integer::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ]),
+                COMPILE_CONFIGURATION.clone(),
+            )
+            .unwrap();
+        }
+    }
+
     mod string {
         use super::*;
 
@@ -807,6 +997,7 @@ mod tests {
                     ModuleInterface::new(
                         ModulePath::new(Package::new("m", ""), vec![]),
                         Default::default(),
+                        Default::default(),
                         vec![(
                             "Error".into(),
                             types::Record::new(
@@ -819,6 +1010,7 @@ mod tests {
                         .into_iter()
                         .collect(),
                         Default::default(),
+                        Default::default(),
                     ),
                     false,
                 )],
@@ -883,6 +1075,7 @@ mod tests {
                     ModuleInterface::new(
                         ModulePath::new(Package::new("m", ""), vec![]),
                         Default::default(),
+                        Default::default(),
                         vec![(
                             "Error".into(),
                             types::Record::new(
@@ -895,6 +1088,7 @@ mod tests {
                         .into_iter()
                         .collect(),
                         Default::default(),
+                        Default::default(),
                     ),
                     false,
                 )],
@@ -964,4 +1158,159 @@ mod tests {
 
         compile(&module, COMPILE_CONFIGURATION.clone()).unwrap();
     }
+
+    #[test]
+    fn compile_for_aarch64() {
+        let mut configuration = COMPILE_CONFIGURATION.as_ref().clone();
+        configuration.code_generation_configuration = CodeGenerationConfiguration {
+            target_triple: Some("aarch64-unknown-linux-gnu".into()),
+            ..configuration.code_generation_configuration.as_ref().clone()
+        }
+        .into();
+
+        let (object, _) = compile(
+            &Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Number::new(42.0, SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()]),
+            configuration.into(),
+        )
+        .unwrap();
+
+        assert_eq!(&object[..4], b"\x7fELF");
+        // EM_AARCH64 in the little-endian e_machine field
+        assert_eq!(&object[18..20], &[183, 0]);
+    }
+
+    #[test]
+    fn compile_for_wasm32_wasi() {
+        let mut configuration = COMPILE_CONFIGURATION.as_ref().clone();
+        configuration.code_generation_configuration = CodeGenerationConfiguration {
+            target_triple: Some("wasm32-wasi".into()),
+            ..configuration.code_generation_configuration.as_ref().clone()
+        }
+        .into();
+
+        let (object, _) = compile(
+            &Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Number::new(42.0, SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()]),
+            configuration.into(),
+        )
+        .unwrap();
+
+        assert_eq!(&object[..4], b"\0asm");
+    }
+
+    #[test]
+    fn compile_to_c() {
+        let mut configuration = COMPILE_CONFIGURATION.as_ref().clone();
+        configuration.code_generation_configuration = CodeGenerationConfiguration {
+            output_format: OutputFormat::C,
+            tail_call_optimization: false,
+            ..configuration.code_generation_configuration.as_ref().clone()
+        }
+        .into();
+
+        let (source, _) = compile(
+            &Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Number::new(42.0, SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()]),
+            configuration.into(),
+        )
+        .unwrap();
+
+        assert!(!source.is_empty());
+    }
+
+    #[test]
+    fn fail_to_compile_to_c_with_tail_call_optimization() {
+        let mut configuration = COMPILE_CONFIGURATION.as_ref().clone();
+        configuration.code_generation_configuration = CodeGenerationConfiguration {
+            output_format: OutputFormat::C,
+            tail_call_optimization: true,
+            ..configuration.code_generation_configuration.as_ref().clone()
+        }
+        .into();
+
+        assert_eq!(
+            compile(&Module::dummy(), configuration.into()),
+            Err(CompileError::TailCallsNotGuaranteed)
+        );
+    }
+
+    #[test]
+    fn compile_with_debug_information() {
+        let mut configuration = COMPILE_CONFIGURATION.as_ref().clone();
+        configuration.code_generation_configuration = CodeGenerationConfiguration {
+            debug_information: true,
+            target_triple: Some("x86_64-unknown-linux-gnu".into()),
+            ..configuration.code_generation_configuration.as_ref().clone()
+        }
+        .into();
+
+        let (object, _) = compile(
+            &Module::from_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::new("/foo/Bar.ein", Location::new(1, 1), "f x = x"),
+            )
+            .into()]),
+            configuration.into(),
+        )
+        .unwrap();
+
+        assert!(object
+            .windows(".debug_line".len())
+            .any(|name| name == b".debug_line"));
+    }
+
+    #[test]
+    fn compile_without_debug_information() {
+        let mut configuration = COMPILE_CONFIGURATION.as_ref().clone();
+        configuration.code_generation_configuration = CodeGenerationConfiguration {
+            debug_information: false,
+            target_triple: Some("x86_64-unknown-linux-gnu".into()),
+            ..configuration.code_generation_configuration.as_ref().clone()
+        }
+        .into();
+
+        let (object, _) = compile(
+            &Module::from_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::new("/foo/Bar.ein", Location::new(1, 1), "f x = x"),
+            )
+            .into()]),
+            configuration.into(),
+        )
+        .unwrap();
+
+        assert!(!object
+            .windows(".debug_line".len())
+            .any(|name| name == b".debug_line"));
+    }
 }
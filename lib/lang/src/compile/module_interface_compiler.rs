@@ -1,5 +1,6 @@
 use super::error::CompileError;
-use crate::ast::*;
+use crate::{ast::*, types::Type};
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct ModuleInterfaceCompiler {}
@@ -9,7 +10,11 @@ impl ModuleInterfaceCompiler {
         Self {}
     }
 
-    pub fn compile(&self, module: &Module) -> Result<ModuleInterface, CompileError> {
+    pub fn compile(
+        &self,
+        module: &Module,
+        documentation: &HashMap<String, String>,
+    ) -> Result<ModuleInterface, CompileError> {
         if let Some(name) = module.export().names().iter().find(|name| {
             let exported_name = module.path().fully_qualify_name(name);
 
@@ -21,10 +26,20 @@ impl ModuleInterfaceCompiler {
                 .any(|name| name == exported_name)
         }) {
             Err(CompileError::ExportedNameNotFound { name: name.into() })
+        } else if let Some(name) = module.export().opaque_names().iter().find(|name| {
+            let exported_name = module.path().fully_qualify_name(name);
+
+            !module.type_definitions().iter().any(|type_definition| {
+                type_definition.name() == exported_name
+                    && matches!(type_definition.type_(), Type::Record(_))
+            })
+        }) {
+            Err(CompileError::OpaqueTypeNotRecord { name: name.into() })
         } else {
             Ok(ModuleInterface::new(
                 module.path().clone(),
                 module.export().names().iter().cloned().collect(),
+                module.export().opaque_names().iter().cloned().collect(),
                 module
                     .type_definitions()
                     .iter()
@@ -40,6 +55,13 @@ impl ModuleInterfaceCompiler {
                     .iter()
                     .map(|definition| (definition.name().into(), definition.type_().clone()))
                     .collect(),
+                module
+                    .export()
+                    .names()
+                    .iter()
+                    .map(|name| module.path().fully_qualify_name(name))
+                    .filter_map(|name| Some((name.clone(), documentation.get(&name)?.clone())))
+                    .collect(),
             ))
         }
     }
@@ -60,12 +82,15 @@ mod tests {
     #[test]
     fn compile_empty_module_interface() {
         assert_eq!(
-            ModuleInterfaceCompiler::new().compile(&Module::from_definitions(vec![]),),
+            ModuleInterfaceCompiler::new()
+                .compile(&Module::from_definitions(vec![]), &Default::default()),
             Ok(ModuleInterface::new(
                 ModulePath::new(Package::new("", ""), vec![]),
                 Default::default(),
                 Default::default(),
                 Default::default(),
+                Default::default(),
+                Default::default(),
             ))
         );
     }
@@ -73,31 +98,36 @@ mod tests {
     #[test]
     fn compile_module_interface_with_definition() {
         assert_eq!(
-            ModuleInterfaceCompiler::new().compile(&Module::new(
-                ModulePath::new(Package::new("P", ""), vec!["M".into()]),
-                Export::new(vec!["x".into()].into_iter().collect()),
-                ExportForeign::new(Default::default()),
-                vec![],
-                vec![],
-                vec![],
-                vec![VariableDefinition::new(
-                    "P().M.x",
-                    Number::new(42.0, SourceInformation::dummy()),
-                    types::Number::new(SourceInformation::dummy()),
-                    SourceInformation::dummy(),
-                )
-                .into()],
-            ),),
+            ModuleInterfaceCompiler::new().compile(
+                &Module::new(
+                    ModulePath::new(Package::new("P", ""), vec!["M".into()]),
+                    Export::new(vec!["x".into()].into_iter().collect()),
+                    ExportForeign::new(Default::default()),
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![VariableDefinition::new(
+                        "P().M.x",
+                        Number::new(42.0, SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                ),
+                &vec![("P().M.x".into(), "foo".into())].into_iter().collect()
+            ),
             Ok(ModuleInterface::new(
                 ModulePath::new(Package::new("P", ""), vec!["M".into()]),
                 vec!["x".into()].into_iter().collect(),
                 Default::default(),
+                Default::default(),
                 vec![(
                     "P().M.x".into(),
                     types::Number::new(SourceInformation::dummy()).into()
                 )]
                 .into_iter()
-                .collect()
+                .collect(),
+                vec![("P().M.x".into(), "foo".into())].into_iter().collect()
             ))
         );
     }
@@ -105,16 +135,84 @@ mod tests {
     #[test]
     fn fail_to_compile_module_interface_due_to_missing_exported_name() {
         assert_eq!(
-            ModuleInterfaceCompiler::new().compile(&Module::new(
-                ModulePath::new(Package::new("P", ""), vec!["M".into()]),
-                Export::new(vec!["x".into()].into_iter().collect()),
-                ExportForeign::new(Default::default()),
-                vec![],
-                vec![],
-                vec![],
-                vec![],
-            ),),
+            ModuleInterfaceCompiler::new().compile(
+                &Module::new(
+                    ModulePath::new(Package::new("P", ""), vec!["M".into()]),
+                    Export::new(vec!["x".into()].into_iter().collect()),
+                    ExportForeign::new(Default::default()),
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                ),
+                &Default::default()
+            ),
             Err(CompileError::ExportedNameNotFound { name: "x".into() })
         );
     }
+
+    #[test]
+    fn compile_module_interface_with_opaque_type() {
+        let record_type = types::Record::new(
+            "P().M.Foo",
+            vec![types::RecordElement::new(
+                "foo",
+                types::Number::new(SourceInformation::dummy()),
+            )],
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            ModuleInterfaceCompiler::new().compile(
+                &Module::new(
+                    ModulePath::new(Package::new("P", ""), vec!["M".into()]),
+                    Export::with_opaque_names(
+                        vec!["Foo".into()].into_iter().collect(),
+                        vec!["Foo".into()].into_iter().collect()
+                    ),
+                    ExportForeign::new(Default::default()),
+                    vec![],
+                    vec![],
+                    vec![TypeDefinition::new("P().M.Foo", record_type.clone())],
+                    vec![],
+                ),
+                &Default::default()
+            ),
+            Ok(ModuleInterface::new(
+                ModulePath::new(Package::new("P", ""), vec!["M".into()]),
+                vec!["Foo".into()].into_iter().collect(),
+                vec!["Foo".into()].into_iter().collect(),
+                vec![("P().M.Foo".into(), record_type.into())]
+                    .into_iter()
+                    .collect(),
+                Default::default(),
+                Default::default(),
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_compile_module_interface_due_to_opaque_type_alias() {
+        assert_eq!(
+            ModuleInterfaceCompiler::new().compile(
+                &Module::new(
+                    ModulePath::new(Package::new("P", ""), vec!["M".into()]),
+                    Export::with_opaque_names(
+                        vec!["Foo".into()].into_iter().collect(),
+                        vec!["Foo".into()].into_iter().collect()
+                    ),
+                    ExportForeign::new(Default::default()),
+                    vec![],
+                    vec![],
+                    vec![TypeDefinition::new(
+                        "P().M.Foo",
+                        types::Number::new(SourceInformation::dummy())
+                    )],
+                    vec![],
+                ),
+                &Default::default()
+            ),
+            Err(CompileError::OpaqueTypeNotRecord { name: "Foo".into() })
+        );
+    }
 }
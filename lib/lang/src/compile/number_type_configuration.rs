@@ -0,0 +1,34 @@
+#[cfg(test)]
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+#[cfg(test)]
+use std::sync::Arc;
+
+#[cfg(test)]
+pub static NUMBER_TYPE_CONFIGURATION: Lazy<Arc<NumberTypeConfiguration>> = Lazy::new(|| {
+    NumberTypeConfiguration {
+        integer_divide_function_name: "integerDivideNumbers".into(),
+        remainder_function_name: "remainderNumbers".into(),
+    }
+    .into()
+});
+
+// Only operations not supported natively by EIR are configured here.
+pub struct NumberTypeConfiguration {
+    pub integer_divide_function_name: String,
+    pub remainder_function_name: String,
+}
+
+impl NumberTypeConfiguration {
+    pub fn qualify(&self, names: &HashMap<String, String>) -> Self {
+        Self {
+            integer_divide_function_name: self
+                .qualify_name(&self.integer_divide_function_name, names),
+            remainder_function_name: self.qualify_name(&self.remainder_function_name, names),
+        }
+    }
+
+    fn qualify_name(&self, name: &str, names: &HashMap<String, String>) -> String {
+        names.get(name).cloned().unwrap_or_else(|| name.into())
+    }
+}
@@ -0,0 +1,568 @@
+use super::{
+    code_generation_configuration::{CodeGenerationConfiguration, OptimizationLevel, OutputFormat},
+    error::CompileError,
+    stage::{Stage, StageHook},
+    target::is_wasm_target,
+};
+use crate::debug::SourceInformation;
+use llvm_sys::{
+    bit_reader::LLVMParseBitcodeInContext2, bit_writer::LLVMWriteBitcodeToMemoryBuffer, core::*,
+    debuginfo::*, prelude::*, target::*, target_machine::*, LLVMCallConv, LLVMModuleFlagBehavior,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ffi::{CStr, CString},
+    path::Path,
+    sync::{Arc, Once},
+};
+
+// LLVM guarantees tail call optimization for calls in tail position with this
+// calling convention even without the `-tailcallopt` option which cannot be
+// set through the C API.
+const TAIL_CALL_CONVENTION: u32 = 18;
+const DWARF_VERSION: u64 = 4;
+const PRODUCER: &str = "ein";
+
+static INITIALIZE_NATIVE_TARGET: Once = Once::new();
+static INITIALIZE_ALL_TARGETS: Once = Once::new();
+
+pub fn compile_bit_code(
+    bit_code: &[u8],
+    configuration: &CodeGenerationConfiguration,
+    debug_symbols: &HashMap<String, Arc<SourceInformation>>,
+    stage_hook: &mut StageHook,
+) -> Result<Vec<u8>, CompileError> {
+    unsafe {
+        let context = LLVMContextCreate();
+        let result = parse_bit_code(context, bit_code).and_then(|module| {
+            if configuration.tail_call_optimization {
+                enable_tail_calls(module);
+            }
+
+            if configuration.debug_information {
+                add_debug_information(context, module, configuration, debug_symbols);
+            }
+
+            stage_hook(Stage::LlvmIr, &|| print_module(module));
+            stage_hook(Stage::Assembly, &|| {
+                emit_machine_code(module, configuration, LLVMCodeGenFileType::LLVMAssemblyFile)
+                    .map(|data| String::from_utf8_lossy(&data).into())
+                    .unwrap_or_else(|error| error.to_string())
+            });
+
+            let result = match configuration.output_format {
+                OutputFormat::BitCode => {
+                    Ok(read_memory_buffer(LLVMWriteBitcodeToMemoryBuffer(module)))
+                }
+                OutputFormat::C => unreachable!(),
+                OutputFormat::Object => {
+                    emit_machine_code(module, configuration, LLVMCodeGenFileType::LLVMObjectFile)
+                }
+            };
+
+            LLVMDisposeModule(module);
+
+            result
+        });
+
+        LLVMContextDispose(context);
+
+        result
+    }
+}
+
+unsafe fn parse_bit_code(
+    context: LLVMContextRef,
+    bit_code: &[u8],
+) -> Result<LLVMModuleRef, CompileError> {
+    let name = CString::new("module").unwrap();
+    let buffer = LLVMCreateMemoryBufferWithMemoryRangeCopy(
+        bit_code.as_ptr() as *const _,
+        bit_code.len(),
+        name.as_ptr(),
+    );
+    let mut module = std::ptr::null_mut();
+
+    let failed = LLVMParseBitcodeInContext2(context, buffer, &mut module) != 0;
+
+    LLVMDisposeMemoryBuffer(buffer);
+
+    if failed {
+        Err(CompileError::LlvmCodeGeneration(
+            "failed to parse bit code".into(),
+        ))
+    } else {
+        Ok(module)
+    }
+}
+
+unsafe fn enable_tail_calls(module: LLVMModuleRef) {
+    let mut function = LLVMGetFirstFunction(module);
+
+    while !function.is_null() {
+        convert_calling_convention(LLVMGetFunctionCallConv(function), |convention| {
+            LLVMSetFunctionCallConv(function, convention)
+        });
+
+        let mut block = LLVMGetFirstBasicBlock(function);
+
+        while !block.is_null() {
+            let mut instruction = LLVMGetFirstInstruction(block);
+
+            while !instruction.is_null() {
+                if !LLVMIsACallInst(instruction).is_null() {
+                    convert_calling_convention(
+                        LLVMGetInstructionCallConv(instruction),
+                        |convention| LLVMSetInstructionCallConv(instruction, convention),
+                    );
+                }
+
+                instruction = LLVMGetNextInstruction(instruction);
+            }
+
+            block = LLVMGetNextBasicBlock(block);
+        }
+
+        function = LLVMGetNextFunction(function);
+    }
+}
+
+fn convert_calling_convention(convention: u32, set: impl FnOnce(u32)) {
+    if convention == LLVMCallConv::LLVMFastCallConv as u32 {
+        set(TAIL_CALL_CONVENTION)
+    }
+}
+
+// Functions compiled from definitions get subprograms and locations of the
+// definitions as other compiler stages do not preserve source information of
+// expressions. Local functions and lambdas get their own ones so that
+// expressions in them are located at their lines. Each source file gets its own compile unit because a debug
+// information builder can create only one.
+unsafe fn add_debug_information(
+    context: LLVMContextRef,
+    module: LLVMModuleRef,
+    configuration: &CodeGenerationConfiguration,
+    debug_symbols: &HashMap<String, Arc<SourceInformation>>,
+) {
+    let mut compile_units = BTreeMap::new();
+    let mut function = LLVMGetFirstFunction(module);
+
+    while !function.is_null() {
+        if LLVMCountBasicBlocks(function) > 0 {
+            let mut length = 0;
+            let symbol = std::slice::from_raw_parts(
+                LLVMGetValueName2(function, &mut length) as *const u8,
+                length,
+            );
+            let symbol = String::from_utf8_lossy(symbol);
+
+            if let Some((name, source_information)) = find_debug_symbol(&symbol, debug_symbols) {
+                let &mut (builder, file, subroutine_type) = compile_units
+                    .entry(source_information.source_name())
+                    .or_insert_with(|| {
+                        create_compile_unit(module, source_information.source_name(), configuration)
+                    });
+
+                add_subprogram(
+                    context,
+                    builder,
+                    function,
+                    file,
+                    subroutine_type,
+                    name,
+                    &symbol,
+                    source_information,
+                    configuration,
+                );
+            }
+        }
+
+        function = LLVMGetNextFunction(function);
+    }
+
+    if compile_units.is_empty() {
+        return;
+    }
+
+    add_module_flag(
+        context,
+        module,
+        "Debug Info Version",
+        LLVMDebugMetadataVersion().into(),
+    );
+    add_module_flag(context, module, "Dwarf Version", DWARF_VERSION);
+
+    for &(builder, _, _) in compile_units.values() {
+        LLVMDIBuilderFinalize(builder);
+        LLVMDisposeDIBuilder(builder);
+    }
+}
+
+unsafe fn create_compile_unit(
+    module: LLVMModuleRef,
+    source_name: &str,
+    configuration: &CodeGenerationConfiguration,
+) -> (LLVMDIBuilderRef, LLVMMetadataRef, LLVMMetadataRef) {
+    let builder = LLVMCreateDIBuilder(module);
+    let file = create_file(builder, source_name);
+    let empty = "";
+
+    LLVMDIBuilderCreateCompileUnit(
+        builder,
+        LLVMDWARFSourceLanguage::LLVMDWARFSourceLanguageC,
+        file,
+        PRODUCER.as_ptr() as *const _,
+        PRODUCER.len(),
+        (configuration.optimization_level != OptimizationLevel::None) as i32,
+        empty.as_ptr() as *const _,
+        0,
+        0,
+        empty.as_ptr() as *const _,
+        0,
+        LLVMDWARFEmissionKind::LLVMDWARFEmissionKindLineTablesOnly,
+        0,
+        0,
+        0,
+        empty.as_ptr() as *const _,
+        0,
+        empty.as_ptr() as *const _,
+        0,
+    );
+
+    (
+        builder,
+        file,
+        LLVMDIBuilderCreateSubroutineType(builder, file, std::ptr::null_mut(), 0, 0),
+    )
+}
+
+unsafe fn add_module_flag(context: LLVMContextRef, module: LLVMModuleRef, key: &str, value: u64) {
+    LLVMAddModuleFlag(
+        module,
+        LLVMModuleFlagBehavior::LLVMModuleFlagBehaviorWarning,
+        key.as_ptr() as *const _,
+        key.len(),
+        LLVMValueAsMetadata(LLVMConstInt(LLVMInt32TypeInContext(context), value, 0)),
+    );
+}
+
+unsafe fn create_file(builder: LLVMDIBuilderRef, source_name: &str) -> LLVMMetadataRef {
+    let path = Path::new(source_name);
+    let filename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| source_name.into());
+    let directory = path
+        .parent()
+        .map(|directory| directory.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    LLVMDIBuilderCreateFile(
+        builder,
+        filename.as_ptr() as *const _,
+        filename.len(),
+        directory.as_ptr() as *const _,
+        directory.len(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn add_subprogram(
+    context: LLVMContextRef,
+    builder: LLVMDIBuilderRef,
+    function: LLVMValueRef,
+    file: LLVMMetadataRef,
+    subroutine_type: LLVMMetadataRef,
+    name: &str,
+    symbol: &str,
+    source_information: &SourceInformation,
+    configuration: &CodeGenerationConfiguration,
+) {
+    let line = source_information.location().line_number() as u32;
+    let subprogram = LLVMDIBuilderCreateFunction(
+        builder,
+        file,
+        name.as_ptr() as *const _,
+        name.len(),
+        symbol.as_ptr() as *const _,
+        symbol.len(),
+        file,
+        line,
+        subroutine_type,
+        0,
+        1,
+        line,
+        0,
+        (configuration.optimization_level != OptimizationLevel::None) as i32,
+    );
+
+    LLVMSetSubprogram(function, subprogram);
+
+    let location = LLVMDIBuilderCreateDebugLocation(
+        context,
+        line,
+        source_information.location().column_number() as u32,
+        subprogram,
+        std::ptr::null_mut(),
+    );
+    let mut block = LLVMGetFirstBasicBlock(function);
+
+    while !block.is_null() {
+        let mut instruction = LLVMGetFirstInstruction(block);
+
+        while !instruction.is_null() {
+            LLVMInstructionSetDebugLoc(instruction, location);
+            instruction = LLVMGetNextInstruction(instruction);
+        }
+
+        block = LLVMGetNextBasicBlock(block);
+    }
+}
+
+// Functions are named after definitions with suffixes separated by dots for
+// thunks, closures and continuations generated from them. So symbols are
+// looked up exactly with their suffixes removed one by one.
+fn find_debug_symbol<'a>(
+    symbol: &str,
+    debug_symbols: &'a HashMap<String, Arc<SourceInformation>>,
+) -> Option<(&'a str, &'a SourceInformation)> {
+    let mut name = symbol;
+
+    loop {
+        if let Some((name, source_information)) = debug_symbols.get_key_value(name) {
+            return Some((name.as_str(), source_information.as_ref()));
+        }
+
+        name = &name[..name.rfind('.')?];
+    }
+}
+
+unsafe fn print_module(module: LLVMModuleRef) -> String {
+    let string = LLVMPrintModuleToString(module);
+    let ir = CStr::from_ptr(string).to_string_lossy().into();
+
+    LLVMDisposeMessage(string);
+
+    ir
+}
+
+unsafe fn emit_machine_code(
+    module: LLVMModuleRef,
+    configuration: &CodeGenerationConfiguration,
+    file_type: LLVMCodeGenFileType,
+) -> Result<Vec<u8>, CompileError> {
+    let triple = if let Some(triple) = &configuration.target_triple {
+        INITIALIZE_ALL_TARGETS.call_once(|| {
+            LLVM_InitializeAllTargetInfos();
+            LLVM_InitializeAllTargets();
+            LLVM_InitializeAllTargetMCs();
+            LLVM_InitializeAllAsmPrinters();
+        });
+
+        LLVMCreateMessage(CString::new(triple.as_str()).unwrap().as_ptr())
+    } else {
+        INITIALIZE_NATIVE_TARGET.call_once(|| {
+            LLVM_InitializeNativeTarget();
+            LLVM_InitializeNativeAsmPrinter();
+        });
+
+        LLVMGetDefaultTargetTriple()
+    };
+    let mut target = std::ptr::null_mut();
+    let mut message = std::ptr::null_mut();
+
+    if LLVMGetTargetFromTriple(triple, &mut target, &mut message) != 0 {
+        LLVMDisposeMessage(triple);
+        return Err(read_message(message));
+    }
+
+    // WebAssembly modules are linked statically and need the tail call
+    // extension for guaranteed tail calls.
+    let wasm = is_wasm_target(&CStr::from_ptr(triple).to_string_lossy());
+    let cpu = CString::new("").unwrap();
+    let features = CString::new(if wasm && configuration.tail_call_optimization {
+        "+tail-call"
+    } else {
+        ""
+    })
+    .unwrap();
+    let target_machine = LLVMCreateTargetMachine(
+        target,
+        triple,
+        cpu.as_ptr(),
+        features.as_ptr(),
+        convert_optimization_level(configuration.optimization_level),
+        if wasm {
+            LLVMRelocMode::LLVMRelocStatic
+        } else {
+            LLVMRelocMode::LLVMRelocPIC
+        },
+        LLVMCodeModel::LLVMCodeModelDefault,
+    );
+
+    LLVMSetTarget(module, triple);
+    LLVMDisposeMessage(triple);
+
+    let mut buffer = std::ptr::null_mut();
+    let failed = LLVMTargetMachineEmitToMemoryBuffer(
+        target_machine,
+        module,
+        file_type,
+        &mut message,
+        &mut buffer,
+    ) != 0;
+
+    LLVMDisposeTargetMachine(target_machine);
+
+    if failed {
+        Err(read_message(message))
+    } else {
+        Ok(read_memory_buffer(buffer))
+    }
+}
+
+fn convert_optimization_level(level: OptimizationLevel) -> LLVMCodeGenOptLevel {
+    match level {
+        OptimizationLevel::None => LLVMCodeGenOptLevel::LLVMCodeGenLevelNone,
+        OptimizationLevel::Less => LLVMCodeGenOptLevel::LLVMCodeGenLevelLess,
+        OptimizationLevel::Default => LLVMCodeGenOptLevel::LLVMCodeGenLevelDefault,
+        OptimizationLevel::Aggressive => LLVMCodeGenOptLevel::LLVMCodeGenLevelAggressive,
+    }
+}
+
+unsafe fn read_memory_buffer(buffer: LLVMMemoryBufferRef) -> Vec<u8> {
+    let data = std::slice::from_raw_parts(
+        LLVMGetBufferStart(buffer) as *const u8,
+        LLVMGetBufferSize(buffer),
+    )
+    .to_vec();
+
+    LLVMDisposeMemoryBuffer(buffer);
+
+    data
+}
+
+unsafe fn read_message(message: *mut std::os::raw::c_char) -> CompileError {
+    let error = CompileError::LlvmCodeGeneration(CStr::from_ptr(message).to_string_lossy().into());
+
+    LLVMDisposeMessage(message);
+
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debug::Location;
+    use llvm_sys::ir_reader::LLVMParseIRInContext;
+
+    unsafe fn compile_ir(ir: &str) -> Vec<u8> {
+        let context = LLVMContextCreate();
+        let name = CString::new("module").unwrap();
+        let buffer = LLVMCreateMemoryBufferWithMemoryRangeCopy(
+            ir.as_ptr() as *const _,
+            ir.len(),
+            name.as_ptr(),
+        );
+        let mut module = std::ptr::null_mut();
+        let mut message = std::ptr::null_mut();
+
+        assert_eq!(
+            LLVMParseIRInContext(context, buffer, &mut module, &mut message),
+            0
+        );
+
+        let bit_code = read_memory_buffer(LLVMWriteBitcodeToMemoryBuffer(module));
+
+        LLVMDisposeModule(module);
+        LLVMContextDispose(context);
+
+        bit_code
+    }
+
+    #[test]
+    fn add_debug_information_of_local_functions() {
+        let bit_code = unsafe {
+            compile_ir(
+                r#"
+                define i64 @"foo().Foo.foo"(i64 %0) {
+                  %2 = call i64 @"foo().Foo.foo.lambda_0"(i64 %0)
+                  ret i64 %2
+                }
+
+                define i64 @"foo().Foo.foo.lambda_0"(i64 %0) {
+                  %2 = add i64 %0, 1
+                  ret i64 %2
+                }
+                "#,
+            )
+        };
+        let mut ir = String::new();
+
+        compile_bit_code(
+            &bit_code,
+            &CodeGenerationConfiguration {
+                output_format: OutputFormat::BitCode,
+                optimization_level: OptimizationLevel::None,
+                tail_call_optimization: false,
+                debug_information: true,
+                target_triple: None,
+            },
+            &vec![
+                (
+                    "foo().Foo.foo".into(),
+                    SourceInformation::new("Foo.ein", Location::new(1, 1), "").into(),
+                ),
+                (
+                    "foo().Foo.foo.lambda_0".into(),
+                    SourceInformation::new("Foo.ein", Location::new(2, 3), "").into(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            &mut |stage, render| {
+                if stage == Stage::LlvmIr {
+                    ir = render();
+                }
+            },
+        )
+        .unwrap();
+
+        assert!(ir.contains("!DILocation(line: 1, column: 1,"));
+        assert!(ir.contains("!DILocation(line: 2, column: 3,"));
+    }
+
+    #[test]
+    fn find_debug_symbols() {
+        let debug_symbols = vec![
+            ("foo().Foo.foo".into(), SourceInformation::dummy().into()),
+            (
+                "foo().Foo.foo.bar".into(),
+                SourceInformation::dummy().into(),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            find_debug_symbol("foo().Foo.foo", &debug_symbols).map(|(name, _)| name),
+            Some("foo().Foo.foo")
+        );
+        assert_eq!(
+            find_debug_symbol("foo().Foo.foo.thunk", &debug_symbols).map(|(name, _)| name),
+            Some("foo().Foo.foo")
+        );
+        assert_eq!(
+            find_debug_symbol("foo().Foo.foo.bar.entry", &debug_symbols).map(|(name, _)| name),
+            Some("foo().Foo.foo.bar")
+        );
+        assert_eq!(
+            find_debug_symbol("foo().Foo.foo_1", &debug_symbols).map(|(name, _)| name),
+            None
+        );
+        assert_eq!(
+            find_debug_symbol("foo().Foo.bar", &debug_symbols).map(|(name, _)| name),
+            None
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use super::{error::CompileError, reference_type_resolver::ReferenceTypeResolver};
+use crate::{ast::*, types::Type};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+// Records of opaque types are constructed, updated and deconstructed only in
+// modules where they are defined. Modules are validated after their names are
+// qualified and before patterns and record updates are desugared.
+pub struct OpaqueTypeValidator {
+    reference_type_resolver: Arc<ReferenceTypeResolver>,
+    names: HashMap<String, String>,
+}
+
+impl OpaqueTypeValidator {
+    pub fn new(
+        reference_type_resolver: Arc<ReferenceTypeResolver>,
+        global_names: &HashMap<String, String>,
+    ) -> Self {
+        Self {
+            reference_type_resolver,
+            names: global_names
+                .iter()
+                .map(|(name, global_name)| (global_name.clone(), name.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn validate(&self, module: &Module) -> Result<(), CompileError> {
+        let opaque_names = module
+            .imports()
+            .iter()
+            .flat_map(|import| {
+                let module_interface = import.module_interface();
+
+                module_interface
+                    .opaque_names()
+                    .iter()
+                    .map(move |name| module_interface.path().fully_qualify_name(name))
+            })
+            .collect::<HashSet<_>>();
+
+        if opaque_names.is_empty() {
+            return Ok(());
+        }
+
+        // Element functions of opaque types are mapped to their type names.
+        let element_function_names = module
+            .imports()
+            .iter()
+            .flat_map(|import| {
+                import
+                    .module_interface()
+                    .types()
+                    .iter()
+                    .filter(|(name, _)| opaque_names.contains(name.as_str()))
+                    .flat_map(|(name, type_)| {
+                        if let Type::Record(record) = type_ {
+                            record.elements().to_vec()
+                        } else {
+                            vec![]
+                        }
+                        .into_iter()
+                        .map(move |element| (format!("{}.{}", name, element.name()), name))
+                    })
+            })
+            .collect::<HashMap<_, _>>();
+
+        module.transform_expressions(&mut |expression| -> Result<_, CompileError> {
+            match expression {
+                Expression::Case(case) => {
+                    for pattern in case.alternatives().iter().flat_map(Alternative::pattern) {
+                        self.validate_pattern(pattern, &opaque_names)?;
+                    }
+                }
+                Expression::LetPattern(let_) => {
+                    for definition in let_.definitions() {
+                        self.validate_pattern(definition.pattern(), &opaque_names)?;
+                    }
+                }
+                Expression::ListPatternCase(case) => {
+                    for alternative in case.alternatives() {
+                        for pattern in alternative.pattern().elements() {
+                            self.validate_pattern(pattern, &opaque_names)?;
+                        }
+                    }
+                }
+                Expression::RecordConstruction(record_construction) => {
+                    if let Some(name) =
+                        self.find_opaque_name(record_construction.type_(), &opaque_names)?
+                    {
+                        return Err(CompileError::OpaqueRecordConstruction {
+                            name,
+                            source_information: record_construction.source_information().clone(),
+                        });
+                    }
+                }
+                Expression::RecordUpdate(record_update) => {
+                    if let Some(name) =
+                        self.find_opaque_name(record_update.type_(), &opaque_names)?
+                    {
+                        return Err(CompileError::OpaqueRecordUpdate {
+                            name,
+                            source_information: record_update.source_information().clone(),
+                        });
+                    }
+                }
+                Expression::RecordElementOperation(operation) => {
+                    if let Some(name) = self.find_opaque_name(operation.type_(), &opaque_names)? {
+                        return Err(CompileError::OpaqueRecordElementOperation {
+                            name,
+                            source_information: operation.source_information().clone(),
+                        });
+                    }
+                }
+                // Records without elements are constructed by variables of the
+                // same names as their types.
+                Expression::Variable(variable) if opaque_names.contains(variable.name()) => {
+                    return Err(CompileError::OpaqueRecordConstruction {
+                        name: self.get_name(variable.name()),
+                        source_information: variable.source_information().clone(),
+                    });
+                }
+                Expression::Variable(variable) => {
+                    if let Some(name) = element_function_names.get(variable.name()) {
+                        return Err(CompileError::OpaqueRecordElementOperation {
+                            name: self.get_name(name),
+                            source_information: variable.source_information().clone(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+
+            Ok(expression.clone())
+        })?;
+
+        Ok(())
+    }
+
+    fn validate_pattern(
+        &self,
+        pattern: &Pattern,
+        opaque_names: &HashSet<String>,
+    ) -> Result<(), CompileError> {
+        match pattern {
+            Pattern::List(list) => {
+                for pattern in list.elements() {
+                    self.validate_pattern(pattern, opaque_names)?;
+                }
+            }
+            Pattern::Record(record) => {
+                if let Some(name) = self.find_opaque_name(record.type_(), opaque_names)? {
+                    return Err(CompileError::OpaqueRecordElementOperation {
+                        name,
+                        source_information: record.source_information().clone(),
+                    });
+                }
+
+                for pattern in record.elements().values() {
+                    self.validate_pattern(pattern, opaque_names)?;
+                }
+            }
+            Pattern::Number(_) | Pattern::String(_) | Pattern::Variable(_) => {}
+        }
+
+        Ok(())
+    }
+
+    // Type aliases of opaque types are resolved here.
+    fn find_opaque_name(
+        &self,
+        type_: &Type,
+        opaque_names: &HashSet<String>,
+    ) -> Result<Option<String>, CompileError> {
+        Ok(self
+            .reference_type_resolver
+            .resolve_to_record(type_)?
+            .filter(|record| opaque_names.contains(record.name()))
+            .map(|record| self.get_name(record.name())))
+    }
+
+    fn get_name(&self, name: &str) -> String {
+        self.names.get(name).cloned().unwrap_or_else(|| name.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, package::Package, path::ModulePath, types};
+
+    fn validate(module: &Module) -> Result<(), CompileError> {
+        OpaqueTypeValidator::new(
+            ReferenceTypeResolver::new(module),
+            &vec![("Foo.Foo".into(), "Foo().Foo".into())]
+                .into_iter()
+                .collect(),
+        )
+        .validate(module)
+    }
+
+    fn create_module(definitions: Vec<Definition>) -> Module {
+        Module::new(
+            ModulePath::dummy(),
+            Export::new(Default::default()),
+            ExportForeign::new(Default::default()),
+            vec![Import::new(
+                ModuleInterface::new(
+                    ModulePath::new(Package::new("Foo", ""), vec![]),
+                    vec!["Foo".into()].into_iter().collect(),
+                    vec!["Foo".into()].into_iter().collect(),
+                    vec![(
+                        "Foo().Foo".into(),
+                        types::Record::new(
+                            "Foo().Foo",
+                            vec![types::RecordElement::new(
+                                "foo",
+                                types::Number::new(SourceInformation::dummy()),
+                            )],
+                            SourceInformation::dummy(),
+                        )
+                        .into(),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    Default::default(),
+                    Default::default(),
+                ),
+                true,
+            )],
+            vec![],
+            vec![],
+            definitions,
+        )
+    }
+
+    #[test]
+    fn validate_opaque_type_reference() {
+        let reference_type = types::Reference::new("Foo().Foo", SourceInformation::dummy());
+
+        assert_eq!(
+            validate(&create_module(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    reference_type.clone(),
+                    reference_type,
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_opaque_record_construction() {
+        let reference_type = types::Reference::new("Foo().Foo", SourceInformation::dummy());
+
+        assert_eq!(
+            validate(&create_module(vec![VariableDefinition::new(
+                "x",
+                RecordConstruction::new(
+                    reference_type.clone(),
+                    vec![(
+                        "foo".into(),
+                        Number::new(42.0, SourceInformation::dummy()).into()
+                    )]
+                    .into_iter()
+                    .collect(),
+                    SourceInformation::dummy(),
+                ),
+                reference_type,
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(CompileError::OpaqueRecordConstruction {
+                name: "Foo.Foo".into(),
+                source_information: SourceInformation::dummy().into(),
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_opaque_record_update() {
+        let reference_type = types::Reference::new("Foo().Foo", SourceInformation::dummy());
+
+        assert_eq!(
+            validate(&create_module(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                RecordUpdate::new(
+                    reference_type.clone(),
+                    Variable::new("x", SourceInformation::dummy()),
+                    vec![(
+                        "foo".into(),
+                        Number::new(42.0, SourceInformation::dummy()).into()
+                    )]
+                    .into_iter()
+                    .collect(),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    reference_type.clone(),
+                    reference_type,
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(CompileError::OpaqueRecordUpdate {
+                name: "Foo.Foo".into(),
+                source_information: SourceInformation::dummy().into(),
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_opaque_record_pattern() {
+        let reference_type = types::Reference::new("Foo().Foo", SourceInformation::dummy());
+
+        assert_eq!(
+            validate(&create_module(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                LetPattern::new(
+                    vec![PatternDefinition::new(
+                        RecordPattern::new(
+                            reference_type.clone(),
+                            vec![(
+                                "foo".into(),
+                                Variable::new("y", SourceInformation::dummy()).into()
+                            )]
+                            .into_iter()
+                            .collect(),
+                            SourceInformation::dummy(),
+                        ),
+                        Variable::new("x", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )],
+                    Variable::new("y", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    reference_type,
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(CompileError::OpaqueRecordElementOperation {
+                name: "Foo.Foo".into(),
+                source_information: SourceInformation::dummy().into(),
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_opaque_record_element_operation() {
+        let reference_type = types::Reference::new("Foo().Foo", SourceInformation::dummy());
+
+        assert_eq!(
+            validate(&create_module(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                RecordElementOperation::new(
+                    reference_type.clone(),
+                    "foo",
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    reference_type,
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(CompileError::OpaqueRecordElementOperation {
+                name: "Foo.Foo".into(),
+                source_information: SourceInformation::dummy().into(),
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_opaque_record_element_function() {
+        let reference_type = types::Reference::new("Foo().Foo", SourceInformation::dummy());
+
+        assert_eq!(
+            validate(&create_module(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Application::new(
+                    Variable::new("Foo().Foo.foo", SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    reference_type,
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(CompileError::OpaqueRecordElementOperation {
+                name: "Foo.Foo".into(),
+                source_information: SourceInformation::dummy().into(),
+            })
+        );
+    }
+}
@@ -35,11 +35,52 @@ impl ReferenceTypeResolver {
     }
 
     pub fn resolve_reference(&self, reference: &types::Reference) -> Result<Type, CompileError> {
-        self.resolve(
-            self.environment
-                .get(reference.name())
-                .ok_or_else(|| CompileError::TypeNotFound(reference.clone()))?,
-        )
+        let type_ = self
+            .environment
+            .get(reference.name())
+            .ok_or_else(|| CompileError::TypeNotFound(reference.clone()))?;
+
+        match type_ {
+            Type::Record(record) if record.arguments().len() != reference.arguments().len() => {
+                Err(CompileError::TypeArgumentsNotMatched(reference.clone()))
+            }
+            Type::Record(record) => {
+                let arguments = record
+                    .arguments()
+                    .iter()
+                    .zip(reference.arguments())
+                    .filter_map(|(parameter, argument)| match parameter {
+                        Type::Parameter(parameter) => Some((parameter.name(), argument)),
+                        _ => None,
+                    })
+                    .collect::<HashMap<_, _>>();
+
+                Ok(record
+                    .transform_types(&mut |type_| -> Result<_, ()> {
+                        Ok(match type_ {
+                            Type::Parameter(parameter) => arguments
+                                .get(parameter.name())
+                                .map(|&type_| type_.clone())
+                                .unwrap_or_else(|| type_.clone()),
+                            _ => type_.clone(),
+                        })
+                    })
+                    .unwrap()
+                    .into())
+            }
+            _ if !reference.arguments().is_empty() => {
+                Err(CompileError::TypeArgumentsNotMatched(reference.clone()))
+            }
+            _ => self.resolve(type_),
+        }
+    }
+
+    // Type parameters of a generic record type are returned as its type arguments.
+    pub fn resolve_type_parameters(&self, name: &str) -> Option<&[Type]> {
+        match self.environment.get(name)? {
+            Type::Record(record) => Some(record.arguments()),
+            _ => None,
+        }
     }
 
     pub fn resolve(&self, type_: &Type) -> Result<Type, CompileError> {
@@ -48,9 +89,11 @@ impl ReferenceTypeResolver {
             Type::Any(_)
             | Type::Boolean(_)
             | Type::Function(_)
+            | Type::Integer(_)
             | Type::List(_)
             | Type::None(_)
             | Type::Number(_)
+            | Type::Parameter(_)
             | Type::Record(_)
             | Type::String(_)
             | Type::Union(_)
@@ -139,6 +182,7 @@ mod tests {
                     ModuleInterface::new(
                         ModulePath::new(Package::new("Foo", ""), vec![]),
                         Default::default(),
+                        Default::default(),
                         vec![(
                             "Foo".into(),
                             types::Number::new(SourceInformation::dummy()).into()
@@ -146,6 +190,7 @@ mod tests {
                         .drain(..)
                         .collect(),
                         Default::default(),
+                        Default::default(),
                     ),
                     true
                 )],
@@ -0,0 +1,60 @@
+use super::code_generation_configuration::OutputFormat;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Stage {
+    Ast,
+    TypedAst,
+    Eir,
+    Fmm,
+    LlvmIr,
+    Assembly,
+}
+
+impl Stage {
+    // The C backend does not go through LLVM.
+    pub fn is_emitted(self, output_format: OutputFormat) -> bool {
+        !matches!(
+            (self, output_format),
+            (Self::LlvmIr, OutputFormat::C) | (Self::Assembly, OutputFormat::C)
+        )
+    }
+}
+
+impl FromStr for Stage {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, ()> {
+        match name {
+            "ast" => Ok(Self::Ast),
+            "typed-ast" => Ok(Self::TypedAst),
+            "eir" => Ok(Self::Eir),
+            "fmm" => Ok(Self::Fmm),
+            "llvm-ir" => Ok(Self::LlvmIr),
+            "asm" => Ok(Self::Assembly),
+            _ => Err(()),
+        }
+    }
+}
+
+// Stage hooks receive functions to render intermediate representations so
+// that they are rendered only when requested.
+pub type StageHook<'a> = dyn FnMut(Stage, &dyn Fn() -> String) + 'a;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emit_llvm_stages_for_llvm_backend() {
+        assert!(Stage::LlvmIr.is_emitted(OutputFormat::Object));
+        assert!(Stage::Assembly.is_emitted(OutputFormat::BitCode));
+    }
+
+    #[test]
+    fn do_not_emit_llvm_stages_for_c_backend() {
+        assert!(Stage::Fmm.is_emitted(OutputFormat::C));
+        assert!(!Stage::LlvmIr.is_emitted(OutputFormat::C));
+        assert!(!Stage::Assembly.is_emitted(OutputFormat::C));
+    }
+}
@@ -8,18 +8,26 @@ use std::sync::Arc;
 pub static STRING_TYPE_CONFIGURATION: Lazy<Arc<StringTypeConfiguration>> = Lazy::new(|| {
     StringTypeConfiguration {
         equal_function_name: "equalStrings".into(),
+        concatenate_function_name: "concatenateStrings".into(),
+        convert_to_string_function_name: "convertToString".into(),
     }
     .into()
 });
 
 pub struct StringTypeConfiguration {
     pub equal_function_name: String,
+    pub concatenate_function_name: String,
+    // Conversion functions accept any values allowed in string interpolation.
+    pub convert_to_string_function_name: String,
 }
 
 impl StringTypeConfiguration {
     pub fn qualify(&self, names: &HashMap<String, String>) -> Self {
         Self {
             equal_function_name: self.qualify_name(&self.equal_function_name, names),
+            concatenate_function_name: self.qualify_name(&self.concatenate_function_name, names),
+            convert_to_string_function_name: self
+                .qualify_name(&self.convert_to_string_function_name, names),
         }
     }
 
@@ -0,0 +1,17 @@
+const WASM_ARCHITECTURE_PREFIX: &str = "wasm";
+
+pub fn is_wasm_target(triple: &str) -> bool {
+    triple.starts_with(WASM_ARCHITECTURE_PREFIX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_wasm_target() {
+        assert!(is_wasm_target("wasm32-wasi"));
+        assert!(is_wasm_target("wasm32-unknown-unknown"));
+        assert!(!is_wasm_target("aarch64-unknown-linux-gnu"));
+    }
+}
@@ -0,0 +1,107 @@
+use super::super::array_type_configuration::ArrayTypeConfiguration;
+use crate::ast::*;
+use std::sync::Arc;
+
+/// ArrayLiteralTransformer converts array literals into pushes of their
+/// elements into empty arrays.
+pub struct ArrayLiteralTransformer {
+    array_type_configuration: Arc<ArrayTypeConfiguration>,
+}
+
+impl ArrayLiteralTransformer {
+    pub fn new(array_type_configuration: Arc<ArrayTypeConfiguration>) -> Self {
+        Self {
+            array_type_configuration,
+        }
+    }
+
+    pub fn transform(&self, module: &Module) -> Module {
+        module
+            .transform_expressions(&mut |expression| -> Result<Expression, ()> {
+                Ok(if let Expression::Array(array) = expression {
+                    self.transform_array(array)
+                } else {
+                    expression.clone()
+                })
+            })
+            .unwrap()
+    }
+
+    fn transform_array(&self, array: &Array) -> Expression {
+        let source_information = array.source_information();
+
+        array.elements().iter().fold(
+            Variable::new(
+                &self.array_type_configuration.empty_array_variable_name,
+                source_information.clone(),
+            )
+            .into(),
+            |array, element| {
+                Application::new(
+                    Application::new(
+                        Variable::new(
+                            &self.array_type_configuration.push_function_name,
+                            source_information.clone(),
+                        ),
+                        element.clone(),
+                        source_information.clone(),
+                    ),
+                    array,
+                    source_information.clone(),
+                )
+                .into()
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::super::array_type_configuration::ARRAY_TYPE_CONFIGURATION, *};
+    use crate::{debug::SourceInformation, types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_array() {
+        assert_eq!(
+            ArrayLiteralTransformer::new(ARRAY_TYPE_CONFIGURATION.clone()).transform(
+                &Module::from_definitions(vec![VariableDefinition::new(
+                    "x",
+                    Array::new(
+                        vec![
+                            Number::new(1.0, SourceInformation::dummy()).into(),
+                            Number::new(2.0, SourceInformation::dummy()).into(),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                    types::Unknown::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()])
+            ),
+            Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Application::new(
+                    Application::new(
+                        Variable::new("pushToArray", SourceInformation::dummy()),
+                        Number::new(2.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    Application::new(
+                        Application::new(
+                            Variable::new("pushToArray", SourceInformation::dummy()),
+                            Number::new(1.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        Variable::new("emptyArray", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                types::Unknown::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        );
+    }
+}
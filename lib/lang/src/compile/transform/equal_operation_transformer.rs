@@ -204,7 +204,7 @@ impl EqualOperationTransformer {
                 .into()
             }
             Type::None(_) => Boolean::new(true, source_information).into(),
-            Type::Number(_) => EqualityOperation::with_type(
+            Type::Integer(_) | Type::Number(_) => EqualityOperation::with_type(
                 type_.clone(),
                 EqualityOperator::Equal,
                 lhs.clone(),
@@ -311,7 +311,9 @@ impl EqualOperationTransformer {
                 )
                 .into()
             }
-            Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+            Type::Parameter(_) | Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => {
+                unreachable!()
+            }
         })
     }
 }
@@ -0,0 +1,402 @@
+use super::super::{
+    error::CompileError, map_type_configuration::MapTypeConfiguration,
+    name_generator::NameGenerator, reference_type_resolver::ReferenceTypeResolver,
+    type_comparability_checker::TypeComparabilityChecker,
+};
+use crate::{
+    ast::*,
+    debug::SourceInformation,
+    path::ModulePath,
+    types::{self, Type},
+};
+use std::{collections::HashSet, sync::Arc};
+
+/// HashOperationTransformer transforms hash operations into applications of
+/// hash functions of their argument types. Hash functions of record types are
+/// generated as global functions in each module as they can be recursive.
+pub struct HashOperationTransformer {
+    name_generator: NameGenerator,
+    map_type_configuration: Arc<MapTypeConfiguration>,
+    record_types: Vec<types::Record>,
+    record_names: HashSet<String>,
+}
+
+impl HashOperationTransformer {
+    pub fn new(map_type_configuration: Arc<MapTypeConfiguration>) -> Self {
+        Self {
+            name_generator: NameGenerator::new("hash_operation_argument_"),
+            map_type_configuration,
+            record_types: vec![],
+            record_names: HashSet::new(),
+        }
+    }
+
+    pub fn transform(&mut self, module: &Module) -> Result<Module, CompileError> {
+        let reference_type_resolver = ReferenceTypeResolver::new(module);
+        let type_comparability_checker =
+            TypeComparabilityChecker::new(reference_type_resolver.clone());
+
+        let transformed_module = module.transform_expressions(&mut |expression| -> Result<
+            Expression,
+            CompileError,
+        > {
+            if let Expression::HashOperation(operation) = expression {
+                if !type_comparability_checker.check(operation.type_())? {
+                    return Err(CompileError::MapKeyNotComparable(
+                        operation.source_information().clone(),
+                    ));
+                }
+
+                self.transform_hash_operation(
+                    operation.type_(),
+                    operation.argument(),
+                    module.path(),
+                    &reference_type_resolver,
+                    operation.source_information().clone(),
+                )
+            } else {
+                Ok(expression.clone())
+            }
+        })?;
+
+        let mut hash_function_definitions = vec![];
+
+        while let Some(record_type) = self.record_types.pop() {
+            hash_function_definitions.push(self.create_record_hash_function(
+                &record_type,
+                module.path(),
+                &reference_type_resolver,
+            )?);
+        }
+
+        Ok(Module::new(
+            transformed_module.path().clone(),
+            transformed_module.export().clone(),
+            transformed_module.export_foreign().clone(),
+            transformed_module.imports().to_vec(),
+            transformed_module.import_foreigns().to_vec(),
+            transformed_module.type_definitions().to_vec(),
+            transformed_module
+                .definitions()
+                .iter()
+                .cloned()
+                .chain(hash_function_definitions.into_iter().map(Definition::from))
+                .collect(),
+        ))
+    }
+
+    fn transform_hash_operation(
+        &mut self,
+        type_: &Type,
+        argument: &Expression,
+        module_path: &ModulePath,
+        reference_type_resolver: &ReferenceTypeResolver,
+        source_information: Arc<SourceInformation>,
+    ) -> Result<Expression, CompileError> {
+        Ok(match reference_type_resolver.resolve(type_)? {
+            Type::Boolean(_) => If::new(
+                argument.clone(),
+                Number::new(1.0, source_information.clone()),
+                Number::new(0.0, source_information.clone()),
+                source_information,
+            )
+            .into(),
+            Type::List(list_type) => {
+                let function_name = self.name_generator.generate();
+                let list_name = self.name_generator.generate();
+                let element_name = self.name_generator.generate();
+                let elements_name = self.name_generator.generate();
+
+                let element_hash = self.transform_hash_operation(
+                    list_type.element(),
+                    &Variable::new(&element_name, source_information.clone()).into(),
+                    module_path,
+                    reference_type_resolver,
+                    source_information.clone(),
+                )?;
+
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        &function_name,
+                        vec![list_name.clone()],
+                        ListCase::new(
+                            Variable::new(&list_name, source_information.clone()),
+                            list_type.clone(),
+                            &element_name,
+                            &elements_name,
+                            Number::new(0.0, source_information.clone()),
+                            self.combine_hashes(
+                                element_hash,
+                                self.apply_hash_function(
+                                    &function_name,
+                                    list_type.clone(),
+                                    &Variable::new(&elements_name, source_information.clone())
+                                        .into(),
+                                    source_information.clone(),
+                                ),
+                                source_information.clone(),
+                            ),
+                            source_information.clone(),
+                        ),
+                        types::Function::new(
+                            list_type.clone(),
+                            types::Number::new(source_information.clone()),
+                            source_information.clone(),
+                        ),
+                        source_information.clone(),
+                    )
+                    .into()],
+                    self.apply_hash_function(
+                        &function_name,
+                        list_type,
+                        argument,
+                        source_information.clone(),
+                    ),
+                    source_information,
+                )
+                .into()
+            }
+            Type::Integer(integer_type) => self.apply_hash_function(
+                &self.map_type_configuration.hash_integer_function_name,
+                integer_type,
+                argument,
+                source_information,
+            ),
+            Type::None(_) => Number::new(0.0, source_information).into(),
+            Type::Number(number_type) => self.apply_hash_function(
+                &self.map_type_configuration.hash_number_function_name,
+                number_type,
+                argument,
+                source_information,
+            ),
+            Type::Record(record_type) => {
+                if self.record_names.insert(record_type.name().into()) {
+                    self.record_types.push(record_type.clone());
+                }
+
+                self.apply_hash_function(
+                    &get_record_hash_function_name(module_path, &record_type),
+                    record_type,
+                    argument,
+                    source_information,
+                )
+            }
+            Type::String(string_type) => self.apply_hash_function(
+                &self.map_type_configuration.hash_string_function_name,
+                string_type,
+                argument,
+                source_information,
+            ),
+            Type::Union(union_type) => {
+                let name = self.name_generator.generate();
+
+                Case::with_type(
+                    union_type.clone(),
+                    &name,
+                    argument.clone(),
+                    union_type
+                        .types()
+                        .iter()
+                        .map(|type_| {
+                            Ok(Alternative::new(
+                                type_.clone(),
+                                self.transform_hash_operation(
+                                    type_,
+                                    &Variable::new(&name, source_information.clone()).into(),
+                                    module_path,
+                                    reference_type_resolver,
+                                    source_information.clone(),
+                                )?,
+                            ))
+                        })
+                        .collect::<Result<_, CompileError>>()?,
+                    source_information,
+                )
+                .into()
+            }
+            Type::Any(_)
+            | Type::Function(_)
+            | Type::Parameter(_)
+            | Type::Reference(_)
+            | Type::Unknown(_)
+            | Type::Variable(_) => unreachable!(),
+        })
+    }
+
+    fn create_record_hash_function(
+        &mut self,
+        record_type: &types::Record,
+        module_path: &ModulePath,
+        reference_type_resolver: &ReferenceTypeResolver,
+    ) -> Result<FunctionDefinition, CompileError> {
+        let source_information = record_type.source_information();
+        let number_type = types::Number::new(source_information.clone());
+        let mut expression: Expression = Number::new(0.0, source_information.clone()).into();
+
+        for element in record_type.elements() {
+            let element_hash = self.transform_hash_operation(
+                element.type_(),
+                &RecordElementOperation::new(
+                    record_type.clone(),
+                    element.name(),
+                    Variable::new("record", source_information.clone()),
+                    source_information.clone(),
+                )
+                .into(),
+                module_path,
+                reference_type_resolver,
+                source_information.clone(),
+            )?;
+
+            expression = self.combine_hashes(expression, element_hash, source_information.clone());
+        }
+
+        Ok(FunctionDefinition::new(
+            get_record_hash_function_name(module_path, record_type),
+            vec!["record".into()],
+            expression,
+            types::Function::new(record_type.clone(), number_type, source_information.clone()),
+            source_information.clone(),
+        ))
+    }
+
+    fn combine_hashes(
+        &self,
+        one: Expression,
+        other: Expression,
+        source_information: Arc<SourceInformation>,
+    ) -> Expression {
+        let number_type = types::Number::new(source_information.clone());
+
+        Application::with_type(
+            types::Function::new(
+                number_type.clone(),
+                number_type.clone(),
+                source_information.clone(),
+            ),
+            Application::with_type(
+                types::Function::new(
+                    number_type.clone(),
+                    types::Function::new(
+                        number_type.clone(),
+                        number_type,
+                        source_information.clone(),
+                    ),
+                    source_information.clone(),
+                ),
+                Variable::new(
+                    &self.map_type_configuration.combine_hashes_function_name,
+                    source_information.clone(),
+                ),
+                one,
+                source_information.clone(),
+            ),
+            other,
+            source_information,
+        )
+        .into()
+    }
+
+    fn apply_hash_function(
+        &self,
+        name: &str,
+        type_: impl Into<Type>,
+        argument: &Expression,
+        source_information: Arc<SourceInformation>,
+    ) -> Expression {
+        Application::with_type(
+            types::Function::new(
+                type_,
+                types::Number::new(source_information.clone()),
+                source_information.clone(),
+            ),
+            Variable::new(name, source_information.clone()),
+            argument.clone(),
+            source_information,
+        )
+        .into()
+    }
+}
+
+// Hash functions of the same record types can be generated in different
+// modules.
+fn get_record_hash_function_name(module_path: &ModulePath, record_type: &types::Record) -> String {
+    module_path.fully_qualify_name(&format!("$hash.{}", record_type.name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::super::map_type_configuration::MAP_TYPE_CONFIGURATION, *};
+    use pretty_assertions::assert_eq;
+
+    fn create_module(expression: impl Into<Expression>, type_: impl Into<Type>) -> Module {
+        Module::from_definitions(vec![FunctionDefinition::new(
+            "f",
+            vec!["x".into()],
+            expression,
+            types::Function::new(
+                type_,
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into()])
+    }
+
+    #[test]
+    fn transform_hash_operation_of_number() {
+        let number_type = types::Number::new(SourceInformation::dummy());
+
+        assert_eq!(
+            HashOperationTransformer::new(MAP_TYPE_CONFIGURATION.clone()).transform(
+                &create_module(
+                    HashOperation::with_type(
+                        number_type.clone(),
+                        Variable::new("x", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    number_type.clone(),
+                )
+            ),
+            Ok(create_module(
+                Application::with_type(
+                    types::Function::new(
+                        number_type.clone(),
+                        number_type.clone(),
+                        SourceInformation::dummy(),
+                    ),
+                    Variable::new("hashNumber", SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                number_type,
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_transform_hash_operation_of_function() {
+        let function_type = types::Function::new(
+            types::Number::new(SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            HashOperationTransformer::new(MAP_TYPE_CONFIGURATION.clone()).transform(
+                &create_module(
+                    HashOperation::with_type(
+                        function_type.clone(),
+                        Variable::new("x", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    function_type,
+                )
+            ),
+            Err(CompileError::MapKeyNotComparable(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+}
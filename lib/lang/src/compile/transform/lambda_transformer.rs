@@ -0,0 +1,93 @@
+use super::super::name_generator::NameGenerator;
+use crate::ast::*;
+
+/// LambdaTransformer converts lambda expressions into local function
+/// definitions which capture free variables as closures.
+pub struct LambdaTransformer {
+    name_generator: NameGenerator,
+}
+
+impl LambdaTransformer {
+    pub fn new() -> Self {
+        Self {
+            name_generator: NameGenerator::new("lambda_"),
+        }
+    }
+
+    pub fn transform(&mut self, module: &Module) -> Module {
+        module
+            .transform_expressions(&mut |expression| -> Result<Expression, ()> {
+                Ok(if let Expression::Lambda(lambda) = expression {
+                    let name = self.name_generator.generate();
+
+                    Let::new(
+                        vec![FunctionDefinition::new(
+                            &name,
+                            lambda.arguments().to_vec(),
+                            lambda.body().clone(),
+                            lambda.type_().clone(),
+                            lambda.source_information().clone(),
+                        )
+                        .into()],
+                        Variable::new(&name, lambda.source_information().clone()),
+                        lambda.source_information().clone(),
+                    )
+                    .into()
+                } else {
+                    expression.clone()
+                })
+            })
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::*, types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_lambda() {
+        let type_ = types::Function::new(
+            types::Number::new(SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            LambdaTransformer::new().transform(&Module::from_definitions(vec![
+                VariableDefinition::new(
+                    "f",
+                    Lambda::with_type(
+                        type_.clone(),
+                        vec!["x".into()],
+                        Variable::new("x", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    type_.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ])),
+            Module::from_definitions(vec![VariableDefinition::new(
+                "f",
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "lambda_0",
+                        vec!["x".into()],
+                        Variable::new("x", SourceInformation::dummy()),
+                        type_.clone(),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    Variable::new("lambda_0", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                type_,
+                SourceInformation::dummy(),
+            )
+            .into()])
+        );
+    }
+}
@@ -124,6 +124,7 @@ mod tests {
                 ModuleInterface::new(
                     ModulePath::new(Package::new("m", ""), vec![]),
                     Default::default(),
+                    Default::default(),
                     vec![(
                         "Error".into(),
                         types::Record::new("Error", Default::default(), SourceInformation::dummy())
@@ -132,6 +133,7 @@ mod tests {
                     .into_iter()
                     .collect(),
                     Default::default(),
+                    Default::default(),
                 ),
                 false,
             )],
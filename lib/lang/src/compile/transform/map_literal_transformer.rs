@@ -0,0 +1,138 @@
+use super::super::map_type_configuration::MapTypeConfiguration;
+use crate::{ast::*, debug::SourceInformation};
+use std::sync::Arc;
+
+/// MapLiteralTransformer converts map literals into insertions of their
+/// entries into empty maps. Equality and hash functions of keys are
+/// constructed from their inferred types later.
+pub struct MapLiteralTransformer {
+    map_type_configuration: Arc<MapTypeConfiguration>,
+}
+
+impl MapLiteralTransformer {
+    pub fn new(map_type_configuration: Arc<MapTypeConfiguration>) -> Self {
+        Self {
+            map_type_configuration,
+        }
+    }
+
+    pub fn transform(&self, module: &Module) -> Module {
+        module
+            .transform_expressions(&mut |expression| -> Result<Expression, ()> {
+                Ok(if let Expression::Map(map) = expression {
+                    self.transform_map(map)
+                } else {
+                    expression.clone()
+                })
+            })
+            .unwrap()
+    }
+
+    fn transform_map(&self, map: &Map) -> Expression {
+        let source_information = map.source_information();
+
+        map.entries()
+            .iter()
+            .fold(self.create_empty_map(source_information), |map, entry| {
+                Application::new(
+                    Application::new(
+                        Application::new(
+                            Variable::new(
+                                &self.map_type_configuration.insert_function_name,
+                                source_information.clone(),
+                            ),
+                            entry.key().clone(),
+                            source_information.clone(),
+                        ),
+                        entry.value().clone(),
+                        source_information.clone(),
+                    ),
+                    map,
+                    source_information.clone(),
+                )
+                .into()
+            })
+    }
+
+    fn create_empty_map(&self, source_information: &Arc<SourceInformation>) -> Expression {
+        Application::new(
+            Application::new(
+                Variable::new(
+                    &self.map_type_configuration.empty_map_function_name,
+                    source_information.clone(),
+                ),
+                Lambda::new(
+                    vec!["lhs".into(), "rhs".into()],
+                    EqualityOperation::new(
+                        EqualityOperator::Equal,
+                        Variable::new("lhs", source_information.clone()),
+                        Variable::new("rhs", source_information.clone()),
+                        source_information.clone(),
+                    ),
+                    source_information.clone(),
+                ),
+                source_information.clone(),
+            ),
+            Lambda::new(
+                vec!["key".into()],
+                HashOperation::new(
+                    Variable::new("key", source_information.clone()),
+                    source_information.clone(),
+                ),
+                source_information.clone(),
+            ),
+            source_information.clone(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::super::map_type_configuration::MAP_TYPE_CONFIGURATION, *};
+    use crate::types;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_map() {
+        let empty_map = MapLiteralTransformer::new(MAP_TYPE_CONFIGURATION.clone())
+            .create_empty_map(&SourceInformation::dummy().into());
+
+        assert_eq!(
+            MapLiteralTransformer::new(MAP_TYPE_CONFIGURATION.clone()).transform(
+                &Module::from_definitions(vec![VariableDefinition::new(
+                    "x",
+                    Map::new(
+                        vec![MapEntry::new(
+                            EinString::new("foo", SourceInformation::dummy()),
+                            Number::new(42.0, SourceInformation::dummy()),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                    types::Unknown::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()])
+            ),
+            Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Application::new(
+                    Application::new(
+                        Application::new(
+                            Variable::new("insertToMap", SourceInformation::dummy()),
+                            EinString::new("foo", SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    empty_map,
+                    SourceInformation::dummy(),
+                ),
+                types::Unknown::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        );
+    }
+}
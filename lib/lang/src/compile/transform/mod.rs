@@ -1,16 +1,24 @@
+mod array_literal_transformer;
 mod boolean_operation_transformer;
 mod elementless_record_transformer;
 mod equal_operation_transformer;
 mod function_type_coercion_transformer;
+mod hash_operation_transformer;
+mod lambda_transformer;
 mod let_error_transformer;
 mod list_case_transformer;
 mod list_literal_transformer;
 mod list_type_coercion_transformer;
+mod map_literal_transformer;
 mod not_equal_operation_transformer;
+mod pattern_transformer;
 mod record_element_function_transformer;
 mod record_equal_function_transformer;
 mod record_update_transformer;
+mod string_interpolation_transformer;
 mod type_coercion_transformer;
+mod type_erasure_transformer;
+mod type_instantiation_transformer;
 mod typed_meta_transformer;
 mod utilities;
 
@@ -24,20 +32,28 @@ use super::{
     type_equality_checker::TypeEqualityChecker,
 };
 use crate::ast::*;
+use array_literal_transformer::ArrayLiteralTransformer;
 pub use boolean_operation_transformer::BooleanOperationTransformer;
 use elementless_record_transformer::ElementlessRecordTransformer;
 pub use equal_operation_transformer::EqualOperationTransformer;
 pub use function_type_coercion_transformer::FunctionTypeCoercionTransformer;
+use hash_operation_transformer::HashOperationTransformer;
+use lambda_transformer::LambdaTransformer;
 pub use let_error_transformer::LetErrorTransformer;
 pub use list_case_transformer::ListCaseTransformer;
 pub use list_literal_transformer::ListLiteralTransformer;
 pub use list_type_coercion_transformer::ListTypeCoercionTransformer;
+use map_literal_transformer::MapLiteralTransformer;
 pub use not_equal_operation_transformer::NotEqualOperationTransformer;
+use pattern_transformer::PatternTransformer;
 use record_element_function_transformer::RecordElementFunctionTransformer;
 use record_equal_function_transformer::RecordEqualFunctionTransformer;
 use record_update_transformer::RecordUpdateTransformer;
 use std::sync::Arc;
+use string_interpolation_transformer::StringInterpolationTransformer;
 use type_coercion_transformer::TypeCoercionTransformer;
+use type_erasure_transformer::TypeErasureTransformer;
+use type_instantiation_transformer::TypeInstantiationTransformer;
 use typed_meta_transformer::TypedMetaTransformer;
 
 pub fn transform_before_name_qualification(module: &Module) -> Result<Module, CompileError> {
@@ -52,14 +68,36 @@ pub fn transform_before_name_qualification(module: &Module) -> Result<Module, Co
     Ok(module)
 }
 
-pub fn transform_without_types(module: &Module) -> Result<Module, CompileError> {
-    RecordUpdateTransformer::new().transform(module)
+pub fn transform_without_types(
+    module: &Module,
+    compile_configuration: Arc<CompileConfiguration>,
+) -> Result<Module, CompileError> {
+    let module =
+        ArrayLiteralTransformer::new(compile_configuration.array_type_configuration.clone())
+            .transform(module);
+    let module = MapLiteralTransformer::new(compile_configuration.map_type_configuration.clone())
+        .transform(&module);
+    let module = StringInterpolationTransformer::new(
+        compile_configuration.string_type_configuration.clone(),
+    )
+    .transform(&module);
+    let module = PatternTransformer::new().transform(&module)?;
+    let module =
+        TypeInstantiationTransformer::new(ModuleEnvironmentCreator::new()).transform(&module)?;
+
+    RecordUpdateTransformer::new().transform(&module)
 }
 
 pub fn transform_with_types(
     module: &Module,
     compile_configuration: Arc<CompileConfiguration>,
 ) -> Result<Module, CompileError> {
+    let module =
+        HashOperationTransformer::new(compile_configuration.map_type_configuration.clone())
+            .transform(module)?;
+    let module = LambdaTransformer::new().transform(&module);
+    let module = &TypeErasureTransformer::new().transform(&module)?;
+
     let reference_type_resolver = ReferenceTypeResolver::new(module);
     let type_equality_checker = TypeEqualityChecker::new(reference_type_resolver.clone());
     let type_canonicalizer = TypeCanonicalizer::new(
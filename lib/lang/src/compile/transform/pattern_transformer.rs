@@ -0,0 +1,1011 @@
+use super::super::{
+    error::CompileError, name_generator::NameGenerator,
+    reference_type_resolver::ReferenceTypeResolver, type_canonicalizer::TypeCanonicalizer,
+    type_equality_checker::TypeEqualityChecker,
+};
+use crate::{
+    ast::*,
+    debug::SourceInformation,
+    types::{self, Type},
+};
+use std::sync::Arc;
+
+/// PatternTransformer desugars patterns into type tests, element accesses,
+/// list cases and equality operations.
+pub struct PatternTransformer {
+    name_generator: NameGenerator,
+}
+
+impl PatternTransformer {
+    pub fn new() -> Self {
+        Self {
+            name_generator: NameGenerator::new("pattern_"),
+        }
+    }
+
+    pub fn transform(&mut self, module: &Module) -> Result<Module, CompileError> {
+        let reference_type_resolver = ReferenceTypeResolver::new(module);
+        let type_equality_checker = TypeEqualityChecker::new(reference_type_resolver.clone());
+        let context = Context {
+            type_canonicalizer: TypeCanonicalizer::new(
+                reference_type_resolver.clone(),
+                type_equality_checker.clone(),
+            ),
+            reference_type_resolver,
+            type_equality_checker,
+        };
+
+        module.transform_expressions(&mut |expression| -> Result<Expression, CompileError> {
+            match expression {
+                Expression::Case(case)
+                    if case
+                        .alternatives()
+                        .iter()
+                        .any(|alternative| alternative.pattern().is_some()) =>
+                {
+                    self.transform_case(case, &context)
+                }
+                Expression::LetPattern(let_) => self.transform_let(let_, &context),
+                Expression::ListPatternCase(case) => self.transform_list_case(case, &context),
+                _ => Ok(expression.clone()),
+            }
+        })
+    }
+
+    fn transform_case(&self, case: &Case, context: &Context) -> Result<Expression, CompileError> {
+        let mut alternatives = vec![];
+        let mut pattern_types = vec![];
+
+        for (index, alternative) in case.alternatives().iter().enumerate() {
+            if Self::contains_type(alternative.type_(), &pattern_types, context)? {
+                continue;
+            } else if alternative.pattern().is_none() {
+                alternatives.push(alternative.clone());
+                continue;
+            }
+
+            let type_ = alternative.type_();
+            let mut expression = None;
+
+            // Values not matched by a pattern fall through to the following
+            // alternatives of the same type.
+            for alternative in case.alternatives()[index..].iter().rev() {
+                expression = match alternative.pattern() {
+                    Some(pattern) => {
+                        if context
+                            .type_equality_checker
+                            .equal(alternative.type_(), type_)?
+                        {
+                            Some(self.bind_failure(
+                                expression.as_ref(),
+                                pattern.source_information(),
+                                |failure| {
+                                    self.transform_pattern(
+                                        case.name(),
+                                        pattern,
+                                        Some(type_),
+                                        alternative.expression(),
+                                        failure,
+                                        context,
+                                    )
+                                },
+                            )?)
+                        } else {
+                            expression
+                        }
+                    }
+                    None => {
+                        if Self::covers_type(alternative.type_(), type_, context)? {
+                            Some(alternative.expression().clone())
+                        } else {
+                            expression
+                        }
+                    }
+                };
+            }
+
+            alternatives.push(Alternative::new(type_.clone(), expression.unwrap()));
+            pattern_types.push(type_.clone());
+        }
+
+        Ok(Case::with_type(
+            case.type_().clone(),
+            case.name(),
+            case.argument().clone(),
+            alternatives,
+            case.source_information().clone(),
+        )
+        .into())
+    }
+
+    fn transform_let(
+        &self,
+        let_: &LetPattern,
+        context: &Context,
+    ) -> Result<Expression, CompileError> {
+        let_.definitions().iter().rev().try_fold(
+            let_.expression().clone(),
+            |expression, definition| {
+                let name = self.name_generator.generate();
+
+                Ok(self.bind(
+                    &name,
+                    definition.body().clone(),
+                    self.transform_pattern(
+                        &name,
+                        definition.pattern(),
+                        None,
+                        &expression,
+                        None,
+                        context,
+                    )?,
+                    definition.source_information(),
+                ))
+            },
+        )
+    }
+
+    fn transform_list_case(
+        &self,
+        case: &ListPatternCase,
+        context: &Context,
+    ) -> Result<Expression, CompileError> {
+        let name = self.name_generator.generate();
+        let mut alternatives = vec![];
+
+        for alternative in case.alternatives() {
+            let (pattern, expression) = self.rename_pattern(
+                &alternative.pattern().clone().into(),
+                alternative.expression(),
+            );
+
+            if let Pattern::List(list) = pattern {
+                alternatives.push((list, expression));
+            }
+        }
+
+        Ok(self.bind(
+            &name,
+            case.argument().clone(),
+            self.match_list_alternatives(
+                &name,
+                &alternatives,
+                None,
+                case.source_information(),
+                context,
+            )?,
+            case.source_information(),
+        ))
+    }
+
+    fn transform_pattern(
+        &self,
+        name: &str,
+        pattern: &Pattern,
+        type_: Option<&Type>,
+        expression: &Expression,
+        failure: Option<&Expression>,
+        context: &Context,
+    ) -> Result<Expression, CompileError> {
+        let (pattern, expression) = self.rename_pattern(pattern, expression);
+
+        self.match_pattern(name, &pattern, type_, expression, failure, context)
+    }
+
+    // Variables in patterns are renamed so that they do not shadow variables
+    // referenced in failure expressions.
+    fn rename_pattern(&self, pattern: &Pattern, expression: &Expression) -> (Pattern, Expression) {
+        let mut bindings = vec![];
+        let pattern = self.rename_pattern_variables(pattern, &mut bindings);
+
+        (
+            pattern,
+            bindings
+                .into_iter()
+                .rev()
+                .fold(expression.clone(), |expression, (name, variable)| {
+                    let source_information = variable.source_information().clone();
+
+                    self.bind(&name, variable, expression, &source_information)
+                }),
+        )
+    }
+
+    fn rename_pattern_variables(
+        &self,
+        pattern: &Pattern,
+        bindings: &mut Vec<(String, Variable)>,
+    ) -> Pattern {
+        match pattern {
+            Pattern::List(list) => {
+                let elements = list
+                    .elements()
+                    .iter()
+                    .map(|pattern| self.rename_pattern_variables(pattern, bindings))
+                    .collect();
+
+                ListPattern::new(
+                    elements,
+                    list.rest_name().map(|name| {
+                        let variable = Variable::new(
+                            self.name_generator.generate(),
+                            list.source_information().clone(),
+                        );
+
+                        bindings.push((name.into(), variable.clone()));
+
+                        variable.name().into()
+                    }),
+                    list.source_information().clone(),
+                )
+                .into()
+            }
+            Pattern::Record(record) => RecordPattern::new(
+                record.type_().clone(),
+                record
+                    .elements()
+                    .iter()
+                    .map(|(key, pattern)| {
+                        (
+                            key.clone(),
+                            self.rename_pattern_variables(pattern, bindings),
+                        )
+                    })
+                    .collect(),
+                record.source_information().clone(),
+            )
+            .into(),
+            Pattern::Variable(variable) => {
+                let renamed_variable = Variable::new(
+                    self.name_generator.generate(),
+                    variable.source_information().clone(),
+                );
+
+                bindings.push((variable.name().into(), renamed_variable.clone()));
+
+                renamed_variable.into()
+            }
+            Pattern::Number(_) | Pattern::String(_) => pattern.clone(),
+        }
+    }
+
+    fn match_pattern(
+        &self,
+        name: &str,
+        pattern: &Pattern,
+        type_: Option<&Type>,
+        expression: Expression,
+        failure: Option<&Expression>,
+        context: &Context,
+    ) -> Result<Expression, CompileError> {
+        let source_information = pattern.source_information();
+
+        Ok(match pattern {
+            Pattern::List(list) => self.match_list_alternatives(
+                name,
+                &[(list.clone(), expression)],
+                failure,
+                source_information,
+                context,
+            )?,
+            Pattern::Number(number) => self.match_type(
+                name,
+                type_,
+                &types::Number::new(source_information.clone()).into(),
+                |name| self.match_literal(name, number.clone(), expression, failure),
+                failure,
+                source_information,
+                context,
+            )?,
+            Pattern::Record(record) => self.match_type(
+                name,
+                type_,
+                record.type_(),
+                |name| self.match_record(name, record, expression, failure, context),
+                failure,
+                source_information,
+                context,
+            )?,
+            Pattern::String(string) => self.match_type(
+                name,
+                type_,
+                &types::EinString::new(source_information.clone()).into(),
+                |name| self.match_literal(name, string.clone(), expression, failure),
+                failure,
+                source_information,
+                context,
+            )?,
+            Pattern::Variable(variable) => self.bind(
+                variable.name(),
+                Variable::new(name, source_information.clone()),
+                expression,
+                source_information,
+            ),
+        })
+    }
+
+    // Values of union or Any types are tested against pattern types first.
+    // Values of unknown types are assumed to be of the pattern types.
+    #[allow(clippy::too_many_arguments)]
+    fn match_type(
+        &self,
+        name: &str,
+        type_: Option<&Type>,
+        pattern_type: &Type,
+        match_value: impl FnOnce(&str) -> Result<Expression, CompileError>,
+        failure: Option<&Expression>,
+        source_information: &Arc<SourceInformation>,
+        context: &Context,
+    ) -> Result<Expression, CompileError> {
+        let type_ = match type_ {
+            Some(type_) => context
+                .reference_type_resolver
+                .resolve(&context.type_canonicalizer.canonicalize(type_)?)?,
+            None => return match_value(name),
+        };
+        let other_types = match &type_ {
+            Type::Any(_) => vec![type_.clone()],
+            Type::Union(union) => {
+                let mut types = vec![];
+
+                for type_ in union.types() {
+                    if !context.type_equality_checker.equal(type_, pattern_type)? {
+                        types.push(type_.clone());
+                    }
+                }
+
+                types
+            }
+            _ => return match_value(name),
+        };
+        let case_name = self.name_generator.generate();
+
+        Ok(Case::new(
+            &case_name,
+            Variable::new(name, source_information.clone()),
+            vec![Alternative::new(
+                pattern_type.clone(),
+                match_value(&case_name)?,
+            )]
+            .into_iter()
+            .chain(if other_types.is_empty() {
+                None
+            } else {
+                Some(Alternative::new(
+                    types::Union::new(other_types, source_information.clone()),
+                    Self::get_failure(failure, source_information)?,
+                ))
+            })
+            .collect(),
+            source_information.clone(),
+        )
+        .into())
+    }
+
+    fn match_literal(
+        &self,
+        name: &str,
+        literal: impl Into<Expression>,
+        expression: Expression,
+        failure: Option<&Expression>,
+    ) -> Result<Expression, CompileError> {
+        let literal = literal.into();
+        let source_information = literal.source_information().clone();
+
+        Ok(If::new(
+            EqualityOperation::new(
+                EqualityOperator::Equal,
+                Variable::new(name, source_information.clone()),
+                literal,
+                source_information.clone(),
+            ),
+            expression,
+            Self::get_failure(failure, &source_information)?,
+            source_information,
+        )
+        .into())
+    }
+
+    fn match_record(
+        &self,
+        name: &str,
+        record: &RecordPattern,
+        expression: Expression,
+        failure: Option<&Expression>,
+        context: &Context,
+    ) -> Result<Expression, CompileError> {
+        let record_type = Self::resolve_record_type(record, context)?;
+        let source_information = record.source_information();
+
+        record
+            .elements()
+            .iter()
+            .rev()
+            .try_fold(expression, |expression, (key, pattern)| {
+                let element_type = record_type
+                    .elements()
+                    .iter()
+                    .find(|element| element.name() == key)
+                    .ok_or_else(|| CompileError::RecordElementNotFound {
+                        record_type: record_type.clone(),
+                        name: key.into(),
+                    })?
+                    .type_();
+                let element_name = self.name_generator.generate();
+
+                Ok(self.bind(
+                    &element_name,
+                    RecordElementOperation::new(
+                        record.type_().clone(),
+                        key,
+                        Variable::new(name, source_information.clone()),
+                        source_information.clone(),
+                    ),
+                    self.match_pattern(
+                        &element_name,
+                        pattern,
+                        if Self::is_generic(element_type) {
+                            None
+                        } else {
+                            Some(element_type)
+                        },
+                        expression,
+                        failure,
+                        context,
+                    )?,
+                    source_information,
+                ))
+            })
+    }
+
+    fn match_list_alternatives(
+        &self,
+        name: &str,
+        alternatives: &[(ListPattern, Expression)],
+        failure: Option<&Expression>,
+        source_information: &Arc<SourceInformation>,
+        context: &Context,
+    ) -> Result<Expression, CompileError> {
+        let bind_rest = |list: &ListPattern, expression: &Expression| match list.rest_name() {
+            Some(rest_name) => self.bind(
+                rest_name,
+                Variable::new(name, list.source_information().clone()),
+                expression.clone(),
+                list.source_information(),
+            ),
+            None => expression.clone(),
+        };
+
+        match alternatives.first() {
+            None => return Self::get_failure(failure, source_information),
+            Some((list, expression))
+                if list.elements().is_empty() && list.rest_name().is_some() =>
+            {
+                return Ok(bind_rest(list, expression));
+            }
+            _ => {}
+        }
+
+        let first_name = self.name_generator.generate();
+        let rest_name = self.name_generator.generate();
+        let mut segments = vec![];
+
+        // Consecutive alternatives with irrefutable first elements are matched
+        // together on the rests of lists.
+        for (list, expression) in alternatives {
+            let tail_alternative = match list.elements() {
+                [] if list.rest_name().is_some() => Some((
+                    ListPattern::new(
+                        vec![],
+                        Some(self.name_generator.generate()),
+                        list.source_information().clone(),
+                    ),
+                    bind_rest(list, expression),
+                )),
+                [] => continue,
+                [Pattern::Variable(variable), patterns @ ..] => Some((
+                    ListPattern::new(
+                        patterns.to_vec(),
+                        list.rest_name().map(String::from),
+                        list.source_information().clone(),
+                    ),
+                    self.bind(
+                        variable.name(),
+                        Variable::new(&first_name, variable.source_information().clone()),
+                        expression.clone(),
+                        variable.source_information(),
+                    ),
+                )),
+                _ => None,
+            };
+
+            match (tail_alternative, segments.last_mut()) {
+                (Some(alternative), Some(ListSegment::Irrefutable(alternatives))) => {
+                    alternatives.push(alternative)
+                }
+                (Some(alternative), _) => {
+                    segments.push(ListSegment::Irrefutable(vec![alternative]))
+                }
+                (None, _) => segments.push(ListSegment::Refutable(list, expression)),
+            }
+        }
+
+        let mut non_empty_alternative = failure.cloned();
+
+        for segment in segments.into_iter().rev() {
+            non_empty_alternative = Some(self.bind_failure(
+                non_empty_alternative.as_ref(),
+                source_information,
+                |failure| match segment {
+                    ListSegment::Irrefutable(alternatives) => self.match_list_alternatives(
+                        &rest_name,
+                        &alternatives,
+                        failure,
+                        source_information,
+                        context,
+                    ),
+                    ListSegment::Refutable(list, expression) => self.match_pattern(
+                        &first_name,
+                        &list.elements()[0],
+                        None,
+                        self.match_list_alternatives(
+                            &rest_name,
+                            &[(
+                                ListPattern::new(
+                                    list.elements()[1..].to_vec(),
+                                    list.rest_name().map(String::from),
+                                    list.source_information().clone(),
+                                ),
+                                expression.clone(),
+                            )],
+                            failure,
+                            list.source_information(),
+                            context,
+                        )?,
+                        failure,
+                        context,
+                    ),
+                },
+            )?);
+        }
+
+        Ok(ListCase::new(
+            Variable::new(name, source_information.clone()),
+            types::Unknown::new(source_information.clone()),
+            first_name,
+            rest_name,
+            match alternatives
+                .iter()
+                .find(|(list, _)| list.elements().is_empty())
+            {
+                Some((list, expression)) => bind_rest(list, expression),
+                None => Self::get_failure(failure, source_information)?,
+            },
+            Self::get_failure(non_empty_alternative.as_ref(), source_information)?,
+            source_information.clone(),
+        )
+        .into())
+    }
+
+    fn bind(
+        &self,
+        name: &str,
+        value: impl Into<Expression>,
+        expression: Expression,
+        source_information: &Arc<SourceInformation>,
+    ) -> Expression {
+        Let::new(
+            vec![VariableDefinition::new(
+                name,
+                value,
+                types::Unknown::new(source_information.clone()),
+                source_information.clone(),
+            )
+            .into()],
+            expression,
+            source_information.clone(),
+        )
+        .into()
+    }
+
+    // Failure expressions are bound to local functions once and referenced
+    // by their applications as they can be duplicated in every branch of
+    // patterns otherwise.
+    fn bind_failure(
+        &self,
+        failure: Option<&Expression>,
+        source_information: &Arc<SourceInformation>,
+        transform: impl FnOnce(Option<&Expression>) -> Result<Expression, CompileError>,
+    ) -> Result<Expression, CompileError> {
+        let failure = match failure {
+            Some(failure) if !Self::is_failure_application(failure) => failure,
+            _ => return transform(failure),
+        };
+        let name = self.name_generator.generate();
+
+        Ok(Let::new(
+            vec![FunctionDefinition::new(
+                &name,
+                vec![self.name_generator.generate()],
+                failure.clone(),
+                types::Function::new(
+                    types::None::new(source_information.clone()),
+                    types::Unknown::new(source_information.clone()),
+                    source_information.clone(),
+                ),
+                source_information.clone(),
+            )
+            .into()],
+            transform(Some(
+                &Application::new(
+                    Variable::new(&name, source_information.clone()),
+                    None::new(source_information.clone()),
+                    source_information.clone(),
+                )
+                .into(),
+            ))?,
+            source_information.clone(),
+        )
+        .into())
+    }
+
+    fn is_failure_application(expression: &Expression) -> bool {
+        matches!(
+            expression,
+            Expression::Application(application)
+                if matches!(
+                    (application.function(), application.argument()),
+                    (Expression::Variable(_), Expression::None(_))
+                )
+        )
+    }
+
+    fn get_failure(
+        failure: Option<&Expression>,
+        source_information: &Arc<SourceInformation>,
+    ) -> Result<Expression, CompileError> {
+        failure
+            .cloned()
+            .ok_or_else(|| CompileError::PatternsNotExhaustive(source_information.clone()))
+    }
+
+    // Type arguments of generic record types are left to type inference.
+    fn resolve_record_type(
+        record: &RecordPattern,
+        context: &Context,
+    ) -> Result<types::Record, CompileError> {
+        let type_ = match record.type_() {
+            Type::Reference(reference) if reference.arguments().is_empty() => {
+                match context
+                    .reference_type_resolver
+                    .resolve_type_parameters(reference.name())
+                {
+                    Some(parameters) => types::Reference::with_arguments(
+                        reference.name(),
+                        parameters.to_vec(),
+                        reference.source_information().clone(),
+                    )
+                    .into(),
+                    None => record.type_().clone(),
+                }
+            }
+            _ => record.type_().clone(),
+        };
+
+        context
+            .reference_type_resolver
+            .resolve_to_record(&type_)?
+            .ok_or_else(|| {
+                CompileError::TypesNotMatched(
+                    record.source_information().clone(),
+                    record.type_().source_information().clone(),
+                )
+            })
+    }
+
+    fn contains_type(
+        type_: &Type,
+        types: &[Type],
+        context: &Context,
+    ) -> Result<bool, CompileError> {
+        for other in types {
+            if context.type_equality_checker.equal(type_, other)? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn covers_type(
+        alternative_type: &Type,
+        type_: &Type,
+        context: &Context,
+    ) -> Result<bool, CompileError> {
+        let alternative_type = context
+            .reference_type_resolver
+            .resolve(&context.type_canonicalizer.canonicalize(alternative_type)?)?;
+
+        match &alternative_type {
+            Type::Any(_) => Ok(true),
+            Type::Union(union) => Self::contains_type(
+                type_,
+                &union.types().iter().cloned().collect::<Vec<_>>(),
+                context,
+            ),
+            _ => context
+                .type_equality_checker
+                .equal(&alternative_type, type_),
+        }
+    }
+
+    fn is_generic(type_: &Type) -> bool {
+        let mut generic = false;
+
+        type_
+            .transform_types(&mut |type_| -> Result<_, ()> {
+                generic = generic || matches!(type_, Type::Parameter(_));
+                Ok(type_.clone())
+            })
+            .unwrap();
+
+        generic
+    }
+}
+
+struct Context {
+    reference_type_resolver: Arc<ReferenceTypeResolver>,
+    type_canonicalizer: Arc<TypeCanonicalizer>,
+    type_equality_checker: Arc<TypeEqualityChecker>,
+}
+
+enum ListSegment<'a> {
+    Irrefutable(Vec<(ListPattern, Expression)>),
+    Refutable(&'a ListPattern, &'a Expression),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_let_pattern() {
+        let reference_type = types::Reference::new("Foo", SourceInformation::dummy());
+        let type_definition = TypeDefinition::new(
+            "Foo",
+            types::Record::new(
+                "Foo",
+                vec![types::RecordElement::new(
+                    "foo",
+                    types::Number::new(SourceInformation::dummy()),
+                )],
+                SourceInformation::dummy(),
+            ),
+        );
+        let create_module = |expression: Expression| {
+            Module::from_definitions_and_type_definitions(
+                vec![type_definition.clone()],
+                vec![VariableDefinition::new(
+                    "x",
+                    expression,
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()],
+            )
+        };
+        let create_let = |name: &str, value: Expression, expression: Expression| -> Expression {
+            Let::new(
+                vec![VariableDefinition::new(
+                    name,
+                    value,
+                    types::Unknown::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()],
+                expression,
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+
+        assert_eq!(
+            PatternTransformer::new().transform(&create_module(
+                LetPattern::new(
+                    vec![PatternDefinition::new(
+                        RecordPattern::new(
+                            reference_type.clone(),
+                            vec![(
+                                "foo".into(),
+                                Variable::new("y", SourceInformation::dummy()).into()
+                            )]
+                            .into_iter()
+                            .collect(),
+                            SourceInformation::dummy()
+                        ),
+                        Variable::new("z", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )],
+                    Variable::new("y", SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            )),
+            Ok(create_module(create_let(
+                "pattern_0",
+                Variable::new("z", SourceInformation::dummy()).into(),
+                create_let(
+                    "pattern_2",
+                    RecordElementOperation::new(
+                        reference_type,
+                        "foo",
+                        Variable::new("pattern_0", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    create_let(
+                        "pattern_1",
+                        Variable::new("pattern_2", SourceInformation::dummy()).into(),
+                        create_let(
+                            "y",
+                            Variable::new("pattern_1", SourceInformation::dummy()).into(),
+                            Variable::new("y", SourceInformation::dummy()).into()
+                        )
+                    )
+                )
+            )))
+        );
+    }
+
+    #[test]
+    fn fail_to_transform_non_exhaustive_list_patterns() {
+        assert_eq!(
+            PatternTransformer::new().transform(&Module::from_definitions(vec![
+                VariableDefinition::new(
+                    "x",
+                    ListPatternCase::new(
+                        Variable::new("xs", SourceInformation::dummy()),
+                        vec![ListAlternative::new(
+                            ListPattern::new(
+                                vec![Variable::new("y", SourceInformation::dummy()).into()],
+                                Some("ys".into()),
+                                SourceInformation::dummy()
+                            ),
+                            None::new(SourceInformation::dummy())
+                        )],
+                        SourceInformation::dummy()
+                    ),
+                    types::None::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ])),
+            Err(CompileError::PatternsNotExhaustive(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    fn count_numbers(module: &Module, value: f64) -> usize {
+        let mut count = 0;
+
+        module
+            .transform_expressions(&mut |expression| -> Result<_, ()> {
+                if matches!(expression, Expression::Number(number) if number.value() == value) {
+                    count += 1;
+                }
+
+                Ok(expression.clone())
+            })
+            .unwrap();
+
+        count
+    }
+
+    #[test]
+    fn bind_failures_of_literal_patterns_once() {
+        let module = PatternTransformer::new()
+            .transform(&Module::from_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Case::new(
+                    "y",
+                    Variable::new("x", SourceInformation::dummy()),
+                    (1..=16)
+                        .map(|value| {
+                            Alternative::with_pattern(
+                                types::Number::new(SourceInformation::dummy()),
+                                Number::new(value as f64, SourceInformation::dummy()),
+                                Number::new(value as f64, SourceInformation::dummy()),
+                            )
+                        })
+                        .chain(vec![
+                            Alternative::new(
+                                types::Number::new(SourceInformation::dummy()),
+                                Number::new(42.0, SourceInformation::dummy()),
+                            ),
+                            Alternative::new(
+                                types::None::new(SourceInformation::dummy()),
+                                Number::new(0.0, SourceInformation::dummy()),
+                            ),
+                        ])
+                        .collect(),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::Union::new(
+                        vec![
+                            types::Number::new(SourceInformation::dummy()).into(),
+                            types::None::new(SourceInformation::dummy()).into(),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()]))
+            .unwrap();
+
+        assert_eq!(count_numbers(&module, 42.0), 1);
+    }
+
+    #[test]
+    fn bind_failures_of_list_patterns_once() {
+        let module = PatternTransformer::new()
+            .transform(&Module::from_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec!["xs".into()],
+                ListPatternCase::new(
+                    Variable::new("xs", SourceInformation::dummy()),
+                    (1..=16)
+                        .map(|value| {
+                            ListAlternative::new(
+                                ListPattern::new(
+                                    vec![
+                                        Number::new(value as f64, SourceInformation::dummy())
+                                            .into(),
+                                        Number::new(value as f64, SourceInformation::dummy())
+                                            .into(),
+                                    ],
+                                    None,
+                                    SourceInformation::dummy(),
+                                ),
+                                Number::new(value as f64, SourceInformation::dummy()),
+                            )
+                        })
+                        .chain(vec![
+                            ListAlternative::new(
+                                ListPattern::new(vec![], None, SourceInformation::dummy()),
+                                Number::new(0.0, SourceInformation::dummy()),
+                            ),
+                            ListAlternative::new(
+                                ListPattern::new(
+                                    vec![],
+                                    Some("ys".into()),
+                                    SourceInformation::dummy(),
+                                ),
+                                Number::new(42.0, SourceInformation::dummy()),
+                            ),
+                        ])
+                        .collect(),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::List::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()]))
+            .unwrap();
+
+        assert_eq!(count_numbers(&module, 42.0), 1);
+    }
+}
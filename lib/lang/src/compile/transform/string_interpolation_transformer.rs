@@ -0,0 +1,121 @@
+use super::super::string_type_configuration::StringTypeConfiguration;
+use crate::ast::*;
+use std::sync::Arc;
+
+/// StringInterpolationTransformer converts string interpolations into
+/// concatenations of their parts. Interpolated expressions are converted into
+/// strings explicitly and their types are checked on type inference later.
+pub struct StringInterpolationTransformer {
+    string_type_configuration: Arc<StringTypeConfiguration>,
+}
+
+impl StringInterpolationTransformer {
+    pub fn new(string_type_configuration: Arc<StringTypeConfiguration>) -> Self {
+        Self {
+            string_type_configuration,
+        }
+    }
+
+    pub fn transform(&self, module: &Module) -> Module {
+        module
+            .transform_expressions(&mut |expression| -> Result<Expression, ()> {
+                Ok(
+                    if let Expression::StringInterpolation(interpolation) = expression {
+                        self.transform_interpolation(interpolation)
+                    } else {
+                        expression.clone()
+                    },
+                )
+            })
+            .unwrap()
+    }
+
+    fn transform_interpolation(&self, interpolation: &StringInterpolation) -> Expression {
+        let source_information = interpolation.source_information();
+        let mut parts = interpolation.parts().iter().map(|part| match part {
+            StringInterpolationPart::Expression(expression) => Application::new(
+                Variable::new(
+                    &self
+                        .string_type_configuration
+                        .convert_to_string_function_name,
+                    expression.source_information().clone(),
+                ),
+                expression.clone(),
+                expression.source_information().clone(),
+            )
+            .into(),
+            StringInterpolationPart::String(string) => {
+                EinString::new(string, source_information.clone()).into()
+            }
+        });
+
+        let first = parts
+            .next()
+            .unwrap_or_else(|| EinString::new("", source_information.clone()).into());
+
+        parts.fold(first, |string, part| {
+            Application::new(
+                Application::new(
+                    Variable::new(
+                        &self.string_type_configuration.concatenate_function_name,
+                        source_information.clone(),
+                    ),
+                    string,
+                    source_information.clone(),
+                ),
+                part,
+                source_information.clone(),
+            )
+            .into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::super::string_type_configuration::STRING_TYPE_CONFIGURATION, *};
+    use crate::{debug::SourceInformation, types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_string_interpolation() {
+        assert_eq!(
+            StringInterpolationTransformer::new(STRING_TYPE_CONFIGURATION.clone()).transform(
+                &Module::from_definitions(vec![VariableDefinition::new(
+                    "x",
+                    StringInterpolation::new(
+                        vec![
+                            StringInterpolationPart::String("foo".into()),
+                            StringInterpolationPart::Expression(
+                                Variable::new("y", SourceInformation::dummy()).into()
+                            ),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                    types::EinString::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()])
+            ),
+            Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Application::new(
+                    Application::new(
+                        Variable::new("concatenateStrings", SourceInformation::dummy()),
+                        EinString::new("foo", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    Application::new(
+                        Variable::new("convertToString", SourceInformation::dummy()),
+                        Variable::new("y", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                types::EinString::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        );
+    }
+}
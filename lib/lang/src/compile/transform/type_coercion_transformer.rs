@@ -379,13 +379,22 @@ impl TypedTransformer for TypeCoercionTransformer {
                 .into()
             }
             Expression::Boolean(_)
+            | Expression::Integer(_)
             | Expression::Let(_)
             | Expression::None(_)
             | Expression::Number(_)
             | Expression::RecordElementOperation(_)
             | Expression::String(_)
+            | Expression::TypeCoercion(_)
             | Expression::Variable(_) => expression.clone(),
-            Expression::RecordUpdate(_) | Expression::TypeCoercion(_) => unreachable!(),
+            Expression::Array(_)
+            | Expression::HashOperation(_)
+            | Expression::Lambda(_)
+            | Expression::LetPattern(_)
+            | Expression::ListPatternCase(_)
+            | Expression::Map(_)
+            | Expression::RecordUpdate(_)
+            | Expression::StringInterpolation(_) => unreachable!(),
         })
     }
 }
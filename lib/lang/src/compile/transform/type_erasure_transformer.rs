@@ -0,0 +1,881 @@
+use super::super::{
+    error::CompileError, name_generator::NameGenerator,
+    reference_type_resolver::ReferenceTypeResolver, type_equality_checker::TypeEqualityChecker,
+    type_eraser::TypeEraser,
+};
+use crate::{
+    ast::*,
+    debug::SourceInformation,
+    types::{self, Type},
+};
+use std::{collections::HashMap, sync::Arc};
+
+// Type arguments are keyed by names of type parameters.
+type TypeArguments = HashMap<String, Type>;
+
+/// TypeErasureTransformer erases type parameters from typed modules.
+///
+/// Values of type parameters are represented as Any values. So values are
+/// coerced from and to their instance types wherever generic variables and
+/// records are used.
+pub struct TypeErasureTransformer {
+    function_name_generator: NameGenerator,
+    argument_name_generator: NameGenerator,
+}
+
+impl TypeErasureTransformer {
+    pub fn new() -> Self {
+        Self {
+            function_name_generator: NameGenerator::new("$te_func_"),
+            argument_name_generator: NameGenerator::new("$te_arg_"),
+        }
+    }
+
+    pub fn transform(&self, module: &Module) -> Result<Module, CompileError> {
+        let reference_type_resolver = ReferenceTypeResolver::new(module);
+        let context = Context {
+            type_equality_checker: TypeEqualityChecker::new(reference_type_resolver.clone()),
+            reference_type_resolver,
+            type_eraser: TypeEraser::new(module),
+        };
+
+        context
+            .type_eraser
+            .erase_module(&module.transform_expressions(
+                &mut |expression| -> Result<_, CompileError> {
+                    Ok(match expression {
+                        // Type coercions before type erasure are instantiations of generic variables.
+                        Expression::TypeCoercion(coercion) => {
+                            let mut parameters = HashMap::new();
+
+                            context.match_type(
+                                coercion.from(),
+                                coercion.to(),
+                                &mut parameters,
+                                false,
+                            )?;
+                            context.match_type(
+                                coercion.from(),
+                                coercion.to(),
+                                &mut parameters,
+                                true,
+                            )?;
+
+                            self.coerce(
+                                &context,
+                                coercion.argument().clone(),
+                                coercion.from(),
+                                &parameters,
+                                Direction::FromParameters,
+                                coercion.source_information(),
+                            )?
+                        }
+                        Expression::RecordConstruction(construction) => {
+                            match context.resolve_generic_record(construction.type_())? {
+                                Some((record_type, parameters)) => RecordConstruction::new(
+                                    construction.type_().clone(),
+                                    construction
+                                        .elements()
+                                        .iter()
+                                        .map(|(name, expression)| {
+                                            Ok((
+                                                name.clone(),
+                                                self.coerce(
+                                                    &context,
+                                                    expression.clone(),
+                                                    Self::get_record_element(&record_type, name),
+                                                    &parameters,
+                                                    Direction::ToParameters,
+                                                    construction.source_information(),
+                                                )?,
+                                            ))
+                                        })
+                                        .collect::<Result<_, CompileError>>()?,
+                                    construction.source_information().clone(),
+                                )
+                                .into(),
+                                None => expression.clone(),
+                            }
+                        }
+                        Expression::RecordElementOperation(operation) => {
+                            match context.resolve_generic_record(operation.type_())? {
+                                Some((record_type, parameters)) => self.coerce(
+                                    &context,
+                                    expression.clone(),
+                                    Self::get_record_element(
+                                        &record_type,
+                                        operation.element_name(),
+                                    ),
+                                    &parameters,
+                                    Direction::FromParameters,
+                                    operation.source_information(),
+                                )?,
+                                None => expression.clone(),
+                            }
+                        }
+                        _ => expression.clone(),
+                    })
+                },
+            )?)
+    }
+
+    // Given expressions are of types with type parameters in the forward
+    // direction and of their instance types in the backward one.
+    fn coerce(
+        &self,
+        context: &Context,
+        expression: Expression,
+        type_: &Type,
+        parameters: &TypeArguments,
+        direction: Direction,
+        source_information: &Arc<SourceInformation>,
+    ) -> Result<Expression, CompileError> {
+        let instance_type = context.instantiate(type_, parameters);
+        let (from_type, to_type) = match direction {
+            Direction::FromParameters => (
+                context.type_eraser.erase(type_)?,
+                context.type_eraser.erase(&instance_type)?,
+            ),
+            Direction::ToParameters => (
+                context.type_eraser.erase(&instance_type)?,
+                context.type_eraser.erase(type_)?,
+            ),
+        };
+
+        if context.type_eraser.equal(&from_type, &to_type)? {
+            return Ok(expression);
+        }
+
+        Ok(match type_ {
+            Type::Parameter(_) => match direction {
+                Direction::FromParameters => {
+                    let name = self.argument_name_generator.generate();
+
+                    Case::with_type(
+                        from_type,
+                        &name,
+                        expression,
+                        vec![Alternative::new(
+                            to_type,
+                            Variable::new(&name, source_information.clone()),
+                        )],
+                        source_information.clone(),
+                    )
+                    .into()
+                }
+                Direction::ToParameters => {
+                    TypeCoercion::new(expression, from_type, to_type, source_information.clone())
+                        .into()
+                }
+            },
+            Type::Function(function_type) => {
+                let from_function_name = self.function_name_generator.generate();
+                let to_function_name = self.function_name_generator.generate();
+                let argument_name = self.argument_name_generator.generate();
+
+                Let::new(
+                    vec![
+                        VariableDefinition::new(
+                            &from_function_name,
+                            expression,
+                            from_type.clone(),
+                            source_information.clone(),
+                        )
+                        .into(),
+                        FunctionDefinition::new(
+                            &to_function_name,
+                            vec![argument_name.clone()],
+                            self.coerce(
+                                context,
+                                Application::with_type(
+                                    from_type,
+                                    Variable::new(&from_function_name, source_information.clone()),
+                                    self.coerce(
+                                        context,
+                                        Variable::new(argument_name, source_information.clone())
+                                            .into(),
+                                        function_type.argument(),
+                                        parameters,
+                                        direction.reverse(),
+                                        source_information,
+                                    )?,
+                                    source_information.clone(),
+                                )
+                                .into(),
+                                function_type.result(),
+                                parameters,
+                                direction,
+                                source_information,
+                            )?,
+                            to_type,
+                            source_information.clone(),
+                        )
+                        .into(),
+                    ],
+                    Variable::new(to_function_name, source_information.clone()),
+                    source_information.clone(),
+                )
+                .into()
+            }
+            Type::List(list_type) => {
+                let function_name = self.function_name_generator.generate();
+                let function_type = types::Function::new(
+                    from_type.clone(),
+                    to_type.clone(),
+                    source_information.clone(),
+                );
+                let list_name = self.argument_name_generator.generate();
+                let first_name = self.argument_name_generator.generate();
+                let rest_name = self.argument_name_generator.generate();
+
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        &function_name,
+                        vec![list_name.clone()],
+                        ListCase::new(
+                            Variable::new(list_name, source_information.clone()),
+                            from_type,
+                            first_name.clone(),
+                            rest_name.clone(),
+                            List::with_type(to_type.clone(), vec![], source_information.clone()),
+                            List::with_type(
+                                to_type,
+                                vec![
+                                    ListElement::Single(
+                                        self.coerce(
+                                            context,
+                                            Variable::new(first_name, source_information.clone())
+                                                .into(),
+                                            list_type.element(),
+                                            parameters,
+                                            direction,
+                                            source_information,
+                                        )?,
+                                    ),
+                                    ListElement::Multiple(
+                                        Application::with_type(
+                                            function_type.clone(),
+                                            Variable::new(
+                                                &function_name,
+                                                source_information.clone(),
+                                            ),
+                                            Variable::new(rest_name, source_information.clone()),
+                                            source_information.clone(),
+                                        )
+                                        .into(),
+                                    ),
+                                ],
+                                source_information.clone(),
+                            ),
+                            source_information.clone(),
+                        ),
+                        function_type.clone(),
+                        source_information.clone(),
+                    )
+                    .into()],
+                    Application::with_type(
+                        function_type,
+                        Variable::new(function_name, source_information.clone()),
+                        expression,
+                        source_information.clone(),
+                    ),
+                    source_information.clone(),
+                )
+                .into()
+            }
+            Type::Union(union_type) => {
+                let name = self.argument_name_generator.generate();
+                let mut alternatives = vec![];
+                let mut any_alternatives = vec![];
+
+                for member_type in union_type.types() {
+                    let (member_from_type, member_to_type) = match direction {
+                        Direction::FromParameters => (
+                            context.type_eraser.erase(member_type)?,
+                            context
+                                .type_eraser
+                                .erase(&context.instantiate(member_type, parameters))?,
+                        ),
+                        Direction::ToParameters => (
+                            context
+                                .type_eraser
+                                .erase(&context.instantiate(member_type, parameters))?,
+                            context.type_eraser.erase(member_type)?,
+                        ),
+                    };
+
+                    let mut alternative_exists = false;
+
+                    for alternative in alternatives.iter().chain(&any_alternatives) {
+                        let alternative: &Alternative = alternative;
+
+                        alternative_exists = alternative_exists
+                            || context
+                                .type_eraser
+                                .equal(alternative.type_(), &member_from_type)?;
+                    }
+
+                    if alternative_exists {
+                        continue;
+                    }
+
+                    let expression = self.coerce(
+                        context,
+                        Variable::new(&name, source_information.clone()).into(),
+                        member_type,
+                        parameters,
+                        direction,
+                        source_information,
+                    )?;
+                    let alternative = Alternative::new(
+                        member_from_type.clone(),
+                        if context.type_eraser.equal(&member_to_type, &to_type)? {
+                            expression
+                        } else {
+                            TypeCoercion::new(
+                                expression,
+                                member_to_type,
+                                to_type.clone(),
+                                source_information.clone(),
+                            )
+                            .into()
+                        },
+                    );
+
+                    // Alternatives of Any types match with any values.
+                    if matches!(member_from_type, Type::Any(_)) {
+                        any_alternatives.push(alternative);
+                    } else {
+                        alternatives.push(alternative);
+                    }
+                }
+
+                Case::with_type(
+                    from_type,
+                    name,
+                    expression,
+                    alternatives.into_iter().chain(any_alternatives).collect(),
+                    source_information.clone(),
+                )
+                .into()
+            }
+            Type::Any(_)
+            | Type::Boolean(_)
+            | Type::Integer(_)
+            | Type::None(_)
+            | Type::Number(_)
+            | Type::Record(_)
+            | Type::Reference(_)
+            | Type::String(_) => expression,
+            Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+        })
+    }
+
+    fn get_record_element<'a>(record_type: &'a types::Record, name: &str) -> &'a Type {
+        record_type
+            .elements()
+            .iter()
+            .find(|element| element.name() == name)
+            .unwrap()
+            .type_()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    FromParameters,
+    ToParameters,
+}
+
+impl Direction {
+    fn reverse(self) -> Self {
+        match self {
+            Self::FromParameters => Self::ToParameters,
+            Self::ToParameters => Self::FromParameters,
+        }
+    }
+}
+
+struct Context {
+    reference_type_resolver: Arc<ReferenceTypeResolver>,
+    type_equality_checker: Arc<TypeEqualityChecker>,
+    type_eraser: Arc<TypeEraser>,
+}
+
+impl Context {
+    // Generic record types are returned with their type parameters and the
+    // type arguments of given types.
+    fn resolve_generic_record(
+        &self,
+        type_: &Type,
+    ) -> Result<Option<(types::Record, TypeArguments)>, CompileError> {
+        let record_type = match self.reference_type_resolver.resolve_to_record(type_)? {
+            Some(record_type) if !record_type.arguments().is_empty() => record_type,
+            _ => return Ok(None),
+        };
+        let parameters = self
+            .reference_type_resolver
+            .resolve_type_parameters(record_type.name())
+            .unwrap()
+            .to_vec();
+
+        Ok(Some((
+            self.reference_type_resolver
+                .resolve_to_record(
+                    &types::Reference::with_arguments(
+                        record_type.name(),
+                        parameters.clone(),
+                        record_type.source_information().clone(),
+                    )
+                    .into(),
+                )?
+                .unwrap(),
+            parameters
+                .iter()
+                .zip(record_type.arguments())
+                .filter_map(|(parameter, argument)| match parameter {
+                    Type::Parameter(parameter) => Some((parameter.name().into(), argument.clone())),
+                    _ => None,
+                })
+                .collect(),
+        )))
+    }
+
+    // Type parameters are matched with their instance types. Members of union
+    // types are matched after the other types as their structures might have
+    // been changed by canonicalization.
+    fn match_type(
+        &self,
+        type_: &Type,
+        instance_type: &Type,
+        parameters: &mut TypeArguments,
+        union: bool,
+    ) -> Result<(), CompileError> {
+        match type_ {
+            Type::Parameter(parameter) => {
+                if !parameters.contains_key(parameter.name()) {
+                    parameters.insert(parameter.name().into(), instance_type.clone());
+                }
+            }
+            Type::Function(function_type) => {
+                if let Some(instance_type) = self
+                    .reference_type_resolver
+                    .resolve_to_function(instance_type)?
+                {
+                    self.match_type(
+                        function_type.argument(),
+                        instance_type.argument(),
+                        parameters,
+                        union,
+                    )?;
+                    self.match_type(
+                        function_type.result(),
+                        instance_type.result(),
+                        parameters,
+                        union,
+                    )?;
+                }
+            }
+            Type::List(list_type) => {
+                if let Some(instance_type) = self
+                    .reference_type_resolver
+                    .resolve_to_list(instance_type)?
+                {
+                    self.match_type(
+                        list_type.element(),
+                        instance_type.element(),
+                        parameters,
+                        union,
+                    )?;
+                }
+            }
+            Type::Record(_) | Type::Reference(_) => {
+                if let (Some(record_type), Some(instance_type)) = (
+                    self.reference_type_resolver.resolve_to_record(type_)?,
+                    self.reference_type_resolver
+                        .resolve_to_record(instance_type)?,
+                ) {
+                    if record_type.name() == instance_type.name() {
+                        for (type_, instance_type) in record_type
+                            .arguments()
+                            .iter()
+                            .zip(instance_type.arguments())
+                        {
+                            self.match_type(type_, instance_type, parameters, union)?;
+                        }
+                    }
+                }
+            }
+            Type::Union(union_type) if union => {
+                let instance_types = match self.reference_type_resolver.resolve(instance_type)? {
+                    Type::Union(union_type) => union_type.types().iter().cloned().collect(),
+                    instance_type => vec![instance_type],
+                };
+                let mut rest_types = instance_types.clone();
+
+                for type_ in union_type.types() {
+                    if matches!(type_, Type::Parameter(_)) {
+                        continue;
+                    }
+
+                    for instance_type in &instance_types {
+                        if self.match_shape(type_, instance_type)? {
+                            self.match_type(type_, instance_type, parameters, false)?;
+                            self.match_type(type_, instance_type, parameters, true)?;
+
+                            rest_types.retain(|type_| type_ != instance_type);
+
+                            break;
+                        }
+                    }
+                }
+
+                for type_ in union_type.types() {
+                    if matches!(type_, Type::Parameter(_)) {
+                        self.match_type(
+                            type_,
+                            &match rest_types.as_slice() {
+                                [] => instance_type.clone(),
+                                [type_] => type_.clone(),
+                                _ => types::Union::new(
+                                    rest_types.clone(),
+                                    instance_type.source_information().clone(),
+                                )
+                                .into(),
+                            },
+                            parameters,
+                            false,
+                        )?;
+                    }
+                }
+            }
+            Type::Any(_)
+            | Type::Boolean(_)
+            | Type::Integer(_)
+            | Type::None(_)
+            | Type::Number(_)
+            | Type::String(_)
+            | Type::Union(_) => {}
+            Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    fn match_shape(&self, type_: &Type, instance_type: &Type) -> Result<bool, CompileError> {
+        Ok(
+            match (
+                self.reference_type_resolver.resolve(type_)?,
+                self.reference_type_resolver.resolve(instance_type)?,
+            ) {
+                (Type::Function(_), Type::Function(_)) | (Type::List(_), Type::List(_)) => true,
+                (Type::Record(one), Type::Record(other)) => one.name() == other.name(),
+                (type_, instance_type) => {
+                    self.type_equality_checker.equal(&type_, &instance_type)?
+                }
+            },
+        )
+    }
+
+    fn instantiate(&self, type_: &Type, parameters: &TypeArguments) -> Type {
+        type_
+            .transform_types(&mut |type_| -> Result<_, ()> {
+                Ok(match type_ {
+                    Type::Parameter(parameter) => parameters
+                        .get(parameter.name())
+                        .cloned()
+                        .unwrap_or_else(|| {
+                            types::Any::new(parameter.source_information().clone()).into()
+                        }),
+                    _ => type_.clone(),
+                })
+            })
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn create_generic_record_type_definition() -> TypeDefinition {
+        TypeDefinition::new(
+            "Box",
+            types::Record::with_arguments(
+                "Box",
+                vec![types::Parameter::new("a", SourceInformation::dummy()).into()],
+                vec![types::RecordElement::new(
+                    "value",
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                )],
+                SourceInformation::dummy(),
+            ),
+        )
+    }
+
+    fn create_erased_record_type_definition() -> TypeDefinition {
+        TypeDefinition::new(
+            "Box",
+            types::Record::new(
+                "Box",
+                vec![types::RecordElement::new(
+                    "value",
+                    types::Any::new(SourceInformation::dummy()),
+                )],
+                SourceInformation::dummy(),
+            ),
+        )
+    }
+
+    #[test]
+    fn transform_non_generic_module() {
+        let module = Module::from_definitions(vec![VariableDefinition::new(
+            "x",
+            Number::new(42.0, SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(TypeErasureTransformer::new().transform(&module), Ok(module));
+    }
+
+    #[test]
+    fn erase_type_parameters_of_definitions() {
+        assert_eq!(
+            TypeErasureTransformer::new().transform(&Module::from_definitions(vec![
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ])),
+            Ok(Module::from_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    types::Any::new(SourceInformation::dummy()),
+                    types::Any::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()]))
+        );
+    }
+
+    #[test]
+    fn transform_instantiation_of_generic_function() {
+        let any_function_type = types::Function::new(
+            types::Any::new(SourceInformation::dummy()),
+            types::Any::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+        let number_function_type = types::Function::new(
+            types::Number::new(SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            TypeErasureTransformer::new().transform(&Module::from_definitions(vec![
+                VariableDefinition::new(
+                    "g",
+                    TypeCoercion::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        types::Function::new(
+                            types::Parameter::new("a", SourceInformation::dummy()),
+                            types::Parameter::new("a", SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        number_function_type.clone(),
+                        SourceInformation::dummy(),
+                    ),
+                    number_function_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ])),
+            Ok(Module::from_definitions(vec![VariableDefinition::new(
+                "g",
+                Let::new(
+                    vec![
+                        VariableDefinition::new(
+                            "$te_func_0",
+                            Variable::new("f", SourceInformation::dummy()),
+                            any_function_type.clone(),
+                            SourceInformation::dummy(),
+                        )
+                        .into(),
+                        FunctionDefinition::new(
+                            "$te_func_1",
+                            vec!["$te_arg_0".into()],
+                            Case::with_type(
+                                types::Any::new(SourceInformation::dummy()),
+                                "$te_arg_1",
+                                Application::with_type(
+                                    any_function_type,
+                                    Variable::new("$te_func_0", SourceInformation::dummy()),
+                                    TypeCoercion::new(
+                                        Variable::new("$te_arg_0", SourceInformation::dummy()),
+                                        types::Number::new(SourceInformation::dummy()),
+                                        types::Any::new(SourceInformation::dummy()),
+                                        SourceInformation::dummy(),
+                                    ),
+                                    SourceInformation::dummy(),
+                                ),
+                                vec![Alternative::new(
+                                    types::Number::new(SourceInformation::dummy()),
+                                    Variable::new("$te_arg_1", SourceInformation::dummy()),
+                                )],
+                                SourceInformation::dummy(),
+                            ),
+                            number_function_type.clone(),
+                            SourceInformation::dummy(),
+                        )
+                        .into(),
+                    ],
+                    Variable::new("$te_func_1", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                number_function_type,
+                SourceInformation::dummy(),
+            )
+            .into()]))
+        );
+    }
+
+    #[test]
+    fn transform_generic_record_construction() {
+        assert_eq!(
+            TypeErasureTransformer::new().transform(
+                &Module::from_definitions_and_type_definitions(
+                    vec![create_generic_record_type_definition()],
+                    vec![VariableDefinition::new(
+                        "x",
+                        RecordConstruction::new(
+                            types::Reference::with_arguments(
+                                "Box",
+                                vec![types::Number::new(SourceInformation::dummy()).into()],
+                                SourceInformation::dummy(),
+                            ),
+                            vec![(
+                                "value".into(),
+                                Number::new(42.0, SourceInformation::dummy()).into(),
+                            )]
+                            .into_iter()
+                            .collect(),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Reference::with_arguments(
+                            "Box",
+                            vec![types::Number::new(SourceInformation::dummy()).into()],
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()]
+                )
+            ),
+            Ok(Module::from_definitions_and_type_definitions(
+                vec![create_erased_record_type_definition()],
+                vec![VariableDefinition::new(
+                    "x",
+                    RecordConstruction::new(
+                        types::Reference::new("Box", SourceInformation::dummy()),
+                        vec![(
+                            "value".into(),
+                            TypeCoercion::new(
+                                Number::new(42.0, SourceInformation::dummy()),
+                                types::Number::new(SourceInformation::dummy()),
+                                types::Any::new(SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            )
+                            .into(),
+                        )]
+                        .into_iter()
+                        .collect(),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Reference::new("Box", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()]
+            ))
+        );
+    }
+
+    #[test]
+    fn transform_generic_record_element_operation() {
+        assert_eq!(
+            TypeErasureTransformer::new().transform(
+                &Module::from_definitions_and_type_definitions(
+                    vec![create_generic_record_type_definition()],
+                    vec![FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        RecordElementOperation::new(
+                            types::Reference::with_arguments(
+                                "Box",
+                                vec![types::Number::new(SourceInformation::dummy()).into()],
+                                SourceInformation::dummy(),
+                            ),
+                            "value",
+                            Variable::new("x", SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Function::new(
+                            types::Reference::with_arguments(
+                                "Box",
+                                vec![types::Number::new(SourceInformation::dummy()).into()],
+                                SourceInformation::dummy(),
+                            ),
+                            types::Number::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()]
+                )
+            ),
+            Ok(Module::from_definitions_and_type_definitions(
+                vec![create_erased_record_type_definition()],
+                vec![FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Case::with_type(
+                        types::Any::new(SourceInformation::dummy()),
+                        "$te_arg_0",
+                        RecordElementOperation::new(
+                            types::Reference::new("Box", SourceInformation::dummy()),
+                            "value",
+                            Variable::new("x", SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        vec![Alternative::new(
+                            types::Number::new(SourceInformation::dummy()),
+                            Variable::new("$te_arg_0", SourceInformation::dummy()),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                    types::Function::new(
+                        types::Reference::new("Box", SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into()]
+            ))
+        );
+    }
+}
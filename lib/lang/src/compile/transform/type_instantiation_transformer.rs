@@ -0,0 +1,164 @@
+use super::super::{
+    error::CompileError, module_environment_creator::ModuleEnvironmentCreator,
+    reference_type_resolver::ReferenceTypeResolver,
+};
+use crate::{
+    ast::*,
+    types::{self, Type},
+};
+use std::sync::Arc;
+
+/// TypeInstantiationTransformer marks instances of generic variables and
+/// record types so that their type arguments are inferred.
+pub struct TypeInstantiationTransformer {
+    module_environment_creator: Arc<ModuleEnvironmentCreator>,
+}
+
+impl TypeInstantiationTransformer {
+    pub fn new(module_environment_creator: Arc<ModuleEnvironmentCreator>) -> Self {
+        Self {
+            module_environment_creator,
+        }
+    }
+
+    pub fn transform(&self, module: &Module) -> Result<Module, CompileError> {
+        let reference_type_resolver = ReferenceTypeResolver::new(module);
+        let variables = self.module_environment_creator.create(module);
+
+        module.transform_expressions(&mut |expression| -> Result<_, CompileError> {
+            Ok(match expression {
+                Expression::RecordConstruction(construction) => RecordConstruction::new(
+                    self.instantiate_record_type(construction.type_(), &reference_type_resolver),
+                    construction.elements().clone(),
+                    construction.source_information().clone(),
+                )
+                .into(),
+                Expression::RecordElementOperation(operation) => RecordElementOperation::new(
+                    self.instantiate_record_type(operation.type_(), &reference_type_resolver),
+                    operation.element_name(),
+                    operation.argument().clone(),
+                    operation.source_information().clone(),
+                )
+                .into(),
+                Expression::RecordUpdate(record_update) => RecordUpdate::new(
+                    self.instantiate_record_type(record_update.type_(), &reference_type_resolver),
+                    record_update.argument().clone(),
+                    record_update.elements().clone(),
+                    record_update.source_information().clone(),
+                )
+                .into(),
+                Expression::Variable(variable) => match variables.get(variable.name()) {
+                    Some(type_) if Self::is_generic(type_) => TypeCoercion::new(
+                        variable.clone(),
+                        type_.clone(),
+                        types::Unknown::new(variable.source_information().clone()),
+                        variable.source_information().clone(),
+                    )
+                    .into(),
+                    _ => expression.clone(),
+                },
+                _ => expression.clone(),
+            })
+        })
+    }
+
+    // Type arguments omitted in expressions are inferred.
+    fn instantiate_record_type(
+        &self,
+        type_: &Type,
+        reference_type_resolver: &ReferenceTypeResolver,
+    ) -> Type {
+        match type_ {
+            Type::Reference(reference) if reference.arguments().is_empty() => {
+                match reference_type_resolver.resolve_type_parameters(reference.name()) {
+                    Some(parameters) if !parameters.is_empty() => types::Reference::with_arguments(
+                        reference.name(),
+                        parameters
+                            .iter()
+                            .map(|_| {
+                                types::Unknown::new(reference.source_information().clone()).into()
+                            })
+                            .collect(),
+                        reference.source_information().clone(),
+                    )
+                    .into(),
+                    _ => type_.clone(),
+                }
+            }
+            _ => type_.clone(),
+        }
+    }
+
+    fn is_generic(type_: &Type) -> bool {
+        let mut generic = false;
+
+        type_
+            .transform_types(&mut |type_| -> Result<_, ()> {
+                generic = generic || matches!(type_, Type::Parameter(_));
+                Ok(type_.clone())
+            })
+            .unwrap();
+
+        generic
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debug::SourceInformation;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_generic_variable() {
+        let type_ = types::Function::new(
+            types::Parameter::new("a", SourceInformation::dummy()),
+            types::Parameter::new("a", SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            TypeInstantiationTransformer::new(ModuleEnvironmentCreator::new()).transform(
+                &Module::from_definitions(vec![
+                    FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        Variable::new("x", SourceInformation::dummy()),
+                        type_.clone(),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    VariableDefinition::new(
+                        "y",
+                        Variable::new("f", SourceInformation::dummy()),
+                        types::Unknown::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into()
+                ])
+            ),
+            Ok(Module::from_definitions(vec![
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    type_.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                VariableDefinition::new(
+                    "y",
+                    TypeCoercion::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        type_,
+                        types::Unknown::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Unknown::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ]))
+        );
+    }
+}
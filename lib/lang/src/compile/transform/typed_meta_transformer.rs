@@ -257,7 +257,8 @@ impl<D: TypedTransformer> TypedMetaTransformer<D> {
             )
             .into(),
             Expression::Operation(operation) => match operation {
-                Operation::Arithmetic(operation) => ArithmeticOperation::new(
+                Operation::Arithmetic(operation) => ArithmeticOperation::with_type(
+                    operation.type_().clone(),
                     operation.operator(),
                     self.transform_expression(operation.lhs(), variables)?,
                     self.transform_expression(operation.rhs(), variables)?,
@@ -279,7 +280,8 @@ impl<D: TypedTransformer> TypedMetaTransformer<D> {
                     operation.source_information().clone(),
                 )
                 .into(),
-                Operation::Order(operation) => OrderOperation::new(
+                Operation::Order(operation) => OrderOperation::with_type(
+                    operation.type_().clone(),
                     operation.operator(),
                     self.transform_expression(operation.lhs(), variables)?,
                     self.transform_expression(operation.rhs(), variables)?,
@@ -316,12 +318,28 @@ impl<D: TypedTransformer> TypedMetaTransformer<D> {
                 operation.source_information().clone(),
             )
             .into(),
+            // Type coercions are generated on type erasure.
+            Expression::TypeCoercion(coercion) => TypeCoercion::new(
+                self.transform_expression(coercion.argument(), variables)?,
+                coercion.from().clone(),
+                coercion.to().clone(),
+                coercion.source_information().clone(),
+            )
+            .into(),
             Expression::Boolean(_)
+            | Expression::Integer(_)
             | Expression::None(_)
             | Expression::Number(_)
             | Expression::String(_)
             | Expression::Variable(_) => expression.clone(),
-            Expression::RecordUpdate(_) | Expression::TypeCoercion(_) => unreachable!(),
+            Expression::Array(_)
+            | Expression::HashOperation(_)
+            | Expression::Lambda(_)
+            | Expression::LetPattern(_)
+            | Expression::ListPatternCase(_)
+            | Expression::Map(_)
+            | Expression::RecordUpdate(_)
+            | Expression::StringInterpolation(_) => unreachable!(),
         };
 
         self.component_transformer
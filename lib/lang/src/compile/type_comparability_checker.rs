@@ -27,9 +27,11 @@ impl TypeComparabilityChecker {
             Type::Any(_) => false,
             Type::Boolean(_) => true,
             Type::Function(_) => false,
+            Type::Integer(_) => true,
             Type::List(list) => self.check_with_cache(list.element(), record_names)?,
             Type::None(_) => true,
             Type::Number(_) => true,
+            Type::Parameter(_) => false,
             Type::Record(record) => {
                 if record_names.contains(record.name()) {
                     true
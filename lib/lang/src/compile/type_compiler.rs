@@ -5,6 +5,7 @@ use super::{
 use crate::types::{self, Type};
 use std::sync::Arc;
 
+pub const INTEGER_TYPE_NAME: &str = "ein_Integer";
 pub const NONE_TYPE_NAME: &str = "ein_None";
 pub const THUNK_ARGUMENT_TYPE_NAME: &str = "ein_thunk_argument";
 
@@ -37,6 +38,8 @@ impl TypeCompiler {
                 self.compile(function.result())?,
             )
             .into(),
+            // Integers are represented by bits of numbers.
+            Type::Integer(_) => eir::types::Type::Number,
             Type::List(_) => self.compile_any_list().into(),
             Type::None(_) => self.compile_none().into(),
             Type::Number(_) => eir::types::Type::Number,
@@ -44,7 +47,7 @@ impl TypeCompiler {
             Type::Reference(reference) => self.compile_reference(reference)?,
             Type::String(_) => self.compile_string(),
             Type::Union(_) => self.compile_union(),
-            Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+            Type::Parameter(_) | Type::Unknown(_) | Type::Variable(_) => unreachable!(),
         })
     }
 
@@ -102,6 +105,12 @@ impl TypeCompiler {
         eir::types::Type::ByteString
     }
 
+    // Integers are boxed into records in variants so that they are
+    // distinguished from numbers.
+    pub fn compile_integer_record(&self) -> eir::types::Record {
+        eir::types::Record::new(INTEGER_TYPE_NAME)
+    }
+
     pub fn compile_none(&self) -> eir::types::Record {
         eir::types::Record::new(NONE_TYPE_NAME)
     }
@@ -1,7 +1,7 @@
 use super::{
     error::CompileError,
     reference_type_resolver::ReferenceTypeResolver,
-    type_compiler::{TypeCompiler, NONE_TYPE_NAME, THUNK_ARGUMENT_TYPE_NAME},
+    type_compiler::{TypeCompiler, INTEGER_TYPE_NAME, NONE_TYPE_NAME, THUNK_ARGUMENT_TYPE_NAME},
 };
 use crate::{ast::*, types::Type};
 use std::{collections::HashSet, sync::Arc};
@@ -30,6 +30,10 @@ impl TypeDefinitionCompiler {
                 eir::types::RecordBody::new(vec![]),
             ),
             eir::ir::TypeDefinition::new(NONE_TYPE_NAME, eir::types::RecordBody::new(vec![])),
+            eir::ir::TypeDefinition::new(
+                INTEGER_TYPE_NAME,
+                eir::types::RecordBody::new(vec![eir::types::Type::Number]),
+            ),
         ]
         .into_iter()
         .chain(
@@ -88,10 +92,13 @@ impl TypeDefinitionCompiler {
             Type::Any(_)
             | Type::Boolean(_)
             | Type::Function(_)
+            | Type::Integer(_)
             | Type::None(_)
             | Type::Number(_)
             | Type::String(_) => vec![],
-            Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+            Type::Parameter(_) | Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => {
+                unreachable!()
+            }
         })
     }
 }
@@ -2,6 +2,7 @@ use super::{error::CompileError, reference_type_resolver::ReferenceTypeResolver}
 use crate::types::Type;
 use std::sync::Arc;
 
+#[derive(Debug)]
 pub struct TypeEqualityChecker {
     reference_type_resolver: Arc<ReferenceTypeResolver>,
 }
@@ -19,7 +20,18 @@ impl TypeEqualityChecker {
         let other = self.reference_type_resolver.resolve(other)?;
 
         let value = match (&one, &other) {
-            (Type::Record(one), Type::Record(other)) => one.name() == other.name(),
+            (Type::Record(one), Type::Record(other)) => {
+                one.name() == other.name()
+                    && one.arguments().len() == other.arguments().len()
+                    && one
+                        .arguments()
+                        .iter()
+                        .zip(other.arguments())
+                        .map(|(one, other)| self.equal(one, other))
+                        .collect::<Result<Vec<_>, CompileError>>()?
+                        .iter()
+                        .all(|value| *value)
+            }
             (Type::Union(one), Type::Union(other)) => {
                 one.types().len() == other.types().len()
                     && one
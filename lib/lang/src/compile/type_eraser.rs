@@ -0,0 +1,158 @@
+use super::{
+    error::CompileError, reference_type_resolver::ReferenceTypeResolver,
+    type_canonicalizer::TypeCanonicalizer, type_equality_checker::TypeEqualityChecker,
+};
+use crate::{
+    ast::*,
+    types::{self, Type},
+};
+use std::sync::Arc;
+
+// Type parameters are erased into Any types and type arguments are dropped
+// after type inference because values of generic types are boxed uniformly.
+pub struct TypeEraser {
+    type_equality_checker: Arc<TypeEqualityChecker>,
+    type_canonicalizer: Arc<TypeCanonicalizer>,
+}
+
+impl TypeEraser {
+    pub fn new(module: &Module) -> Arc<Self> {
+        let reference_type_resolver = ReferenceTypeResolver::new(&Module::new(
+            module.path().clone(),
+            module.export().clone(),
+            module.export_foreign().clone(),
+            module
+                .imports()
+                .iter()
+                .map(|import| Self::erase_import(import, &|type_| Ok(Self::erase_shallowly(type_))))
+                .collect::<Result<_, CompileError>>()
+                .unwrap(),
+            vec![],
+            module
+                .type_definitions()
+                .iter()
+                .map(|definition| {
+                    TypeDefinition::new(
+                        definition.name(),
+                        Self::erase_shallowly(definition.type_()),
+                    )
+                })
+                .collect(),
+            vec![],
+        ));
+        let type_equality_checker = TypeEqualityChecker::new(reference_type_resolver.clone());
+
+        Self {
+            type_canonicalizer: TypeCanonicalizer::new(
+                reference_type_resolver,
+                type_equality_checker.clone(),
+            ),
+            type_equality_checker,
+        }
+        .into()
+    }
+
+    pub fn erase(&self, type_: &Type) -> Result<Type, CompileError> {
+        if Self::is_generic(type_) {
+            self.type_canonicalizer
+                .canonicalize(&Self::erase_shallowly(type_))
+        } else {
+            Ok(type_.clone())
+        }
+    }
+
+    // Types are compared after erasure.
+    pub fn equal(&self, one: &Type, other: &Type) -> Result<bool, CompileError> {
+        self.type_equality_checker
+            .equal(&self.erase(one)?, &self.erase(other)?)
+    }
+
+    pub fn erase_module(&self, module: &Module) -> Result<Module, CompileError> {
+        let module = module.transform_types(&mut |type_| self.erase(type_))?;
+
+        Ok(Module::new(
+            module.path().clone(),
+            module.export().clone(),
+            module.export_foreign().clone(),
+            module
+                .imports()
+                .iter()
+                .map(|import| Self::erase_import(import, &|type_| self.erase(type_)))
+                .collect::<Result<_, _>>()?,
+            module.import_foreigns().to_vec(),
+            module.type_definitions().to_vec(),
+            module.definitions().to_vec(),
+        ))
+    }
+
+    fn erase_import(
+        import: &Import,
+        erase: &dyn Fn(&Type) -> Result<Type, CompileError>,
+    ) -> Result<Import, CompileError> {
+        let interface = import.module_interface();
+
+        Ok(Import::new(
+            ModuleInterface::new(
+                interface.path().clone(),
+                interface.exported_names().clone(),
+                interface.opaque_names().clone(),
+                interface
+                    .types()
+                    .iter()
+                    .map(|(name, type_)| Ok((name.clone(), erase(type_)?)))
+                    .collect::<Result<_, CompileError>>()?,
+                interface
+                    .variables()
+                    .iter()
+                    .map(|(name, type_)| Ok((name.clone(), erase(type_)?)))
+                    .collect::<Result<_, CompileError>>()?,
+                interface.documentation().clone(),
+            ),
+            import.qualified(),
+        ))
+    }
+
+    fn erase_shallowly(type_: &Type) -> Type {
+        type_
+            .transform_types(&mut |type_| -> Result<_, ()> {
+                Ok(match type_ {
+                    Type::Parameter(parameter) => {
+                        types::Any::new(parameter.source_information().clone()).into()
+                    }
+                    Type::Record(record) => types::Record::new(
+                        record.name(),
+                        record.elements().to_vec(),
+                        record.source_information().clone(),
+                    )
+                    .into(),
+                    Type::Reference(reference) => types::Reference::new(
+                        reference.name(),
+                        reference.source_information().clone(),
+                    )
+                    .into(),
+                    _ => type_.clone(),
+                })
+            })
+            .unwrap()
+    }
+
+    fn is_generic(type_: &Type) -> bool {
+        let mut generic = false;
+
+        type_
+            .transform_types(&mut |type_| -> Result<_, ()> {
+                generic = generic
+                    || match type_ {
+                        Type::Parameter(_) => true,
+                        Type::Record(record) => !record.arguments().is_empty(),
+                        Type::Reference(reference) => !reference.arguments().is_empty(),
+                        _ => false,
+                    };
+
+                Ok(type_.clone())
+            })
+            .unwrap();
+
+        generic
+    }
+}
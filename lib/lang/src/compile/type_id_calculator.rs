@@ -36,6 +36,7 @@ impl TypeIdCalculator {
                 self.calculate_type_id(function.argument())?,
                 self.calculate_type_id(function.result())?
             ),
+            Type::Integer(_) => "Integer".into(),
             Type::List(list) => format!("[{}]", self.calculate_type_id(list.element())?),
             Type::None(_) => "None".into(),
             Type::Number(_) => "Number".into(),
@@ -50,7 +51,9 @@ impl TypeIdCalculator {
                     .collect::<Result<Vec<_>, _>>()?
                     .join("|")
             ),
-            Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+            Type::Parameter(_) | Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => {
+                unreachable!()
+            }
         })
     }
 }
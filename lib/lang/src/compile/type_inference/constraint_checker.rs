@@ -71,10 +71,11 @@ impl ConstraintChecker {
                     }
                 }
                 (Type::Boolean(_), Type::Boolean(_)) => {}
+                (Type::Integer(_), Type::Integer(_)) => {}
                 (Type::None(_), Type::None(_)) => {}
                 (Type::Number(_), Type::Number(_)) => {}
                 (Type::String(_), Type::String(_)) => {}
-                (Type::Record(one), Type::Record(other)) => {
+                (Type::Parameter(one), Type::Parameter(other)) => {
                     if one.name() != other.name() {
                         return Err(CompileError::TypesNotMatched(
                             one.source_information().clone(),
@@ -82,6 +83,17 @@ impl ConstraintChecker {
                         ));
                     }
                 }
+                (Type::Record(one), Type::Record(other)) => {
+                    if !self
+                        .type_equality_checker
+                        .equal(&one.clone().into(), &other.clone().into())?
+                    {
+                        return Err(CompileError::TypesNotMatched(
+                            one.source_information().clone(),
+                            other.source_information().clone(),
+                        ));
+                    }
+                }
                 (one, other) => {
                     return Err(CompileError::TypesNotMatched(
                         one.source_information().clone(),
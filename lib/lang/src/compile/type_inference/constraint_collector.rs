@@ -8,6 +8,7 @@ use super::{
 };
 use crate::{
     ast::*,
+    debug::SourceInformation,
     types::{self, Type},
 };
 use std::{collections::HashMap, sync::Arc};
@@ -60,11 +61,27 @@ impl ConstraintCollector {
         function_definition: &FunctionDefinition,
         variables: &HashMap<String, Type>,
     ) -> Result<(), CompileError> {
-        let source_information = function_definition.source_information();
+        self.infer_function(
+            function_definition.arguments(),
+            function_definition.body(),
+            function_definition.type_(),
+            function_definition.source_information(),
+            variables,
+        )
+    }
+
+    fn infer_function(
+        &mut self,
+        arguments: &[String],
+        body: &Expression,
+        type_: &Type,
+        source_information: &Arc<SourceInformation>,
+        variables: &HashMap<String, Type>,
+    ) -> Result<(), CompileError> {
         let mut variables = variables.clone();
-        let mut type_ = function_definition.type_().clone();
+        let mut type_ = type_.clone();
 
-        for argument_name in function_definition.arguments() {
+        for argument_name in arguments {
             let argument_type: Type = types::Variable::new(source_information.clone()).into();
             let result_type: Type = types::Variable::new(source_information.clone()).into();
 
@@ -82,7 +99,7 @@ impl ConstraintCollector {
             type_ = result_type;
         }
 
-        let body_type = self.infer_expression(function_definition.body(), &variables)?;
+        let body_type = self.infer_expression(body, &variables)?;
         self.solved_subsumption_set.add(body_type, type_);
 
         Ok(())
@@ -152,6 +169,14 @@ impl ConstraintCollector {
 
                 Ok(result.into())
             }
+            Expression::HashOperation(operation) => {
+                let argument = self.infer_expression(operation.argument(), variables)?;
+
+                self.solved_subsumption_set
+                    .add(argument, operation.type_().clone());
+
+                Ok(types::Number::new(operation.source_information().clone()).into())
+            }
             Expression::If(if_) => {
                 let condition = self.infer_expression(if_.condition(), variables)?;
                 self.solved_subsumption_set.add(
@@ -168,6 +193,17 @@ impl ConstraintCollector {
 
                 Ok(result.into())
             }
+            Expression::Lambda(lambda) => {
+                self.infer_function(
+                    lambda.arguments(),
+                    lambda.body(),
+                    lambda.type_(),
+                    lambda.source_information(),
+                    variables,
+                )?;
+
+                Ok(lambda.type_().clone())
+            }
             Expression::Let(let_) => {
                 let mut variables = variables.clone();
 
@@ -272,6 +308,9 @@ impl ConstraintCollector {
 
                 Ok(result.into())
             }
+            Expression::Integer(integer) => {
+                Ok(types::Integer::new(integer.source_information().clone()).into())
+            }
             Expression::None(none) => {
                 Ok(types::None::new(none.source_information().clone()).into())
             }
@@ -282,12 +321,10 @@ impl ConstraintCollector {
                 Operation::Arithmetic(operation) => {
                     let lhs = self.infer_expression(operation.lhs(), variables)?;
                     let rhs = self.infer_expression(operation.rhs(), variables)?;
-                    let number_type = types::Number::new(operation.source_information().clone());
 
-                    self.solved_subsumption_set.add(lhs, number_type.clone());
-                    self.solved_subsumption_set.add(rhs, number_type.clone());
+                    self.infer_numeric_operation(lhs, rhs, operation.type_())?;
 
-                    number_type.into()
+                    operation.type_().clone()
                 }
                 Operation::Boolean(operation) => {
                     let lhs = self.infer_expression(operation.lhs(), variables)?;
@@ -313,10 +350,8 @@ impl ConstraintCollector {
                 Operation::Order(operation) => {
                     let lhs = self.infer_expression(operation.lhs(), variables)?;
                     let rhs = self.infer_expression(operation.rhs(), variables)?;
-                    let number_type = types::Number::new(operation.source_information().clone());
 
-                    self.solved_subsumption_set.add(lhs, number_type.clone());
-                    self.solved_subsumption_set.add(rhs, number_type);
+                    self.infer_numeric_operation(lhs, rhs, operation.type_())?;
 
                     types::Boolean::new(operation.source_information().clone()).into()
                 }
@@ -384,7 +419,63 @@ impl ConstraintCollector {
                 .get(variable.name())
                 .cloned()
                 .ok_or_else(|| CompileError::VariableNotFound(variable.clone())),
-            Expression::RecordUpdate(_) | Expression::TypeCoercion(_) => unreachable!(),
+            // Type coercions before type inference instantiate generic variables.
+            Expression::TypeCoercion(coercion) => {
+                let mut parameters = HashMap::<String, Type>::new();
+
+                self.solved_subsumption_set.add(
+                    coercion
+                        .from()
+                        .transform_types(&mut |type_| -> Result<_, CompileError> {
+                            Ok(match type_ {
+                                Type::Parameter(parameter) => parameters
+                                    .entry(parameter.name().into())
+                                    .or_insert_with(|| {
+                                        types::Variable::new(coercion.source_information().clone())
+                                            .into()
+                                    })
+                                    .clone(),
+                                _ => type_.clone(),
+                            })
+                        })?,
+                    coercion.to().clone(),
+                );
+
+                Ok(coercion.to().clone())
+            }
+            Expression::Array(_)
+            | Expression::LetPattern(_)
+            | Expression::ListPatternCase(_)
+            | Expression::Map(_)
+            | Expression::RecordUpdate(_)
+            | Expression::StringInterpolation(_) => unreachable!(),
         }
     }
+
+    // Operands are numbers unless either of them is known to be an integer.
+    fn infer_numeric_operation(
+        &mut self,
+        lhs: Type,
+        rhs: Type,
+        type_: &Type,
+    ) -> Result<(), CompileError> {
+        if matches!(
+            self.reference_type_resolver.resolve(&lhs)?,
+            Type::Integer(_)
+        ) || matches!(
+            self.reference_type_resolver.resolve(&rhs)?,
+            Type::Integer(_)
+        ) {
+            self.solved_subsumption_set.add(lhs, type_.clone());
+            self.solved_subsumption_set.add(rhs, type_.clone());
+        } else {
+            let number_type = types::Number::new(type_.source_information().clone());
+
+            self.solved_subsumption_set.add(lhs, number_type.clone());
+            self.solved_subsumption_set.add(rhs, number_type.clone());
+            self.solved_subsumption_set.add(number_type, type_.clone());
+        }
+
+        Ok(())
+    }
 }
@@ -4,7 +4,7 @@ use super::{
     subsumption_set::SubsumptionSet,
     variable_constraint_set::VariableConstraintSet,
 };
-use crate::types::Type;
+use crate::types::{self, Type};
 use std::{collections::HashMap, sync::Arc};
 
 pub struct ConstraintSolver {
@@ -68,6 +68,37 @@ impl ConstraintSolver {
                 (Type::List(one), Type::List(other)) => {
                     solved_subsumption_set.add(one.element().clone(), other.element().clone());
                 }
+                // Type arguments of records are invariant.
+                (Type::Record(one), Type::Record(other)) => {
+                    if one.name() == other.name() {
+                        for (one, other) in one.arguments().iter().zip(other.arguments()) {
+                            solved_subsumption_set.add(one.clone(), other.clone());
+                            solved_subsumption_set.add(other.clone(), one.clone());
+                        }
+                    }
+
+                    checked_subsumption_set.add(one, other);
+                }
+                (Type::Union(one), upper) => {
+                    for type_ in one.types() {
+                        solved_subsumption_set.add(type_.clone(), upper.clone());
+                    }
+                }
+                // Records are matched with ones of the same names in unions
+                // to infer their type arguments.
+                (Type::Record(one), Type::Union(union)) => {
+                    for type_ in union.types() {
+                        if let Some(other) =
+                            self.reference_type_resolver.resolve_to_record(type_)?
+                        {
+                            if one.name() == other.name() {
+                                solved_subsumption_set.add(one.clone(), other);
+                            }
+                        }
+                    }
+
+                    checked_subsumption_set.add(one, union);
+                }
                 subsumption => checked_subsumption_set.add(subsumption.0, subsumption.1),
             }
         }
@@ -82,10 +113,13 @@ impl ConstraintSolver {
         let mut substitutions = HashMap::<usize, Type>::new();
 
         for (id, constraint) in constraint_set.constraints() {
-            let constraint_type = self
-                .constraint_converter
-                .convert(constraint)?
-                .substitute_variables(&substitutions);
+            let constraint_type = Self::remove_variable_from_union(
+                *id,
+                &self
+                    .constraint_converter
+                    .convert(constraint)?
+                    .substitute_variables(&substitutions),
+            );
 
             for type_ in substitutions.values_mut() {
                 *type_ = type_.substitute_variable(*id, &constraint_type);
@@ -99,6 +133,35 @@ impl ConstraintSolver {
         Ok(substitutions)
     }
 
+    // Variables constrained by each other (e.g. by invariant type arguments)
+    // refer to themselves in their lower types.
+    fn remove_variable_from_union(id: usize, type_: &Type) -> Type {
+        match type_ {
+            Type::Union(union) => {
+                let types = Self::flatten_union_without_variable(id, type_);
+
+                if types.is_empty() {
+                    type_.clone()
+                } else {
+                    types::Union::new(types, union.source_information().clone()).into()
+                }
+            }
+            _ => type_.clone(),
+        }
+    }
+
+    fn flatten_union_without_variable(id: usize, type_: &Type) -> Vec<Type> {
+        match type_ {
+            Type::Union(union) => union
+                .types()
+                .iter()
+                .flat_map(|type_| Self::flatten_union_without_variable(id, type_))
+                .collect(),
+            Type::Variable(variable) if variable.id() == id => vec![],
+            _ => vec![type_.clone()],
+        }
+    }
+
     fn validate_substitutions(
         &self,
         substitutions: &HashMap<usize, Type>,
@@ -108,9 +171,11 @@ impl ConstraintSolver {
                 Type::Any(_)
                 | Type::Boolean(_)
                 | Type::Function(_)
+                | Type::Integer(_)
                 | Type::List(_)
                 | Type::None(_)
                 | Type::Number(_)
+                | Type::Parameter(_)
                 | Type::Record(_)
                 | Type::Reference(_)
                 | Type::String(_)
@@ -42,9 +42,11 @@ impl IntersectionTypeCalculator {
                 (Type::Any(_), _) => other.clone(),
                 (Type::Boolean(_), _)
                 | (Type::Function(_), _)
+                | (Type::Integer(_), _)
                 | (Type::List(_), _)
                 | (Type::None(_), _)
                 | (Type::Number(_), _)
+                | (Type::Parameter(_), _)
                 | (Type::Record(_), _)
                 | (Type::String(_), _) => one.clone(),
                 (Type::Union(one), Type::Union(other)) => self.type_canonicalizer.canonicalize(
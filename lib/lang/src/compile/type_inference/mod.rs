@@ -427,6 +427,232 @@ mod tests {
         );
     }
 
+    #[test]
+    fn infer_types_of_lambdas_with_free_variables() {
+        let create_module = |lambda_type: Type, operation_type: Type| {
+            Module::from_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Lambda::with_type(
+                    lambda_type,
+                    vec!["y".into()],
+                    ArithmeticOperation::with_type(
+                        operation_type,
+                        ArithmeticOperator::Add,
+                        Variable::new("x", SourceInformation::dummy()),
+                        Variable::new("y", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                types::Unknown::new(SourceInformation::dummy()).into(),
+                types::Unknown::new(SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                types::Number::new(SourceInformation::dummy()).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_lambdas_with_free_variables() {
+        let module = Module::from_definitions(vec![FunctionDefinition::new(
+            "f",
+            vec!["x".into()],
+            Lambda::new(
+                vec!["y".into()],
+                ArithmeticOperation::new(
+                    ArithmeticOperator::Add,
+                    Variable::new("x", SourceInformation::dummy()),
+                    Variable::new("y", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ),
+            types::Function::new(
+                types::EinString::new(SourceInformation::dummy()),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(
+            infer_types(&module),
+            Err(CompileError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_instances_of_generic_functions() {
+        let function_type = types::Function::new(
+            types::Parameter::new("a", SourceInformation::dummy()),
+            types::Parameter::new("a", SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+        let create_module = |number_type: Type, string_type: Type| {
+            Module::from_definitions(vec![
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    function_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                VariableDefinition::new(
+                    "x",
+                    Application::with_type(
+                        number_type.clone(),
+                        TypeCoercion::new(
+                            Variable::new("f", SourceInformation::dummy()),
+                            function_type.clone(),
+                            number_type,
+                            SourceInformation::dummy(),
+                        ),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                VariableDefinition::new(
+                    "y",
+                    Application::with_type(
+                        string_type.clone(),
+                        TypeCoercion::new(
+                            Variable::new("f", SourceInformation::dummy()),
+                            function_type.clone(),
+                            string_type,
+                            SourceInformation::dummy(),
+                        ),
+                        EinString::new("foo", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::EinString::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                types::Unknown::new(SourceInformation::dummy()).into(),
+                types::Unknown::new(SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                types::Function::new(
+                    types::EinString::new(SourceInformation::dummy()),
+                    types::EinString::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_instances_of_generic_functions() {
+        let function_type = types::Function::new(
+            types::Parameter::new("a", SourceInformation::dummy()),
+            types::Parameter::new("a", SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            infer_types(&Module::from_definitions(vec![
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    function_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                VariableDefinition::new(
+                    "x",
+                    Application::new(
+                        TypeCoercion::new(
+                            Variable::new("f", SourceInformation::dummy()),
+                            function_type,
+                            types::Unknown::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::EinString::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])),
+            Err(CompileError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_generic_functions_with_concrete_results() {
+        assert_eq!(
+            infer_types(&Module::from_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Number::new(42.0, SourceInformation::dummy()),
+                types::Function::new(
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(CompileError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
     #[test]
     fn infer_types_of_let_error() {
         let union_type = types::Union::new(
@@ -449,6 +675,7 @@ mod tests {
                 ModuleInterface::new(
                     ModulePath::new(Package::new("m", ""), vec![]),
                     Default::default(),
+                    Default::default(),
                     vec![(
                         "Error".into(),
                         types::Record::new("Error", Default::default(), SourceInformation::dummy())
@@ -457,6 +684,7 @@ mod tests {
                     .into_iter()
                     .collect(),
                     Default::default(),
+                    Default::default(),
                 ),
                 false,
             )],
@@ -527,12 +755,14 @@ mod tests {
                     ModulePath::new(Package::new("m", ""), vec![]),
                     Default::default(),
                     Default::default(),
+                    Default::default(),
                     vec![(
                         "x".into(),
                         types::Number::new(SourceInformation::dummy()).into(),
                     )]
                     .into_iter()
                     .collect(),
+                    Default::default(),
                 ),
                 true,
             )],
@@ -837,6 +1067,7 @@ mod tests {
                 ModuleInterface::new(
                     ModulePath::new(Package::new("P", ""), vec![]),
                     Default::default(),
+                    Default::default(),
                     vec![(
                         "Foo".into(),
                         types::Number::new(SourceInformation::dummy()).into(),
@@ -844,6 +1075,7 @@ mod tests {
                     .into_iter()
                     .collect(),
                     Default::default(),
+                    Default::default(),
                 ),
                 true,
             )],
@@ -1572,38 +1804,132 @@ mod tests {
 
         #[test]
         fn infer_types_of_arithmetic_operations() {
+            let create_module = |type_: Type| {
+                Module::from_definitions(vec![VariableDefinition::new(
+                    "x",
+                    ArithmeticOperation::with_type(
+                        type_,
+                        ArithmeticOperator::Add,
+                        Number::new(42.0, SourceInformation::dummy()),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()])
+            };
+
+            assert_eq!(
+                infer_types(&create_module(
+                    types::Unknown::new(SourceInformation::dummy()).into()
+                )),
+                Ok(create_module(
+                    types::Number::new(SourceInformation::dummy()).into()
+                ))
+            );
+        }
+
+        #[test]
+        fn infer_types_of_integer_arithmetic_operations() {
+            let create_module = |type_: Type| {
+                Module::from_definitions(vec![VariableDefinition::new(
+                    "x",
+                    ArithmeticOperation::with_type(
+                        type_,
+                        ArithmeticOperator::Remainder,
+                        Integer::new(42, SourceInformation::dummy()),
+                        Integer::new(42, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Integer::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()])
+            };
+
+            assert_eq!(
+                infer_types(&create_module(
+                    types::Unknown::new(SourceInformation::dummy()).into()
+                )),
+                Ok(create_module(
+                    types::Integer::new(SourceInformation::dummy()).into()
+                ))
+            );
+        }
+
+        #[test]
+        fn fail_to_infer_types_of_arithmetic_operations_with_mixed_operands() {
             let module = Module::from_definitions(vec![VariableDefinition::new(
                 "x",
                 ArithmeticOperation::new(
                     ArithmeticOperator::Add,
-                    Number::new(42.0, SourceInformation::dummy()),
+                    Integer::new(42, SourceInformation::dummy()),
                     Number::new(42.0, SourceInformation::dummy()),
                     SourceInformation::dummy(),
                 ),
-                types::Number::new(SourceInformation::dummy()),
+                types::Unknown::new(SourceInformation::dummy()),
                 SourceInformation::dummy(),
             )
             .into()]);
 
-            assert_eq!(infer_types(&module), Ok(module.clone()));
+            assert_eq!(
+                infer_types(&module),
+                Err(CompileError::NumericOperandsExpected(
+                    SourceInformation::dummy().into()
+                ))
+            );
         }
 
         #[test]
-        fn infer_types_of_number_comparison_operations() {
+        fn fail_to_infer_types_of_integer_divide_operations() {
             let module = Module::from_definitions(vec![VariableDefinition::new(
                 "x",
-                OrderOperation::new(
-                    OrderOperator::LessThan,
-                    Number::new(42.0, SourceInformation::dummy()),
-                    Number::new(42.0, SourceInformation::dummy()),
+                ArithmeticOperation::new(
+                    ArithmeticOperator::Divide,
+                    Integer::new(42, SourceInformation::dummy()),
+                    Integer::new(42, SourceInformation::dummy()),
                     SourceInformation::dummy(),
                 ),
-                types::Boolean::new(SourceInformation::dummy()),
+                types::Unknown::new(SourceInformation::dummy()),
                 SourceInformation::dummy(),
             )
             .into()]);
 
-            assert_eq!(infer_types(&module), Ok(module.clone()));
+            assert_eq!(
+                infer_types(&module),
+                Err(CompileError::IntegerDivideOperatorExpected(
+                    SourceInformation::dummy().into()
+                ))
+            );
+        }
+
+        #[test]
+        fn infer_types_of_number_comparison_operations() {
+            let create_module = |type_: Type| {
+                Module::from_definitions(vec![VariableDefinition::new(
+                    "x",
+                    OrderOperation::with_type(
+                        type_,
+                        OrderOperator::LessThan,
+                        Number::new(42.0, SourceInformation::dummy()),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Boolean::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()])
+            };
+
+            assert_eq!(
+                infer_types(&create_module(
+                    types::Unknown::new(SourceInformation::dummy()).into()
+                )),
+                Ok(create_module(
+                    types::Number::new(SourceInformation::dummy()).into()
+                ))
+            );
         }
 
         #[test]
@@ -61,11 +61,58 @@ impl TypeInferrer {
         let checker = ConstraintChecker::new(
             substitutor.clone(),
             self.reference_type_resolver.clone(),
-            self.type_equality_checker,
+            self.type_equality_checker.clone(),
         );
 
         checker.check(checked_subsumption_set)?;
 
-        module.transform_types(&mut |type_| substitutor.substitute(type_))
+        let module = module.transform_types(&mut |type_| substitutor.substitute(type_))?;
+
+        Self::check_operand_types(&module, &self.reference_type_resolver)?;
+
+        Ok(module)
+    }
+
+    // Operands of arithmetic and order operations need to be of the same
+    // numeric type. Integers are not divided with `/` operator as their
+    // results would be truncated silently.
+    fn check_operand_types(
+        module: &Module,
+        reference_type_resolver: &ReferenceTypeResolver,
+    ) -> Result<(), CompileError> {
+        module.transform_expressions(&mut |expression| -> Result<_, CompileError> {
+            if let Expression::Operation(operation) = expression {
+                let (type_, divide) = match operation {
+                    Operation::Arithmetic(operation) => (
+                        Some(operation.type_()),
+                        operation.operator() == ArithmeticOperator::Divide,
+                    ),
+                    Operation::Order(operation) => (Some(operation.type_()), false),
+                    Operation::Boolean(_) | Operation::Equality(_) | Operation::Pipe(_) => {
+                        (None, false)
+                    }
+                };
+
+                if let Some(type_) = type_ {
+                    match reference_type_resolver.resolve(type_)? {
+                        Type::Integer(_) if divide => {
+                            return Err(CompileError::IntegerDivideOperatorExpected(
+                                operation.source_information().clone(),
+                            ))
+                        }
+                        Type::Integer(_) | Type::Number(_) => {}
+                        _ => {
+                            return Err(CompileError::NumericOperandsExpected(
+                                operation.source_information().clone(),
+                            ))
+                        }
+                    }
+                }
+            }
+
+            Ok(expression.clone())
+        })?;
+
+        Ok(())
     }
 }
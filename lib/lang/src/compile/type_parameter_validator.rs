@@ -0,0 +1,104 @@
+use super::error::CompileError;
+use crate::{ast::*, types::Type};
+use std::collections::HashMap;
+
+pub struct TypeParameterValidator {}
+
+impl TypeParameterValidator {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn validate(&self, module: &Module) -> Result<(), CompileError> {
+        for type_definition in module.type_definitions() {
+            let mut parameters = HashMap::new();
+
+            if let Type::Record(record) = type_definition.type_() {
+                for argument in record.arguments() {
+                    if let Type::Parameter(parameter) = argument {
+                        if let Some(other) = parameters.insert(parameter.name(), parameter) {
+                            return Err(CompileError::DuplicateNames(
+                                other.source_information().clone(),
+                                parameter.source_information().clone(),
+                            ));
+                        }
+                    }
+                }
+            }
+
+            type_definition
+                .type_()
+                .transform_types(&mut |type_| match type_ {
+                    Type::Parameter(parameter) if !parameters.contains_key(parameter.name()) => {
+                        Err(CompileError::TypeParameterNotFound(parameter.clone()))
+                    }
+                    _ => Ok(type_.clone()),
+                })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, types};
+
+    #[test]
+    fn validate_record_type_with_type_parameters() {
+        let parameter = types::Parameter::new("a", SourceInformation::dummy());
+
+        assert_eq!(
+            TypeParameterValidator::new().validate(&Module::from_definitions_and_type_definitions(
+                vec![TypeDefinition::new(
+                    "Foo",
+                    types::Record::with_arguments(
+                        "Foo",
+                        vec![parameter.clone().into()],
+                        vec![types::RecordElement::new("foo", parameter)],
+                        SourceInformation::dummy()
+                    )
+                )],
+                vec![]
+            )),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_undefined_type_parameter() {
+        let parameter = types::Parameter::new("a", SourceInformation::dummy());
+
+        assert_eq!(
+            TypeParameterValidator::new().validate(&Module::from_definitions_and_type_definitions(
+                vec![TypeDefinition::new(
+                    "Foo",
+                    types::Record::new(
+                        "Foo",
+                        vec![types::RecordElement::new("foo", parameter.clone())],
+                        SourceInformation::dummy()
+                    )
+                )],
+                vec![]
+            )),
+            Err(CompileError::TypeParameterNotFound(parameter))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_type_parameter_in_type_alias() {
+        let parameter = types::Parameter::new("a", SourceInformation::dummy());
+
+        assert_eq!(
+            TypeParameterValidator::new().validate(&Module::from_definitions_and_type_definitions(
+                vec![TypeDefinition::new(
+                    "Foo",
+                    types::List::new(parameter.clone(), SourceInformation::dummy())
+                )],
+                vec![]
+            )),
+            Err(CompileError::TypeParameterNotFound(parameter))
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use crate::debug::*;
+use std::{fmt::Display, sync::Arc};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CompileWarning {
+    UnreachableAlternative(Arc<SourceInformation>),
+}
+
+impl Display for CompileWarning {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::UnreachableAlternative(source_information) => write!(
+                formatter,
+                "unreachable alternative in case expression\n{}",
+                source_information
+            ),
+        }
+    }
+}
@@ -42,6 +42,14 @@ impl SourceInformation {
         }
     }
 
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
     #[cfg(test)]
     pub fn dummy() -> Self {
         Self::new("", Location::new(0, 0), "")
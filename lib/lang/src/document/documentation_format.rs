@@ -0,0 +1,28 @@
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DocumentationFormat {
+    Html,
+    Markdown,
+}
+
+impl DocumentationFormat {
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+impl FromStr for DocumentationFormat {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, ()> {
+        match name {
+            "html" => Ok(Self::Html),
+            "markdown" => Ok(Self::Markdown),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,307 @@
+mod documentation_format;
+mod renderer;
+
+use crate::{ast::ModuleInterface, format::format_type_with_names, types::Type};
+pub use documentation_format::DocumentationFormat;
+use renderer::Renderer;
+use std::collections::HashMap;
+
+const TITLE: &str = "Documentation";
+
+// Only exported names of modules are documented. References to them in types
+// are linked to their documentation.
+pub fn document(module_interfaces: &[ModuleInterface], format: DocumentationFormat) -> String {
+    Documenter::new(module_interfaces, format).document(module_interfaces)
+}
+
+struct Documenter {
+    renderer: Renderer,
+    names: HashMap<String, String>,
+}
+
+impl Documenter {
+    pub fn new(module_interfaces: &[ModuleInterface], format: DocumentationFormat) -> Self {
+        Self {
+            renderer: Renderer::new(format),
+            names: module_interfaces
+                .iter()
+                .flat_map(|module_interface| {
+                    module_interface.exported_names().iter().map(move |name| {
+                        (
+                            module_interface.path().fully_qualify_name(name),
+                            module_interface.path().qualify_name(name),
+                        )
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    pub fn document(&self, module_interfaces: &[ModuleInterface]) -> String {
+        self.renderer.document(
+            TITLE,
+            &vec![self.renderer.heading(1, TITLE, None)]
+                .into_iter()
+                .chain(
+                    module_interfaces
+                        .iter()
+                        .filter(|module_interface| !module_interface.exported_names().is_empty())
+                        .flat_map(|module_interface| self.document_module(module_interface)),
+                )
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn document_module(&self, module_interface: &ModuleInterface) -> Vec<String> {
+        let path = module_interface.path();
+        let mut sections = vec![self.renderer.heading(
+            2,
+            &vec![path.package().name()]
+                .into_iter()
+                .chain(path.components())
+                .collect::<Vec<_>>()
+                .join("/"),
+            Some(&anchor(&path.fully_qualify_name(""))),
+        )];
+
+        for name in module_interface.exported_names() {
+            let full_name = path.fully_qualify_name(name);
+
+            if let Some(type_) = module_interface.types().get(&full_name) {
+                sections.extend(self.document_type(
+                    name,
+                    &full_name,
+                    type_,
+                    module_interface.opaque_names().contains(name),
+                ));
+            } else if let Some(type_) = module_interface.variables().get(&full_name) {
+                sections.push(self.renderer.heading(3, name, Some(&anchor(&full_name))));
+                sections.push(self.renderer.code(&format!(
+                    "{} : {}",
+                    self.renderer.text(name),
+                    self.format_type(type_)
+                )));
+            }
+
+            if let Some(documentation) = module_interface.documentation().get(&full_name) {
+                sections.push(self.renderer.paragraph(documentation));
+            }
+        }
+
+        sections
+    }
+
+    // Elements of opaque types are not documented.
+    fn document_type(
+        &self,
+        name: &str,
+        full_name: &str,
+        type_: &Type,
+        opaque: bool,
+    ) -> Vec<String> {
+        let mut sections =
+            vec![self
+                .renderer
+                .heading(3, &format!("type {}", name), Some(&anchor(full_name)))];
+
+        match type_ {
+            Type::Record(record) => {
+                sections.push(
+                    self.renderer
+                        .code(&format!("type {}", self.renderer.text(name))),
+                );
+
+                if !opaque && !record.elements().is_empty() {
+                    sections.push(
+                        self.renderer.list(
+                            "Fields",
+                            &record
+                                .elements()
+                                .iter()
+                                .map(|element| {
+                                    format!(
+                                        "{} : {}",
+                                        self.renderer.text(element.name()),
+                                        self.format_type(element.type_())
+                                    )
+                                })
+                                .collect::<Vec<_>>(),
+                        ),
+                    );
+                }
+            }
+            _ => {
+                sections.push(self.renderer.code(&format!(
+                    "type {} = {}",
+                    self.renderer.text(name),
+                    self.format_type(type_)
+                )));
+
+                if let Type::Union(union) = type_ {
+                    sections.push(
+                        self.renderer.list(
+                            "Members",
+                            &union
+                                .types()
+                                .iter()
+                                .map(|type_| self.format_type(type_))
+                                .collect::<Vec<_>>(),
+                        ),
+                    );
+                }
+            }
+        }
+
+        sections
+    }
+
+    fn format_type(&self, type_: &Type) -> String {
+        format_type_with_names(type_, &|name| match self.names.get(name) {
+            Some(displayed_name) => self.renderer.link(displayed_name, &anchor(name)),
+            None => self.renderer.text(name.rsplit('.').next().unwrap_or(name)),
+        })
+    }
+}
+
+// Anchors consist only of alphanumeric characters and hyphens so that they
+// are valid in both HTML and Markdown.
+fn anchor(name: &str) -> String {
+    name.chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() {
+                character
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, package::Package, path::ModulePath, types};
+    use pretty_assertions::assert_eq;
+
+    fn create_module_interface() -> ModuleInterface {
+        ModuleInterface::new(
+            ModulePath::new(Package::new("P", ""), vec!["M".into()]),
+            vec!["Foo".into(), "Bar".into(), "f".into()]
+                .into_iter()
+                .collect(),
+            Default::default(),
+            vec![
+                (
+                    "P().M.Foo".into(),
+                    types::Record::new(
+                        "P().M.Foo",
+                        vec![types::RecordElement::new(
+                            "foo",
+                            types::Number::new(SourceInformation::dummy()),
+                        )],
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ),
+                (
+                    "P().M.Bar".into(),
+                    types::Union::new(
+                        vec![
+                            types::Reference::new("P().M.Foo", SourceInformation::dummy()).into(),
+                            types::None::new(SourceInformation::dummy()).into(),
+                        ],
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            vec![(
+                "P().M.f".into(),
+                types::Function::new(
+                    types::Reference::new("P().M.Bar", SourceInformation::dummy()),
+                    types::Reference::new("P().O.Baz", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            )]
+            .into_iter()
+            .collect(),
+            vec![
+                ("P().M.Foo".into(), "Foo type".into()),
+                ("P().M.f".into(), "Function f".into()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+
+    #[test]
+    fn document_in_markdown() {
+        assert_eq!(
+            document(&[create_module_interface()], DocumentationFormat::Markdown),
+            indoc::indoc!(
+                "
+                # Documentation
+
+                ## <a id=\"P---M-\"></a>P/M
+
+                ### <a id=\"P---M-Bar\"></a>type Bar
+
+                <code>type Bar = None | [M.Foo](#P---M-Foo)</code>
+
+                Members:
+
+                - <code>None</code>
+                - <code>[M.Foo](#P---M-Foo)</code>
+
+                ### <a id=\"P---M-Foo\"></a>type Foo
+
+                <code>type Foo</code>
+
+                Fields:
+
+                - <code>foo : Number</code>
+
+                Foo type
+
+                ### <a id=\"P---M-f\"></a>f
+
+                <code>f : [M.Bar](#P---M-Bar) -> Baz</code>
+
+                Function f
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn document_opaque_type() {
+        let module_interface = create_module_interface();
+
+        assert!(!document(
+            &[ModuleInterface::new(
+                module_interface.path().clone(),
+                module_interface.exported_names().clone(),
+                vec!["Foo".into()].into_iter().collect(),
+                module_interface.types().clone(),
+                module_interface.variables().clone(),
+                module_interface.documentation().clone(),
+            )],
+            DocumentationFormat::Markdown
+        )
+        .contains("Fields"));
+    }
+
+    #[test]
+    fn document_in_html() {
+        let html = document(&[create_module_interface()], DocumentationFormat::Html);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h3 id=\"P---M-f\">f</h3>"));
+        assert!(html.contains(
+            "<pre><code>f : <a href=\"#P---M-Bar\">M.Bar</a> -> Baz</code></pre>\n<p>Function f</p>"
+        ));
+    }
+}
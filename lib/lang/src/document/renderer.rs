@@ -0,0 +1,101 @@
+use super::documentation_format::DocumentationFormat;
+
+// Renderers receive texts already rendered except the ones of headings,
+// paragraphs and texts themselves.
+pub struct Renderer {
+    format: DocumentationFormat,
+}
+
+impl Renderer {
+    pub fn new(format: DocumentationFormat) -> Self {
+        Self { format }
+    }
+
+    pub fn document(&self, title: &str, sections: &[String]) -> String {
+        match self.format {
+            DocumentationFormat::Html => format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+                self.text(title),
+                sections.concat()
+            ),
+            DocumentationFormat::Markdown => sections.join("\n"),
+        }
+    }
+
+    pub fn heading(&self, level: usize, text: &str, anchor: Option<&str>) -> String {
+        match self.format {
+            DocumentationFormat::Html => format!(
+                "<h{}{}>{}</h{}>\n",
+                level,
+                anchor
+                    .map(|anchor| format!(" id=\"{}\"", anchor))
+                    .unwrap_or_default(),
+                self.text(text),
+                level
+            ),
+            DocumentationFormat::Markdown => format!(
+                "{} {}{}\n",
+                "#".repeat(level),
+                anchor
+                    .map(|anchor| format!("<a id=\"{}\"></a>", anchor))
+                    .unwrap_or_default(),
+                text
+            ),
+        }
+    }
+
+    pub fn code(&self, code: &str) -> String {
+        match self.format {
+            DocumentationFormat::Html => format!("<pre><code>{}</code></pre>\n", code),
+            DocumentationFormat::Markdown => format!("<code>{}</code>\n", code),
+        }
+    }
+
+    pub fn paragraph(&self, text: &str) -> String {
+        match self.format {
+            DocumentationFormat::Html => text
+                .split("\n\n")
+                .map(|paragraph| format!("<p>{}</p>\n", self.text(paragraph)))
+                .collect(),
+            DocumentationFormat::Markdown => format!("{}\n", text),
+        }
+    }
+
+    pub fn list(&self, title: &str, items: &[String]) -> String {
+        match self.format {
+            DocumentationFormat::Html => format!(
+                "<p>{}</p>\n<ul>\n{}</ul>\n",
+                self.text(title),
+                items
+                    .iter()
+                    .map(|item| format!("<li><code>{}</code></li>\n", item))
+                    .collect::<String>()
+            ),
+            DocumentationFormat::Markdown => format!(
+                "{}:\n\n{}",
+                title,
+                items
+                    .iter()
+                    .map(|item| format!("- <code>{}</code>\n", item))
+                    .collect::<String>()
+            ),
+        }
+    }
+
+    pub fn link(&self, text: &str, anchor: &str) -> String {
+        match self.format {
+            DocumentationFormat::Html => format!("<a href=\"#{}\">{}</a>", anchor, self.text(text)),
+            DocumentationFormat::Markdown => format!("[{}](#{})", text, anchor),
+        }
+    }
+
+    pub fn text(&self, text: &str) -> String {
+        match self.format {
+            DocumentationFormat::Html => text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;"),
+            DocumentationFormat::Markdown => text.into(),
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use super::{indent, type_::format_type};
+use crate::ast::*;
+
+pub fn format_definition(definition: &Definition) -> String {
+    match definition {
+        Definition::FunctionDefinition(definition) => format_body(
+            &format!(
+                "{} : {}\n{} {} =",
+                definition.name(),
+                format_type(definition.type_()),
+                definition.name(),
+                definition.arguments().join(" ")
+            ),
+            definition.body(),
+        ),
+        Definition::VariableDefinition(definition) => format_variable_definition(definition, "="),
+    }
+}
+
+fn format_variable_definition(definition: &VariableDefinition, sign: &str) -> String {
+    format_body(
+        &format!(
+            "{} : {}\n{} {}",
+            definition.name(),
+            format_type(definition.type_()),
+            definition.name(),
+            sign
+        ),
+        definition.body(),
+    )
+}
+
+fn format_body(head: &str, body: &Expression) -> String {
+    let body = format_expression(body);
+
+    if body.contains('\n') {
+        format!("{}\n{}", head, indent(&body))
+    } else {
+        format!("{} {}", head, body)
+    }
+}
+
+pub fn format_expression(expression: &Expression) -> String {
+    match expression {
+        Expression::Application(application) => format!(
+            "{} {}",
+            match application.function() {
+                Expression::Application(_) => format_expression(application.function()),
+                _ => format_atomic_expression(application.function()),
+            },
+            format_atomic_expression(application.argument())
+        ),
+        Expression::Array(array) => {
+            if array.elements().is_empty() {
+                "[||]".into()
+            } else {
+                format!(
+                    "[| {} |]",
+                    array
+                        .elements()
+                        .iter()
+                        .map(format_expression)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+        }
+        Expression::Boolean(boolean) => if boolean.value() { "True" } else { "False" }.into(),
+        Expression::Case(case) => format!(
+            "case {} = {}\n{}",
+            case.name(),
+            format_expression(case.argument()),
+            indent(
+                &case
+                    .alternatives()
+                    .iter()
+                    .map(|alternative| format_body(
+                        &format!(
+                            "{} =>",
+                            if let Some(pattern) = alternative.pattern() {
+                                format_pattern(pattern)
+                            } else {
+                                format_type(alternative.type_())
+                            }
+                        ),
+                        alternative.expression()
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        ),
+        Expression::HashOperation(operation) => {
+            format!("$hash {}", format_atomic_expression(operation.argument()))
+        }
+        Expression::If(if_) => format!(
+            "if {}\nthen\n{}\nelse\n{}",
+            format_expression(if_.condition()),
+            indent(&format_expression(if_.then())),
+            indent(&format_expression(if_.else_()))
+        ),
+        Expression::Integer(integer) => format!("{}i", integer.value()),
+        Expression::Lambda(lambda) => format_body(
+            &format!("\\{} ->", lambda.arguments().join(" ")),
+            lambda.body(),
+        ),
+        Expression::Let(let_) => format_let(
+            &let_
+                .definitions()
+                .iter()
+                .map(format_definition)
+                .collect::<Vec<_>>(),
+            let_.expression(),
+        ),
+        Expression::LetError(let_) => format_let(
+            &let_
+                .definitions()
+                .iter()
+                .map(|definition| format_variable_definition(definition, "?="))
+                .collect::<Vec<_>>(),
+            let_.expression(),
+        ),
+        Expression::LetPattern(let_) => format_let(
+            &let_
+                .definitions()
+                .iter()
+                .map(|definition| {
+                    format_body(
+                        &format!("{} =", format_pattern(definition.pattern())),
+                        definition.body(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            let_.expression(),
+        ),
+        Expression::List(list) => format!(
+            "[{}]",
+            list.elements()
+                .iter()
+                .map(|element| match element {
+                    ListElement::Multiple(expression) =>
+                        format!("...{}", format_atomic_expression(expression)),
+                    ListElement::Single(expression) => format_expression(expression),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Expression::ListCase(case) => format!(
+            "case {}\n{}",
+            format_expression(case.argument()),
+            indent(&format!(
+                "{}\n{}",
+                format_body("[] =>", case.empty_alternative()),
+                format_body(
+                    &format!("[{}, ...{}] =>", case.first_name(), case.rest_name()),
+                    case.non_empty_alternative()
+                )
+            ))
+        ),
+        Expression::ListPatternCase(case) => format!(
+            "case {}\n{}",
+            format_expression(case.argument()),
+            indent(
+                &case
+                    .alternatives()
+                    .iter()
+                    .map(|alternative| format_body(
+                        &format!("{} =>", format_list_pattern(alternative.pattern())),
+                        alternative.expression()
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        ),
+        Expression::Map(map) => {
+            if map.entries().is_empty() {
+                "{}".into()
+            } else {
+                format!(
+                    "{{ {} }}",
+                    map.entries()
+                        .iter()
+                        .map(|entry| format!(
+                            "{}: {}",
+                            format_expression(entry.key()),
+                            format_expression(entry.value())
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+        }
+        Expression::None(_) => "None".into(),
+        Expression::Number(number) => format!("{}", number.value()),
+        Expression::Operation(operation) => format_operation(operation),
+        Expression::RecordConstruction(construction) => format!(
+            "{}{{ {} }}",
+            format_type(construction.type_()),
+            format_record_elements(construction.elements())
+        ),
+        Expression::RecordElementOperation(operation) => format!(
+            "{}.{} {}",
+            format_type(operation.type_()),
+            operation.element_name(),
+            format_atomic_expression(operation.argument())
+        ),
+        Expression::RecordUpdate(update) => format!(
+            "{}{{ ...{}, {} }}",
+            format_type(update.type_()),
+            format_atomic_expression(update.argument()),
+            format_record_elements(update.elements())
+        ),
+        Expression::String(string) => format_string(string.value()),
+        Expression::StringInterpolation(interpolation) => format!(
+            "\"{}\"",
+            interpolation
+                .parts()
+                .iter()
+                .map(|part| match part {
+                    StringInterpolationPart::Expression(expression) => {
+                        format!("#{{{}}}", format_expression(expression))
+                    }
+                    StringInterpolationPart::String(string) => escape_string(string),
+                })
+                .collect::<Vec<_>>()
+                .concat()
+        ),
+        Expression::TypeCoercion(coercion) => format!(
+            "({} : {} as {})",
+            format_expression(coercion.argument()),
+            format_type(coercion.from()),
+            format_type(coercion.to())
+        ),
+        Expression::Variable(variable) => variable.name().into(),
+    }
+}
+
+fn format_atomic_expression(expression: &Expression) -> String {
+    match expression {
+        Expression::Application(_)
+        | Expression::Case(_)
+        | Expression::HashOperation(_)
+        | Expression::If(_)
+        | Expression::Lambda(_)
+        | Expression::Let(_)
+        | Expression::LetError(_)
+        | Expression::LetPattern(_)
+        | Expression::ListCase(_)
+        | Expression::ListPatternCase(_)
+        | Expression::Operation(_)
+        | Expression::RecordElementOperation(_) => format!("({})", format_expression(expression)),
+        _ => format_expression(expression),
+    }
+}
+
+fn format_pattern(pattern: &Pattern) -> String {
+    match pattern {
+        Pattern::List(list) => format_list_pattern(list),
+        Pattern::Number(number) => format!("{}", number.value()),
+        Pattern::Record(record) => format!(
+            "{}{{ {} }}",
+            format_type(record.type_()),
+            record
+                .elements()
+                .iter()
+                .map(|(name, pattern)| format!("{} = {}", name, format_pattern(pattern)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Pattern::String(string) => format_string(string.value()),
+        Pattern::Variable(variable) => variable.name().into(),
+    }
+}
+
+fn format_string(string: &str) -> String {
+    format!("\"{}\"", escape_string(string))
+}
+
+fn escape_string(string: &str) -> String {
+    let string = format!("{:?}", string);
+
+    string[1..string.len() - 1].replace("#{", "\\#{")
+}
+
+fn format_list_pattern(list: &ListPattern) -> String {
+    format!(
+        "[{}]",
+        list.elements()
+            .iter()
+            .map(format_pattern)
+            .chain(list.rest_name().map(|name| format!("...{}", name)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn format_let(definitions: &[String], expression: &Expression) -> String {
+    format!(
+        "let\n{}\nin\n{}",
+        indent(&definitions.join("\n")),
+        indent(&format_expression(expression))
+    )
+}
+
+fn format_operation(operation: &Operation) -> String {
+    let (operator, lhs, rhs) = match operation {
+        Operation::Arithmetic(operation) => (
+            match operation.operator() {
+                ArithmeticOperator::Add => "+",
+                ArithmeticOperator::Subtract => "-",
+                ArithmeticOperator::Multiply => "*",
+                ArithmeticOperator::Divide => "/",
+                ArithmeticOperator::IntegerDivide => "//",
+                ArithmeticOperator::Remainder => "%",
+            },
+            operation.lhs(),
+            operation.rhs(),
+        ),
+        Operation::Boolean(operation) => (
+            match operation.operator() {
+                BooleanOperator::And => "&&",
+                BooleanOperator::Or => "||",
+            },
+            operation.lhs(),
+            operation.rhs(),
+        ),
+        Operation::Equality(operation) => (
+            match operation.operator() {
+                EqualityOperator::Equal => "==",
+                EqualityOperator::NotEqual => "/=",
+            },
+            operation.lhs(),
+            operation.rhs(),
+        ),
+        Operation::Order(operation) => (
+            match operation.operator() {
+                OrderOperator::LessThan => "<",
+                OrderOperator::LessThanOrEqual => "<=",
+                OrderOperator::GreaterThan => ">",
+                OrderOperator::GreaterThanOrEqual => ">=",
+            },
+            operation.lhs(),
+            operation.rhs(),
+        ),
+        Operation::Pipe(operation) => ("|>", operation.lhs(), operation.rhs()),
+    };
+
+    format!(
+        "{} {} {}",
+        format_atomic_expression(lhs),
+        operator,
+        format_atomic_expression(rhs)
+    )
+}
+
+fn format_record_elements(elements: &std::collections::BTreeMap<String, Expression>) -> String {
+    elements
+        .iter()
+        .map(|(name, expression)| format!("{} = {}", name, format_expression(expression)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,22 @@
+mod expression;
+mod module;
+mod type_;
+
+pub use module::format_module;
+pub use type_::{format_type, format_type_with_names};
+
+const INDENT: &str = "  ";
+
+fn indent(string: &str) -> String {
+    string
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                line.into()
+            } else {
+                format!("{}{}", INDENT, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
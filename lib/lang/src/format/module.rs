@@ -0,0 +1,218 @@
+use super::{expression::format_definition, indent, type_::format_type};
+use crate::{ast::*, types::Type};
+
+pub fn format_module(module: &Module) -> String {
+    let mut sections = vec![];
+
+    if !module.export().names().is_empty() {
+        sections.push(format!(
+            "export {{\n{}\n}}",
+            indent(&format_exported_names(module.export()))
+        ));
+    }
+
+    if !module.export_foreign().names().is_empty() {
+        sections.push(format!(
+            "export foreign {{\n{}\n}}",
+            indent(&format_names(module.export_foreign().names()))
+        ));
+    }
+
+    if !module.imports().is_empty() {
+        sections.push(
+            module
+                .imports()
+                .iter()
+                .map(|import| format!("import \"{}\"", import.module_interface().path()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+
+    if !module.import_foreigns().is_empty() {
+        sections.push(
+            module
+                .import_foreigns()
+                .iter()
+                .map(|import| {
+                    format!(
+                        "import foreign {}{} : {}",
+                        match import.calling_convention() {
+                            CallingConvention::C => "\"c\" ",
+                            CallingConvention::Native => "",
+                        },
+                        import.name(),
+                        format_type(import.type_())
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+
+    sections.extend(module.type_definitions().iter().map(format_type_definition));
+    sections.extend(module.definitions().iter().map(format_definition));
+
+    sections.join("\n\n") + "\n"
+}
+
+fn format_exported_names(export: &Export) -> String {
+    let mut names = export.names().iter().collect::<Vec<_>>();
+
+    names.sort();
+
+    names
+        .iter()
+        .map(|name| {
+            if export.opaque_names().contains(*name) {
+                format!("opaque {},", name)
+            } else {
+                format!("{},", name)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_names(names: &std::collections::HashSet<String>) -> String {
+    let mut names = names.iter().cloned().collect::<Vec<_>>();
+
+    names.sort();
+
+    names
+        .iter()
+        .map(|name| format!("{},", name))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_type_definition(definition: &TypeDefinition) -> String {
+    match definition.type_() {
+        Type::Record(record) if record.name() == definition.name() => format!(
+            "type {} {{\n{}\n}}",
+            format_type(definition.type_()),
+            indent(
+                &record
+                    .elements()
+                    .iter()
+                    .map(|element| format!(
+                        "{} : {},",
+                        element.name(),
+                        format_type(element.type_())
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        ),
+        type_ => format!("type {} = {}", definition.name(), format_type(type_)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, path::ModulePath, types};
+
+    #[test]
+    fn format_module_with_definitions() {
+        assert_eq!(
+            format_module(&Module::from_definitions_and_type_definitions(
+                vec![TypeDefinition::new(
+                    "Foo",
+                    types::Record::new(
+                        "Foo",
+                        vec![types::RecordElement::new(
+                            "foo",
+                            types::Number::new(SourceInformation::dummy()),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                )],
+                vec![
+                    FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        ArithmeticOperation::new(
+                            ArithmeticOperator::Add,
+                            Variable::new("x", SourceInformation::dummy()),
+                            Number::new(42.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Function::new(
+                            types::Number::new(SourceInformation::dummy()),
+                            types::Number::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    VariableDefinition::new(
+                        "x",
+                        Let::new(
+                            vec![VariableDefinition::new(
+                                "y",
+                                Application::new(
+                                    Variable::new("f", SourceInformation::dummy()),
+                                    Number::new(1.0, SourceInformation::dummy()),
+                                    SourceInformation::dummy(),
+                                ),
+                                types::Number::new(SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            )
+                            .into()],
+                            Variable::new("y", SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ],
+            )),
+            indoc::indoc!(
+                "
+                type Foo {
+                  foo : Number,
+                }
+
+                f : Number -> Number
+                f x = x + 42
+
+                x : Number
+                x =
+                  let
+                    y : Number
+                    y = f 1
+                  in
+                    y
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn format_module_with_opaque_names() {
+        assert_eq!(
+            format_module(&Module::new(
+                ModulePath::dummy(),
+                Export::with_opaque_names(
+                    vec!["Foo".into(), "foo".into()].into_iter().collect(),
+                    vec!["Foo".into()].into_iter().collect(),
+                ),
+                ExportForeign::new(Default::default()),
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+            )),
+            indoc::indoc!(
+                "
+                export {
+                  opaque Foo,
+                  foo,
+                }
+                "
+            )
+        );
+    }
+}
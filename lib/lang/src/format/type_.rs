@@ -0,0 +1,139 @@
+use crate::types::Type;
+
+pub fn format_type(type_: &Type) -> String {
+    format_type_with_names(type_, &|name| name.into())
+}
+
+// Names of records and references are formatted by a given function.
+pub fn format_type_with_names(type_: &Type, format_name: &dyn Fn(&str) -> String) -> String {
+    match type_ {
+        Type::Any(_) => "Any".into(),
+        Type::Boolean(_) => "Boolean".into(),
+        Type::Function(function) => format!(
+            "{} -> {}",
+            match function.argument() {
+                Type::Function(_) => format!(
+                    "({})",
+                    format_type_with_names(function.argument(), format_name)
+                ),
+                _ => format_type_with_names(function.argument(), format_name),
+            },
+            format_type_with_names(function.result(), format_name)
+        ),
+        Type::Integer(_) => "Integer".into(),
+        Type::List(list) => format!("List {}", format_atomic_type(list.element(), format_name)),
+        Type::None(_) => "None".into(),
+        Type::Number(_) => "Number".into(),
+        Type::Parameter(parameter) => parameter.name().into(),
+        Type::Record(record) => {
+            format_type_application(&format_name(record.name()), record.arguments(), format_name)
+        }
+        Type::Reference(reference) => format_type_application(
+            &format_name(reference.name()),
+            reference.arguments(),
+            format_name,
+        ),
+        Type::String(_) => "String".into(),
+        Type::Union(union) => union
+            .types()
+            .iter()
+            .map(|type_| match type_ {
+                Type::Function(_) => format!("({})", format_type_with_names(type_, format_name)),
+                _ => format_type_with_names(type_, format_name),
+            })
+            .collect::<Vec<_>>()
+            .join(" | "),
+        Type::Unknown(_) => "_".into(),
+        Type::Variable(variable) => format!("'{}", variable.id()),
+    }
+}
+
+fn format_type_application(
+    name: &str,
+    arguments: &[Type],
+    format_name: &dyn Fn(&str) -> String,
+) -> String {
+    vec![name.into()]
+        .into_iter()
+        .chain(
+            arguments
+                .iter()
+                .map(|type_| format_atomic_type(type_, format_name)),
+        )
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn format_atomic_type(type_: &Type, format_name: &dyn Fn(&str) -> String) -> String {
+    match type_ {
+        Type::Function(_) | Type::List(_) | Type::Union(_) => {
+            format!("({})", format_type_with_names(type_, format_name))
+        }
+        Type::Record(record) if !record.arguments().is_empty() => {
+            format!("({})", format_type_with_names(type_, format_name))
+        }
+        Type::Reference(reference) if !reference.arguments().is_empty() => {
+            format!("({})", format_type_with_names(type_, format_name))
+        }
+        _ => format_type_with_names(type_, format_name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, types};
+
+    #[test]
+    fn format_function_type() {
+        assert_eq!(
+            format_type(
+                &types::Function::new(
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    types::List::new(
+                        types::Union::new(
+                            vec![
+                                types::Number::new(SourceInformation::dummy()).into(),
+                                types::None::new(SourceInformation::dummy()).into()
+                            ],
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ),
+            "(Number -> Number) -> List (None | Number)"
+        );
+    }
+
+    #[test]
+    fn format_reference_type_with_arguments() {
+        assert_eq!(
+            format_type(
+                &types::List::new(
+                    types::Reference::with_arguments(
+                        "Pair",
+                        vec![
+                            types::Parameter::new("a", SourceInformation::dummy()).into(),
+                            types::List::new(
+                                types::Number::new(SourceInformation::dummy()),
+                                SourceInformation::dummy()
+                            )
+                            .into()
+                        ],
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ),
+            "List (Pair a (List Number))"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{compile::CompileError, debug::SourceInformation};
+use std::{error::Error, fmt::Display, sync::Arc};
+
+#[derive(Debug, PartialEq)]
+pub enum InterpretError {
+    CaseNotMatched(Arc<SourceInformation>),
+    Compile(CompileError),
+    ForeignFunction { name: String, message: String },
+    ForeignFunctionNotFound(String),
+    VariableInitializationCycle(String),
+    VariableNotFound(String),
+}
+
+impl Error for InterpretError {}
+
+impl Display for InterpretError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::CaseNotMatched(source_information) => write!(
+                formatter,
+                "no alternative matched in case expression\n{}",
+                source_information
+            ),
+            Self::Compile(error) => write!(formatter, "{}", error),
+            Self::ForeignFunction { name, message } => {
+                write!(
+                    formatter,
+                    "foreign function \"{}\" failed: {}",
+                    name, message
+                )
+            }
+            Self::ForeignFunctionNotFound(name) => {
+                write!(formatter, "foreign function \"{}\" not registered", name)
+            }
+            Self::VariableInitializationCycle(name) => {
+                write!(formatter, "variable \"{}\" initialized recursively", name)
+            }
+            Self::VariableNotFound(name) => write!(formatter, "variable \"{}\" not found", name),
+        }
+    }
+}
+
+impl From<CompileError> for InterpretError {
+    fn from(error: CompileError) -> Self {
+        Self::Compile(error)
+    }
+}
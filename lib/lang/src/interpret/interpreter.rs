@@ -0,0 +1,1342 @@
+use super::{
+    error::InterpretError,
+    module_context::ModuleContext,
+    scope::Scope,
+    value::{ForeignFunction, Function, List, Record, Value, Variant},
+};
+use crate::{
+    ast::{self, *},
+    compile::{
+        compile_to_typed_module, CompileConfiguration, CompileError, ReferenceTypeResolver,
+        TypeEqualityChecker,
+    },
+    types::{self, Type},
+};
+use std::{cell::RefCell, cmp::Ordering, collections::HashMap, sync::Arc};
+
+pub type ForeignFunctionCallback = dyn Fn(&[Value]) -> Result<Value, String>;
+// Resolvers find foreign functions of the C calling convention by their names
+// and resolved argument and result types.
+pub type ForeignFunctionResolver =
+    dyn Fn(&str, &[Type], &Type) -> Option<Box<ForeignFunctionCallback>>;
+
+enum GlobalVariable {
+    ForeignFunction(ForeignFunction),
+    Function(Function),
+    Variable {
+        definition: Arc<VariableDefinition>,
+        context: Arc<ModuleContext>,
+        state: RefCell<VariableState>,
+    },
+}
+
+enum VariableState {
+    Uninitialized,
+    Initializing,
+    Initialized(Value),
+}
+
+enum Call {
+    Function(Function),
+    Value(Value),
+}
+
+// Global variables are initialized lazily on their first use like compiled
+// ones.
+#[derive(Default)]
+pub struct Interpreter {
+    foreign_functions: HashMap<String, Box<ForeignFunctionCallback>>,
+    foreign_function_resolvers: Vec<Box<ForeignFunctionResolver>>,
+    global_variables: HashMap<String, GlobalVariable>,
+}
+
+impl Interpreter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register_foreign_function(
+        &mut self,
+        name: impl Into<String>,
+        function: impl Fn(&[Value]) -> Result<Value, String> + 'static,
+    ) {
+        self.foreign_functions
+            .insert(name.into(), Box::new(function));
+    }
+
+    // Resolvers are used for foreign functions not registered when modules
+    // importing them are loaded.
+    pub fn register_foreign_function_resolver(
+        &mut self,
+        resolver: impl Fn(&str, &[Type], &Type) -> Option<Box<ForeignFunctionCallback>> + 'static,
+    ) {
+        self.foreign_function_resolvers.push(Box::new(resolver));
+    }
+
+    // Modules need to be loaded after modules they import.
+    pub fn load(
+        &mut self,
+        module: &Module,
+        configuration: Arc<CompileConfiguration>,
+    ) -> Result<ModuleInterface, InterpretError> {
+        let (module, module_interface, _, _, configuration) =
+            compile_to_typed_module(module, configuration, &mut |_, _| {})?;
+
+        self.load_typed_module(&module, &configuration)?;
+
+        Ok(module_interface)
+    }
+
+    pub(super) fn load_typed_module(
+        &mut self,
+        module: &Module,
+        configuration: &CompileConfiguration,
+    ) -> Result<(), InterpretError> {
+        let reference_type_resolver = ReferenceTypeResolver::new(module);
+        let context = Arc::new(ModuleContext::new(
+            reference_type_resolver.clone(),
+            TypeEqualityChecker::new(reference_type_resolver.clone()),
+            &configuration.error_type_configuration.error_type_name,
+        ));
+
+        for declaration in module.import_foreigns() {
+            let function_type = reference_type_resolver
+                .resolve_to_function(declaration.type_())?
+                .ok_or_else(|| {
+                    CompileError::FunctionExpected(declaration.source_information().clone())
+                })?;
+
+            if declaration.calling_convention() == CallingConvention::C
+                && !self
+                    .foreign_functions
+                    .contains_key(declaration.foreign_name())
+            {
+                self.resolve_foreign_function(
+                    declaration.foreign_name(),
+                    &function_type,
+                    &reference_type_resolver,
+                )?;
+            }
+
+            self.global_variables.insert(
+                declaration.name().into(),
+                GlobalVariable::ForeignFunction(ForeignFunction::new(
+                    declaration.foreign_name(),
+                    function_type.arguments().len(),
+                    vec![],
+                )),
+            );
+        }
+
+        for definition in module.definitions() {
+            self.global_variables.insert(
+                definition.name().into(),
+                match definition {
+                    Definition::FunctionDefinition(function_definition) => {
+                        GlobalVariable::Function(Function::new(
+                            function_definition.clone().into(),
+                            context.clone(),
+                            Scope::default(),
+                            vec![],
+                        ))
+                    }
+                    Definition::VariableDefinition(variable_definition) => {
+                        GlobalVariable::Variable {
+                            definition: variable_definition.clone().into(),
+                            context: context.clone(),
+                            state: VariableState::Uninitialized.into(),
+                        }
+                    }
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    fn resolve_foreign_function(
+        &mut self,
+        name: &str,
+        function_type: &types::Function,
+        reference_type_resolver: &ReferenceTypeResolver,
+    ) -> Result<(), InterpretError> {
+        let arguments = function_type
+            .arguments()
+            .into_iter()
+            .map(|type_| reference_type_resolver.resolve(type_))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut result = function_type.result();
+
+        while let Type::Function(function_type) = result {
+            result = function_type.result();
+        }
+
+        let result = reference_type_resolver.resolve(result)?;
+
+        if let Some(function) = self
+            .foreign_function_resolvers
+            .iter()
+            .find_map(|resolve| resolve(name, &arguments, &result))
+        {
+            self.foreign_functions.insert(name.into(), function);
+        }
+
+        Ok(())
+    }
+
+    // Global names are fully qualified with their module paths.
+    pub fn evaluate_variable(&self, name: &str) -> Result<Value, InterpretError> {
+        match self
+            .global_variables
+            .get(name)
+            .ok_or_else(|| InterpretError::VariableNotFound(name.into()))?
+        {
+            GlobalVariable::ForeignFunction(function) => Ok(function.clone().into()),
+            GlobalVariable::Function(function) => Ok(function.clone().into()),
+            GlobalVariable::Variable {
+                definition,
+                context,
+                state,
+            } => {
+                match &*state.borrow() {
+                    VariableState::Uninitialized => {}
+                    VariableState::Initializing => {
+                        return Err(InterpretError::VariableInitializationCycle(name.into()))
+                    }
+                    VariableState::Initialized(value) => return Ok(value.clone()),
+                }
+
+                state.replace(VariableState::Initializing);
+
+                let value = self.evaluate(definition.body(), context, &Scope::default());
+
+                state.replace(match &value {
+                    Ok(value) => VariableState::Initialized(value.clone()),
+                    Err(_) => VariableState::Uninitialized,
+                });
+
+                value
+            }
+        }
+    }
+
+    pub fn apply(&self, function: &Value, argument: Value) -> Result<Value, InterpretError> {
+        match self.call(function.clone(), argument)? {
+            Call::Function(function) => self.evaluate(
+                function.definition().body(),
+                function.context(),
+                &self.bind_arguments(&function),
+            ),
+            Call::Value(value) => Ok(value),
+        }
+    }
+
+    // Expressions in tail positions are evaluated in a loop so that tail calls
+    // do not consume stacks.
+    fn evaluate(
+        &self,
+        expression: &Expression,
+        context: &Arc<ModuleContext>,
+        scope: &Scope,
+    ) -> Result<Value, InterpretError> {
+        let mut function_definition: Arc<FunctionDefinition>;
+        let mut expression = expression;
+        let mut context = context.clone();
+        let mut scope = scope.clone();
+
+        loop {
+            let (function, argument) = match expression {
+                Expression::Application(application) => (
+                    self.evaluate(application.function(), &context, &scope)?,
+                    self.evaluate(application.argument(), &context, &scope)?,
+                ),
+                Expression::Case(case) => {
+                    let argument = self.evaluate(case.argument(), &context, &scope)?;
+                    let alternative = self
+                        .find_alternative(&context, case.alternatives(), &argument)?
+                        .ok_or_else(|| {
+                            InterpretError::CaseNotMatched(case.source_information().clone())
+                        })?;
+
+                    scope = scope.bind_variable(
+                        case.name(),
+                        self.narrow(&context, argument, alternative.type_())?,
+                    );
+                    expression = alternative.expression();
+                    continue;
+                }
+                Expression::If(if_) => {
+                    expression = if self.evaluate_boolean(if_.condition(), &context, &scope)? {
+                        if_.then()
+                    } else {
+                        if_.else_()
+                    };
+                    continue;
+                }
+                Expression::Let(let_) => {
+                    scope = self.bind_definitions(let_.definitions(), &context, scope)?;
+                    expression = let_.expression();
+                    continue;
+                }
+                Expression::LetError(let_) => {
+                    let error_type = types::Reference::new(
+                        context.error_type_name(),
+                        let_.source_information().clone(),
+                    )
+                    .into();
+
+                    for definition in let_.definitions() {
+                        let value = self.evaluate(definition.body(), &context, &scope)?;
+
+                        if self.matches(&context, &value, &error_type)? {
+                            return Ok(value);
+                        }
+
+                        scope = scope.bind_variable(definition.name(), value);
+                    }
+
+                    expression = let_.expression();
+                    continue;
+                }
+                Expression::ListCase(case) => {
+                    let list = match self.evaluate(case.argument(), &context, &scope)? {
+                        Value::List(list) => list,
+                        _ => unreachable!(),
+                    };
+
+                    expression = if let Some((first, rest)) = list.first_rest() {
+                        scope = scope
+                            .bind_variable(case.first_name(), first.clone())
+                            .bind_variable(case.rest_name(), rest.clone().into());
+
+                        case.non_empty_alternative()
+                    } else {
+                        case.empty_alternative()
+                    };
+                    continue;
+                }
+                Expression::Operation(Operation::Pipe(operation)) => {
+                    let argument = self.evaluate(operation.lhs(), &context, &scope)?;
+
+                    (self.evaluate(operation.rhs(), &context, &scope)?, argument)
+                }
+                Expression::TypeCoercion(coercion)
+                    if !self.is_tagged_on_coercion(&context, coercion.from())? =>
+                {
+                    expression = coercion.argument();
+                    continue;
+                }
+                _ => return self.evaluate_non_tail(expression, &context, &scope),
+            };
+
+            match self.call(function, argument)? {
+                Call::Function(function) => {
+                    scope = self.bind_arguments(&function);
+                    context = function.context().clone();
+                    function_definition = function.definition().clone();
+                    expression = function_definition.body();
+                }
+                Call::Value(value) => return Ok(value),
+            }
+        }
+    }
+
+    fn evaluate_non_tail(
+        &self,
+        expression: &Expression,
+        context: &Arc<ModuleContext>,
+        scope: &Scope,
+    ) -> Result<Value, InterpretError> {
+        Ok(match expression {
+            Expression::Boolean(boolean) => boolean.value().into(),
+            Expression::List(list) => self.evaluate_list(list, context, scope)?.into(),
+            Expression::None(_) => Value::None,
+            Expression::Integer(integer) => integer.value().into(),
+            Expression::Number(number) => number.value().into(),
+            Expression::Operation(operation) => {
+                self.evaluate_operation(operation, context, scope)?
+            }
+            Expression::RecordConstruction(construction) => Record::new(
+                context
+                    .reference_type_resolver()
+                    .resolve_to_record(construction.type_())?
+                    .unwrap()
+                    .name(),
+                construction
+                    .elements()
+                    .iter()
+                    .map(|(name, expression)| {
+                        Ok((name.clone(), self.evaluate(expression, context, scope)?))
+                    })
+                    .collect::<Result<_, InterpretError>>()?,
+            )
+            .into(),
+            Expression::RecordElementOperation(operation) => {
+                match self.evaluate(operation.argument(), context, scope)? {
+                    Value::Record(record) => record.elements()[operation.element_name()].clone(),
+                    _ => unreachable!(),
+                }
+            }
+            Expression::String(string) => string.value().into(),
+            Expression::TypeCoercion(coercion) => self.coerce(
+                context,
+                self.evaluate(coercion.argument(), context, scope)?,
+                coercion.from(),
+                coercion.to(),
+            )?,
+            Expression::Variable(variable) => {
+                if let Some(value) = scope.find(variable.name()) {
+                    value
+                } else {
+                    self.evaluate_variable(variable.name())?
+                }
+            }
+            Expression::Application(_)
+            | Expression::Array(_)
+            | Expression::Case(_)
+            | Expression::HashOperation(_)
+            | Expression::If(_)
+            | Expression::Lambda(_)
+            | Expression::Let(_)
+            | Expression::LetError(_)
+            | Expression::LetPattern(_)
+            | Expression::ListCase(_)
+            | Expression::ListPatternCase(_)
+            | Expression::Map(_)
+            | Expression::RecordUpdate(_)
+            | Expression::StringInterpolation(_) => unreachable!(),
+        })
+    }
+
+    fn evaluate_list(
+        &self,
+        list: &ast::List,
+        context: &Arc<ModuleContext>,
+        scope: &Scope,
+    ) -> Result<List, InterpretError> {
+        let elements = list
+            .elements()
+            .iter()
+            .map(|element| {
+                Ok(match element {
+                    ListElement::Multiple(expression) => {
+                        (true, self.evaluate(expression, context, scope)?)
+                    }
+                    ListElement::Single(expression) => {
+                        (false, self.evaluate(expression, context, scope)?)
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, InterpretError>>()?;
+
+        Ok(elements
+            .into_iter()
+            .rev()
+            .fold(List::default(), |list, (multiple, value)| {
+                if !multiple {
+                    list.prepend(value)
+                } else if let Value::List(elements) = value {
+                    if list.first_rest().is_none() {
+                        elements
+                    } else {
+                        List::concatenate(elements.iter().cloned().collect(), list)
+                    }
+                } else {
+                    unreachable!()
+                }
+            }))
+    }
+
+    fn evaluate_operation(
+        &self,
+        operation: &Operation,
+        context: &Arc<ModuleContext>,
+        scope: &Scope,
+    ) -> Result<Value, InterpretError> {
+        Ok(match operation {
+            Operation::Arithmetic(operation) => match (
+                self.evaluate(operation.lhs(), context, scope)?,
+                self.evaluate(operation.rhs(), context, scope)?,
+            ) {
+                (Value::Number(lhs), Value::Number(rhs)) => match operation.operator() {
+                    ArithmeticOperator::Add => lhs + rhs,
+                    ArithmeticOperator::Subtract => lhs - rhs,
+                    ArithmeticOperator::Multiply => lhs * rhs,
+                    ArithmeticOperator::Divide => lhs / rhs,
+                    ArithmeticOperator::IntegerDivide => (lhs / rhs).trunc(),
+                    ArithmeticOperator::Remainder => lhs % rhs,
+                }
+                .into(),
+                // Integer operations wrap around on overflow and result in zero
+                // on division by zero.
+                (Value::Integer(lhs), Value::Integer(rhs)) => match operation.operator() {
+                    ArithmeticOperator::Add => lhs.wrapping_add(rhs),
+                    ArithmeticOperator::Subtract => lhs.wrapping_sub(rhs),
+                    ArithmeticOperator::Multiply => lhs.wrapping_mul(rhs),
+                    ArithmeticOperator::IntegerDivide => {
+                        if rhs == 0 {
+                            0
+                        } else {
+                            lhs.wrapping_div(rhs)
+                        }
+                    }
+                    ArithmeticOperator::Remainder => {
+                        if rhs == 0 {
+                            0
+                        } else {
+                            lhs.wrapping_rem(rhs)
+                        }
+                    }
+                    ArithmeticOperator::Divide => {
+                        return Err(CompileError::IntegerDivideOperatorExpected(
+                            operation.source_information().clone(),
+                        )
+                        .into())
+                    }
+                }
+                .into(),
+                _ => unreachable!(),
+            },
+            Operation::Boolean(operation) => {
+                let lhs = self.evaluate_boolean(operation.lhs(), context, scope)?;
+
+                match operation.operator() {
+                    BooleanOperator::And => {
+                        lhs && self.evaluate_boolean(operation.rhs(), context, scope)?
+                    }
+                    BooleanOperator::Or => {
+                        lhs || self.evaluate_boolean(operation.rhs(), context, scope)?
+                    }
+                }
+                .into()
+            }
+            Operation::Equality(operation) => {
+                let equal = self.evaluate(operation.lhs(), context, scope)?
+                    == self.evaluate(operation.rhs(), context, scope)?;
+
+                match operation.operator() {
+                    EqualityOperator::Equal => equal,
+                    EqualityOperator::NotEqual => !equal,
+                }
+                .into()
+            }
+            Operation::Order(operation) => {
+                let ordering = match (
+                    self.evaluate(operation.lhs(), context, scope)?,
+                    self.evaluate(operation.rhs(), context, scope)?,
+                ) {
+                    (Value::Number(lhs), Value::Number(rhs)) => lhs.partial_cmp(&rhs),
+                    (Value::Integer(lhs), Value::Integer(rhs)) => Some(lhs.cmp(&rhs)),
+                    _ => unreachable!(),
+                };
+
+                match operation.operator() {
+                    OrderOperator::LessThan => matches!(ordering, Some(Ordering::Less)),
+                    OrderOperator::LessThanOrEqual => {
+                        matches!(ordering, Some(Ordering::Less) | Some(Ordering::Equal))
+                    }
+                    OrderOperator::GreaterThan => matches!(ordering, Some(Ordering::Greater)),
+                    OrderOperator::GreaterThanOrEqual => {
+                        matches!(ordering, Some(Ordering::Greater) | Some(Ordering::Equal))
+                    }
+                }
+                .into()
+            }
+            Operation::Pipe(_) => unreachable!(),
+        })
+    }
+
+    fn evaluate_boolean(
+        &self,
+        expression: &Expression,
+        context: &Arc<ModuleContext>,
+        scope: &Scope,
+    ) -> Result<bool, InterpretError> {
+        match self.evaluate(expression, context, scope)? {
+            Value::Boolean(boolean) => Ok(boolean),
+            _ => unreachable!(),
+        }
+    }
+
+    fn call(&self, function: Value, argument: Value) -> Result<Call, InterpretError> {
+        Ok(match function {
+            Value::ForeignFunction(function) => {
+                let arguments = function
+                    .arguments()
+                    .iter()
+                    .cloned()
+                    .chain(vec![argument])
+                    .collect::<Vec<_>>();
+
+                if arguments.len() < function.arity() {
+                    Call::Value(
+                        ForeignFunction::new(function.name(), function.arity(), arguments).into(),
+                    )
+                } else {
+                    Call::Value(
+                        self.foreign_functions.get(function.name()).ok_or_else(|| {
+                            InterpretError::ForeignFunctionNotFound(function.name().into())
+                        })?(&arguments)
+                        .map_err(|message| InterpretError::ForeignFunction {
+                            name: function.name().into(),
+                            message,
+                        })?,
+                    )
+                }
+            }
+            Value::Function(function) => {
+                let function = Function::new(
+                    function.definition().clone(),
+                    function.context().clone(),
+                    function.scope().clone(),
+                    function
+                        .arguments()
+                        .iter()
+                        .cloned()
+                        .chain(vec![argument])
+                        .collect(),
+                );
+
+                if function.arguments().len() < function.definition().arguments().len() {
+                    Call::Value(function.into())
+                } else {
+                    Call::Function(function)
+                }
+            }
+            _ => unreachable!(),
+        })
+    }
+
+    fn bind_arguments(&self, function: &Function) -> Scope {
+        function
+            .definition()
+            .arguments()
+            .iter()
+            .zip(function.arguments())
+            .fold(function.scope().clone(), |scope, (name, value)| {
+                scope.bind_variable(name, value.clone())
+            })
+    }
+
+    // Functions defined next to each other can refer to each other
+    // recursively.
+    fn bind_definitions(
+        &self,
+        definitions: &[Definition],
+        context: &Arc<ModuleContext>,
+        mut scope: Scope,
+    ) -> Result<Scope, InterpretError> {
+        let mut functions = vec![];
+
+        for definition in definitions {
+            match definition {
+                Definition::FunctionDefinition(function_definition) => {
+                    functions.push(function_definition.clone().into());
+                }
+                Definition::VariableDefinition(variable_definition) => {
+                    if !functions.is_empty() {
+                        scope =
+                            scope.bind_functions(std::mem::take(&mut functions), context.clone());
+                    }
+
+                    let value = self.evaluate(variable_definition.body(), context, &scope)?;
+
+                    scope = scope.bind_variable(variable_definition.name(), value);
+                }
+            }
+        }
+
+        Ok(if functions.is_empty() {
+            scope
+        } else {
+            scope.bind_functions(functions, context.clone())
+        })
+    }
+
+    fn find_alternative<'a>(
+        &self,
+        context: &ModuleContext,
+        alternatives: &'a [Alternative],
+        value: &Value,
+    ) -> Result<Option<&'a Alternative>, InterpretError> {
+        for alternative in alternatives {
+            if self.matches(context, value, alternative.type_())? {
+                return Ok(Some(alternative));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn matches(
+        &self,
+        context: &ModuleContext,
+        value: &Value,
+        type_: &Type,
+    ) -> Result<bool, InterpretError> {
+        let type_ = context.reference_type_resolver().resolve(type_)?;
+
+        Ok(match &type_ {
+            Type::Any(_) => true,
+            Type::Boolean(_) => matches!(value, Value::Boolean(_)),
+            Type::Function(_) | Type::List(_) => match value {
+                Value::Variant(variant) => context
+                    .type_equality_checker()
+                    .equal(variant.type_(), &type_)?,
+                Value::ForeignFunction(_) | Value::Function(_) => {
+                    matches!(type_, Type::Function(_))
+                }
+                Value::List(_) => matches!(type_, Type::List(_)),
+                _ => false,
+            },
+            Type::Integer(_) => matches!(value, Value::Integer(_)),
+            Type::None(_) => matches!(value, Value::None),
+            Type::Number(_) => matches!(value, Value::Number(_)),
+            Type::Record(record_type) => {
+                matches!(value, Value::Record(record) if record.name() == record_type.name())
+            }
+            Type::String(_) => matches!(value, Value::String(_)),
+            Type::Union(union) => {
+                for type_ in union.types() {
+                    if self.matches(context, value, type_)? {
+                        return Ok(true);
+                    }
+                }
+
+                false
+            }
+            Type::Parameter(_) | Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => {
+                unreachable!()
+            }
+        })
+    }
+
+    fn narrow(
+        &self,
+        context: &ModuleContext,
+        value: Value,
+        type_: &Type,
+    ) -> Result<Value, InterpretError> {
+        Ok(match value {
+            Value::Variant(variant)
+                if !context.reference_type_resolver().is_any(type_)?
+                    && !context.reference_type_resolver().is_union(type_)? =>
+            {
+                variant.value().clone()
+            }
+            value => value,
+        })
+    }
+
+    fn coerce(
+        &self,
+        context: &ModuleContext,
+        value: Value,
+        from: &Type,
+        to: &Type,
+    ) -> Result<Value, InterpretError> {
+        let from = context.reference_type_resolver().resolve(from)?;
+        let to = context.reference_type_resolver().resolve(to)?;
+
+        Ok(match (&from, &to, value) {
+            (Type::List(from_list), Type::List(to_list), Value::List(list)) => {
+                if context
+                    .type_equality_checker()
+                    .equal(from_list.element(), to_list.element())?
+                {
+                    list.into()
+                } else {
+                    List::new(
+                        list.iter()
+                            .map(|element| {
+                                self.coerce(
+                                    context,
+                                    element.clone(),
+                                    from_list.element(),
+                                    to_list.element(),
+                                )
+                            })
+                            .collect::<Result<_, _>>()?,
+                    )
+                    .into()
+                }
+            }
+            (Type::Function(_), Type::Any(_), value)
+            | (Type::Function(_), Type::Union(_), value)
+            | (Type::List(_), Type::Any(_), value)
+            | (Type::List(_), Type::Union(_), value) => Variant::new(from.clone(), value).into(),
+            (_, _, value) => value,
+        })
+    }
+
+    fn is_tagged_on_coercion(
+        &self,
+        context: &ModuleContext,
+        from: &Type,
+    ) -> Result<bool, InterpretError> {
+        Ok(context.reference_type_resolver().is_function(from)?
+            || context.reference_type_resolver().is_list(from)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compile::COMPILE_CONFIGURATION, debug::SourceInformation, path::ModulePath};
+    use pretty_assertions::assert_eq;
+
+    fn evaluate(definitions: Vec<Definition>, name: &str) -> Result<Value, InterpretError> {
+        let mut interpreter = Interpreter::new();
+
+        interpreter.load(
+            &Module::from_definitions(definitions),
+            COMPILE_CONFIGURATION.clone(),
+        )?;
+
+        interpreter.evaluate_variable(&format!("().{}", name))
+    }
+
+    fn number_function_type() -> types::Function {
+        types::Function::new(
+            types::Number::new(SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+    }
+
+    #[test]
+    fn evaluate_arithmetic_operation() {
+        assert_eq!(
+            evaluate(
+                vec![VariableDefinition::new(
+                    "x",
+                    ArithmeticOperation::new(
+                        ArithmeticOperator::Add,
+                        Number::new(40.0, SourceInformation::dummy()),
+                        Number::new(2.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()],
+                "x"
+            ),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn apply_function() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .load(
+                &Module::from_definitions(vec![FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    ArithmeticOperation::new(
+                        ArithmeticOperator::Multiply,
+                        Variable::new("x", SourceInformation::dummy()),
+                        Number::new(2.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    number_function_type(),
+                    SourceInformation::dummy(),
+                )
+                .into()]),
+                COMPILE_CONFIGURATION.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            interpreter.apply(&interpreter.evaluate_variable("().f").unwrap(), 21.0.into()),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_deep_tail_recursion() {
+        assert_eq!(
+            evaluate(
+                vec![
+                    FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        If::new(
+                            EqualityOperation::new(
+                                EqualityOperator::Equal,
+                                Variable::new("x", SourceInformation::dummy()),
+                                Number::new(0.0, SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            ),
+                            Number::new(42.0, SourceInformation::dummy()),
+                            Application::new(
+                                Variable::new("f", SourceInformation::dummy()),
+                                ArithmeticOperation::new(
+                                    ArithmeticOperator::Subtract,
+                                    Variable::new("x", SourceInformation::dummy()),
+                                    Number::new(1.0, SourceInformation::dummy()),
+                                    SourceInformation::dummy(),
+                                ),
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy(),
+                        ),
+                        number_function_type(),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    VariableDefinition::new(
+                        "x",
+                        Application::new(
+                            Variable::new("f", SourceInformation::dummy()),
+                            Number::new(1e6, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ],
+                "x"
+            ),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_closure() {
+        assert_eq!(
+            evaluate(
+                vec![
+                    FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        Let::new(
+                            vec![FunctionDefinition::new(
+                                "g",
+                                vec!["y".into()],
+                                ArithmeticOperation::new(
+                                    ArithmeticOperator::Add,
+                                    Variable::new("x", SourceInformation::dummy()),
+                                    Variable::new("y", SourceInformation::dummy()),
+                                    SourceInformation::dummy(),
+                                ),
+                                number_function_type(),
+                                SourceInformation::dummy(),
+                            )
+                            .into()],
+                            Variable::new("g", SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Function::new(
+                            types::Number::new(SourceInformation::dummy()),
+                            number_function_type(),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    VariableDefinition::new(
+                        "x",
+                        Application::new(
+                            Application::new(
+                                Variable::new("f", SourceInformation::dummy()),
+                                Number::new(40.0, SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            ),
+                            Number::new(2.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ],
+                "x"
+            ),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_case_expression() {
+        let union_type = types::Union::new(
+            vec![
+                types::Number::new(SourceInformation::dummy()).into(),
+                types::None::new(SourceInformation::dummy()).into(),
+            ],
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            evaluate(
+                vec![
+                    VariableDefinition::new(
+                        "x",
+                        Number::new(42.0, SourceInformation::dummy()),
+                        union_type,
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    VariableDefinition::new(
+                        "y",
+                        Case::new(
+                            "z",
+                            Variable::new("x", SourceInformation::dummy()),
+                            vec![
+                                Alternative::new(
+                                    types::None::new(SourceInformation::dummy()),
+                                    Number::new(0.0, SourceInformation::dummy()),
+                                ),
+                                Alternative::new(
+                                    types::Number::new(SourceInformation::dummy()),
+                                    Variable::new("z", SourceInformation::dummy()),
+                                ),
+                            ],
+                            SourceInformation::dummy(),
+                        ),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ],
+                "y"
+            ),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_record() {
+        let record_type = types::Record::new(
+            "Foo",
+            vec![types::RecordElement::new(
+                "foo",
+                types::Number::new(SourceInformation::dummy()),
+            )],
+            SourceInformation::dummy(),
+        );
+        let reference_type = types::Reference::new("Foo", SourceInformation::dummy());
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .load(
+                &Module::from_definitions_and_type_definitions(
+                    vec![TypeDefinition::new("Foo", record_type)],
+                    vec![
+                        VariableDefinition::new(
+                            "x",
+                            RecordConstruction::new(
+                                reference_type.clone(),
+                                vec![(
+                                    "foo".into(),
+                                    Number::new(42.0, SourceInformation::dummy()).into(),
+                                )]
+                                .into_iter()
+                                .collect(),
+                                SourceInformation::dummy(),
+                            ),
+                            reference_type.clone(),
+                            SourceInformation::dummy(),
+                        )
+                        .into(),
+                        VariableDefinition::new(
+                            "y",
+                            RecordElementOperation::new(
+                                reference_type,
+                                "foo",
+                                Variable::new("x", SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            ),
+                            types::Number::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        )
+                        .into(),
+                    ],
+                ),
+                COMPILE_CONFIGURATION.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(interpreter.evaluate_variable("().y"), Ok(42.0.into()));
+    }
+
+    #[test]
+    fn evaluate_list_case() {
+        let list_type = types::List::new(
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            evaluate(
+                vec![
+                    FunctionDefinition::new(
+                        "sum",
+                        vec!["xs".into()],
+                        ListCase::new(
+                            Variable::new("xs", SourceInformation::dummy()),
+                            list_type.clone(),
+                            "y",
+                            "ys",
+                            Number::new(0.0, SourceInformation::dummy()),
+                            ArithmeticOperation::new(
+                                ArithmeticOperator::Add,
+                                Variable::new("y", SourceInformation::dummy()),
+                                Application::new(
+                                    Variable::new("sum", SourceInformation::dummy()),
+                                    Variable::new("ys", SourceInformation::dummy()),
+                                    SourceInformation::dummy(),
+                                ),
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Function::new(
+                            list_type,
+                            types::Number::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    VariableDefinition::new(
+                        "x",
+                        Application::new(
+                            Variable::new("sum", SourceInformation::dummy()),
+                            ast::List::new(
+                                vec![
+                                    ListElement::Single(
+                                        Number::new(40.0, SourceInformation::dummy()).into()
+                                    ),
+                                    ListElement::Multiple(
+                                        ast::List::new(
+                                            vec![
+                                                ListElement::Single(
+                                                    Number::new(1.0, SourceInformation::dummy())
+                                                        .into()
+                                                ),
+                                                ListElement::Single(
+                                                    Number::new(1.0, SourceInformation::dummy())
+                                                        .into()
+                                                ),
+                                            ],
+                                            SourceInformation::dummy(),
+                                        )
+                                        .into()
+                                    ),
+                                ],
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ],
+                "x"
+            ),
+            Ok(42.0.into())
+        );
+    }
+
+    #[test]
+    fn evaluate_let_error() {
+        let error_type = types::Reference::new("Error", SourceInformation::dummy());
+        let union_type = types::Union::new(
+            vec![
+                types::Number::new(SourceInformation::dummy()).into(),
+                error_type.clone().into(),
+            ],
+            SourceInformation::dummy(),
+        );
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .load(
+                &Module::from_definitions_and_type_definitions(
+                    vec![TypeDefinition::new(
+                        "Error",
+                        types::Record::new("Error", vec![], SourceInformation::dummy()),
+                    )],
+                    vec![
+                        VariableDefinition::new(
+                            "x",
+                            RecordConstruction::new(
+                                error_type.clone(),
+                                Default::default(),
+                                SourceInformation::dummy(),
+                            ),
+                            union_type.clone(),
+                            SourceInformation::dummy(),
+                        )
+                        .into(),
+                        VariableDefinition::new(
+                            "y",
+                            LetError::new(
+                                vec![VariableDefinition::new(
+                                    "z",
+                                    Variable::new("x", SourceInformation::dummy()),
+                                    types::Number::new(SourceInformation::dummy()),
+                                    SourceInformation::dummy(),
+                                )],
+                                Variable::new("z", SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            ),
+                            union_type,
+                            SourceInformation::dummy(),
+                        )
+                        .into(),
+                    ],
+                ),
+                COMPILE_CONFIGURATION.clone(),
+            )
+            .unwrap();
+
+        assert!(matches!(
+            interpreter.evaluate_variable("().y"),
+            Ok(Value::Record(record)) if record.name() == "().Error"
+        ));
+    }
+
+    #[test]
+    fn call_foreign_function() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter.register_foreign_function("g", |arguments| match arguments {
+            [Value::Number(number)] => Ok((number * 2.0).into()),
+            _ => Err("number expected".into()),
+        });
+
+        interpreter
+            .load(
+                &Module::new(
+                    ModulePath::dummy(),
+                    Export::new(Default::default()),
+                    ExportForeign::new(Default::default()),
+                    vec![],
+                    vec![ImportForeign::new(
+                        "f",
+                        "g",
+                        CallingConvention::Native,
+                        number_function_type(),
+                        SourceInformation::dummy(),
+                    )],
+                    vec![],
+                    vec![VariableDefinition::new(
+                        "x",
+                        Application::new(
+                            Variable::new("f", SourceInformation::dummy()),
+                            Number::new(21.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                ),
+                COMPILE_CONFIGURATION.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(interpreter.evaluate_variable("().x"), Ok(42.0.into()));
+    }
+
+    #[test]
+    fn call_resolved_foreign_function() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter.register_foreign_function_resolver(|name, arguments, result| {
+            if name == "g"
+                && arguments == [types::Number::new(SourceInformation::dummy()).into()]
+                && result == &types::Number::new(SourceInformation::dummy()).into()
+            {
+                Some(Box::new(|arguments: &[Value]| match arguments {
+                    [Value::Number(number)] => Ok((number * 2.0).into()),
+                    _ => Err("number expected".into()),
+                }))
+            } else {
+                None
+            }
+        });
+
+        interpreter
+            .load(
+                &Module::new(
+                    ModulePath::dummy(),
+                    Export::new(Default::default()),
+                    ExportForeign::new(Default::default()),
+                    vec![],
+                    vec![ImportForeign::new(
+                        "f",
+                        "g",
+                        CallingConvention::C,
+                        number_function_type(),
+                        SourceInformation::dummy(),
+                    )],
+                    vec![],
+                    vec![VariableDefinition::new(
+                        "x",
+                        Application::new(
+                            Variable::new("f", SourceInformation::dummy()),
+                            Number::new(21.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                ),
+                COMPILE_CONFIGURATION.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(interpreter.evaluate_variable("().x"), Ok(42.0.into()));
+    }
+
+    #[test]
+    fn fail_to_call_unresolved_foreign_function() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter.register_foreign_function_resolver(|_, _, _| None);
+
+        interpreter
+            .load(
+                &Module::new(
+                    ModulePath::dummy(),
+                    Export::new(Default::default()),
+                    ExportForeign::new(Default::default()),
+                    vec![],
+                    vec![ImportForeign::new(
+                        "f",
+                        "g",
+                        CallingConvention::C,
+                        number_function_type(),
+                        SourceInformation::dummy(),
+                    )],
+                    vec![],
+                    vec![VariableDefinition::new(
+                        "x",
+                        Application::new(
+                            Variable::new("f", SourceInformation::dummy()),
+                            Number::new(21.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                ),
+                COMPILE_CONFIGURATION.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            interpreter.evaluate_variable("().x"),
+            Err(InterpretError::ForeignFunctionNotFound("g".into()))
+        );
+    }
+}
@@ -0,0 +1,11 @@
+mod error;
+mod interpreter;
+mod module_context;
+mod repl;
+mod scope;
+mod value;
+
+pub use error::InterpretError;
+pub use interpreter::{ForeignFunctionCallback, ForeignFunctionResolver, Interpreter};
+pub use repl::Repl;
+pub use value::{ForeignFunction, ForeignValue, Function, List, Record, Value, Variant};
@@ -0,0 +1,36 @@
+use crate::compile::{ReferenceTypeResolver, TypeEqualityChecker};
+use std::sync::Arc;
+
+// Module contexts resolve types in modules where functions are defined.
+#[derive(Debug)]
+pub struct ModuleContext {
+    reference_type_resolver: Arc<ReferenceTypeResolver>,
+    type_equality_checker: Arc<TypeEqualityChecker>,
+    error_type_name: String,
+}
+
+impl ModuleContext {
+    pub fn new(
+        reference_type_resolver: Arc<ReferenceTypeResolver>,
+        type_equality_checker: Arc<TypeEqualityChecker>,
+        error_type_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            reference_type_resolver,
+            type_equality_checker,
+            error_type_name: error_type_name.into(),
+        }
+    }
+
+    pub fn reference_type_resolver(&self) -> &ReferenceTypeResolver {
+        &self.reference_type_resolver
+    }
+
+    pub fn type_equality_checker(&self) -> &TypeEqualityChecker {
+        &self.type_equality_checker
+    }
+
+    pub fn error_type_name(&self) -> &str {
+        &self.error_type_name
+    }
+}
@@ -0,0 +1,500 @@
+use super::{error::InterpretError, interpreter::Interpreter, value::Value};
+use crate::{
+    ast::*,
+    compile::{compile_to_typed_module, CompileConfiguration},
+    format::format_type,
+    path::ModulePath,
+    types::{self, Type},
+};
+use std::{collections::HashMap, sync::Arc};
+
+const RESULT_NAME: &str = "$result";
+
+// REPLs keep definitions of all inputs in a module and load it into an
+// interpreter again on every input.
+pub struct Repl {
+    module_path: ModulePath,
+    imports: Vec<Import>,
+    type_definitions: Vec<TypeDefinition>,
+    definitions: Vec<Definition>,
+    interpreter: Interpreter,
+    configuration: Arc<CompileConfiguration>,
+}
+
+impl Repl {
+    pub fn new(
+        module_path: ModulePath,
+        imports: Vec<Import>,
+        interpreter: Interpreter,
+        configuration: Arc<CompileConfiguration>,
+    ) -> Self {
+        let mut configuration = configuration.as_ref().clone();
+
+        configuration.main_module_configuration = None;
+
+        Self {
+            module_path,
+            imports,
+            type_definitions: vec![],
+            definitions: vec![],
+            interpreter,
+            configuration: configuration.into(),
+        }
+    }
+
+    // Results of expressions are formatted with their types while definitions
+    // do not output anything.
+    pub fn evaluate(&mut self, input: &ReplInput) -> Result<Option<String>, InterpretError> {
+        let mut type_definitions = self.type_definitions.clone();
+        let mut definitions = self.definitions.clone();
+
+        match input {
+            ReplInput::Definition(definition) => {
+                definitions.retain(|other| other.name() != definition.name());
+                definitions.push(definition.clone());
+            }
+            ReplInput::Expression(expression) => definitions.push(
+                VariableDefinition::new(
+                    RESULT_NAME,
+                    expression.clone(),
+                    types::Unknown::new(expression.source_information().clone()),
+                    expression.source_information().clone(),
+                )
+                .into(),
+            ),
+            ReplInput::TypeDefinition(type_definition) => {
+                type_definitions.retain(|other| other.name() != type_definition.name());
+                type_definitions.push(type_definition.clone());
+            }
+        }
+
+        let (module, module_interface, _, global_names, configuration) = compile_to_typed_module(
+            &Module::new(
+                self.module_path.clone(),
+                Export::new(Default::default()),
+                ExportForeign::new(Default::default()),
+                self.imports.clone(),
+                vec![],
+                type_definitions.clone(),
+                definitions.clone(),
+            ),
+            self.configuration.clone(),
+            &mut |_, _| {},
+        )?;
+
+        self.interpreter
+            .load_typed_module(&module, &configuration)?;
+
+        if let ReplInput::Expression(_) = input {
+            let name = self.module_path.fully_qualify_name(RESULT_NAME);
+            let names = global_names
+                .iter()
+                .map(|(name, global_name)| (global_name.clone(), name.clone()))
+                .collect::<HashMap<_, _>>();
+
+            let value = self.interpreter.evaluate_variable(&name)?;
+            // Types in module interfaces keep their type arguments.
+            let type_ = &module_interface.variables()[&name];
+
+            Ok(Some(format!(
+                "{} : {}",
+                format_value(&value, &names),
+                format_type(&unqualify_type(type_, &names))
+            )))
+        } else {
+            self.type_definitions = type_definitions;
+            self.definitions = definitions;
+
+            Ok(None)
+        }
+    }
+}
+
+fn format_value(value: &Value, names: &HashMap<String, String>) -> String {
+    match value {
+        Value::Boolean(boolean) => if *boolean { "True" } else { "False" }.into(),
+        Value::ForeignFunction(_) | Value::Function(_) => "<function>".into(),
+        Value::ForeignValue(_) => "<foreign>".into(),
+        Value::List(list) => format!(
+            "[{}]",
+            list.iter()
+                .map(|element| format_value(element, names))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::None => "None".into(),
+        Value::Integer(integer) => format!("{}i", integer),
+        Value::Number(number) => format!("{}", number),
+        Value::Record(record) => {
+            let name = unqualify_name(record.name(), names);
+
+            if record.elements().is_empty() {
+                name.into()
+            } else {
+                format!(
+                    "{}{{ {} }}",
+                    name,
+                    record
+                        .elements()
+                        .iter()
+                        .map(|(key, value)| format!("{} = {}", key, format_value(value, names)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+        }
+        Value::String(string) => format!("{:?}", string),
+        Value::Variant(variant) => format_value(variant.value(), names),
+    }
+}
+
+fn unqualify_type(type_: &Type, names: &HashMap<String, String>) -> Type {
+    type_
+        .transform_types(&mut |type_| -> Result<_, ()> {
+            Ok(match type_ {
+                Type::Record(record) => types::Record::with_arguments(
+                    unqualify_name(record.name(), names),
+                    record.arguments().to_vec(),
+                    record.elements().to_vec(),
+                    record.source_information().clone(),
+                )
+                .into(),
+                Type::Reference(reference) => types::Reference::with_arguments(
+                    unqualify_name(reference.name(), names),
+                    reference.arguments().to_vec(),
+                    reference.source_information().clone(),
+                )
+                .into(),
+                _ => type_.clone(),
+            })
+        })
+        .unwrap()
+}
+
+fn unqualify_name<'a>(name: &'a str, names: &'a HashMap<String, String>) -> &'a str {
+    names.get(name).map(|name| name.as_str()).unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compile::COMPILE_CONFIGURATION, parse::parse_repl_input};
+    use pretty_assertions::assert_eq;
+
+    fn create_repl() -> Repl {
+        Repl::new(
+            ModulePath::dummy(),
+            vec![],
+            Interpreter::new(),
+            COMPILE_CONFIGURATION.clone(),
+        )
+    }
+
+    fn evaluate(repl: &mut Repl, source: &str) -> Result<Option<String>, InterpretError> {
+        repl.evaluate(&parse_repl_input(source, "").unwrap())
+    }
+
+    #[test]
+    fn evaluate_expression() {
+        assert_eq!(
+            evaluate(&mut create_repl(), "40 + 2"),
+            Ok(Some("42 : Number".into()))
+        );
+    }
+
+    #[test]
+    fn keep_definitions() {
+        let mut repl = create_repl();
+
+        assert_eq!(evaluate(&mut repl, "x = 40"), Ok(None));
+        assert_eq!(
+            evaluate(&mut repl, "f : Number -> Number\nf y = x + y"),
+            Ok(None)
+        );
+        assert_eq!(evaluate(&mut repl, "f 2"), Ok(Some("42 : Number".into())));
+    }
+
+    #[test]
+    fn redefine_variable() {
+        let mut repl = create_repl();
+
+        assert_eq!(evaluate(&mut repl, "x = 13"), Ok(None));
+        assert_eq!(evaluate(&mut repl, "x = 42"), Ok(None));
+        assert_eq!(evaluate(&mut repl, "x"), Ok(Some("42 : Number".into())));
+    }
+
+    #[test]
+    fn evaluate_record() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(&mut repl, "type Foo { foo : Number, bar : Boolean }"),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(&mut repl, "Foo{ foo = 42, bar = True }"),
+            Ok(Some("Foo{ bar = True, foo = 42 } : Foo".into()))
+        );
+    }
+
+    #[test]
+    fn evaluate_list() {
+        assert_eq!(
+            evaluate(&mut create_repl(), "[\"foo\", \"bar\"]"),
+            Ok(Some("[\"foo\", \"bar\"] : List String".into()))
+        );
+    }
+
+    #[test]
+    fn discard_invalid_definition() {
+        let mut repl = create_repl();
+
+        assert_eq!(evaluate(&mut repl, "x = 42"), Ok(None));
+        assert!(evaluate(&mut repl, "x = y").is_err());
+        assert_eq!(evaluate(&mut repl, "x"), Ok(Some("42 : Number".into())));
+    }
+
+    #[test]
+    fn evaluate_generic_function() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(&mut repl, "identity : a -> a\nidentity x = x"),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(&mut repl, "identity 42"),
+            Ok(Some("42 : Number".into()))
+        );
+        assert_eq!(
+            evaluate(&mut repl, "identity \"foo\""),
+            Ok(Some("\"foo\" : String".into()))
+        );
+    }
+
+    #[test]
+    fn evaluate_generic_list_function() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "map : (a -> b) -> List a -> List b\nmap f xs = case xs\n  [] => []\n  [y, ...ys] => [f y, ...map f ys]"
+            ),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(&mut repl, "f : Number -> String\nf x = \"foo\""),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(&mut repl, "map f [1, 2]"),
+            Ok(Some("[\"foo\", \"foo\"] : List String".into()))
+        );
+    }
+
+    #[test]
+    fn evaluate_generic_record() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(&mut repl, "type Pair a b { first : a, second : b }"),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "Pair.first (Pair{ first = 42, second = \"foo\" })"
+            ),
+            Ok(Some("42 : Number".into()))
+        );
+        assert_eq!(
+            evaluate(&mut repl, "Pair{ first = 42, second = \"foo\" }"),
+            Ok(Some(
+                "Pair{ first = 42, second = \"foo\" } : Pair Number String".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn evaluate_record_pattern() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(&mut repl, "type Foo { foo : Number, bar : String }"),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "let\n  Foo{ foo = x, bar = y } = Foo{ foo = 42, bar = \"foo\" }\nin\n  x"
+            ),
+            Ok(Some("42 : Number".into()))
+        );
+    }
+
+    #[test]
+    fn evaluate_case_with_patterns() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(&mut repl, "type Foo { foo : Number | None }"),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "f : Foo | String | None -> Number\nf x = case y = x\n  Foo{ foo = 42 } => 1\n  Foo{ foo = z } => 2\n  \"foo\" => 3\n  String => 4\n  None => 5"
+            ),
+            Ok(None)
+        );
+
+        for (argument, result) in &[
+            ("Foo{ foo = 42 }", "1"),
+            ("Foo{ foo = None }", "2"),
+            ("Foo{ foo = 13 }", "2"),
+            ("\"foo\"", "3"),
+            ("\"bar\"", "4"),
+            ("None", "5"),
+        ] {
+            assert_eq!(
+                evaluate(&mut repl, &format!("f ({})", argument)),
+                Ok(Some(format!("{} : Number", result)))
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_list_patterns() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "f : List Number -> Number\nf xs = case xs\n  [] => 0\n  [1] => 1\n  [x, y, ...ys] => x + y\n  [x] => x"
+            ),
+            Ok(None)
+        );
+
+        for (argument, result) in &[("[]", "0"), ("[1]", "1"), ("[2]", "2"), ("[1, 2, 3]", "3")] {
+            assert_eq!(
+                evaluate(&mut repl, &format!("f {}", argument)),
+                Ok(Some(format!("{} : Number", result)))
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_nested_patterns() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "type Foo { foo : Number | String, bar : List Number }"
+            ),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "f : Foo | None -> Number\nf x = case y = x\n  Foo{ foo = 0, bar = [1, z] } => z\n  Foo{ foo = \"foo\", bar = [] } => 10\n  Foo{ foo = 0 } => 20\n  Foo{ bar = [z, ...zs] } => 30 + z\n  Foo => 40\n  None => 50"
+            ),
+            Ok(None)
+        );
+
+        for (argument, result) in &[
+            ("Foo{ foo = 0, bar = [1, 2] }", "2"),
+            ("Foo{ foo = 0, bar = [1, 2, 3] }", "20"),
+            ("Foo{ foo = \"foo\", bar = [] }", "10"),
+            ("Foo{ foo = \"foo\", bar = [5] }", "35"),
+            ("Foo{ foo = 1, bar = [] }", "40"),
+            ("Foo{ foo = \"bar\", bar = [] }", "40"),
+            ("None", "50"),
+        ] {
+            assert_eq!(
+                evaluate(&mut repl, &format!("f ({})", argument)),
+                Ok(Some(format!("{} : Number", result)))
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_list_patterns_in_order() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "f : List Number -> Number\nf xs = case xs\n  [] => 0\n  [1, 2] => 12\n  [1, x] => x\n  [x, 3, ...ys] => 100 + x\n  [x, ...ys] => 1000 + x"
+            ),
+            Ok(None)
+        );
+
+        for (argument, result) in &[
+            ("[]", "0"),
+            ("[1, 2]", "12"),
+            ("[1, 5]", "5"),
+            ("[1, 5, 6]", "1001"),
+            ("[7, 3, 9]", "107"),
+            ("[7, 4]", "1007"),
+        ] {
+            assert_eq!(
+                evaluate(&mut repl, &format!("f {}", argument)),
+                Ok(Some(format!("{} : Number", result)))
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_lambda() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(&mut repl, "(\\x y -> x + y) 1 2"),
+            Ok(Some("3 : Number".into()))
+        );
+    }
+
+    #[test]
+    fn evaluate_lambda_with_free_variables() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "map : (a -> b) -> List a -> List b\nmap f xs = case xs\n  [] => []\n  [y, ...ys] => [f y, ...map f ys]"
+            ),
+            Ok(None)
+        );
+        assert_eq!(evaluate(&mut repl, "x = 1"), Ok(None));
+        assert_eq!(
+            evaluate(&mut repl, "let y = 2 in map (\\z -> x + y + z) [1, 2]"),
+            Ok(Some("[4, 5] : List Number".into()))
+        );
+    }
+
+    #[test]
+    fn evaluate_recursive_generic_function_with_union_type() {
+        let mut repl = create_repl();
+
+        assert_eq!(
+            evaluate(&mut repl, "type Node a { value : a, next : Node a | None }"),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "count : Node a | None -> Number\ncount node = case node = node\n  Node a => 1 + count (Node.next node)\n  None => 0"
+            ),
+            Ok(None)
+        );
+        assert_eq!(
+            evaluate(
+                &mut repl,
+                "count (Node{ value = 1, next = Node{ value = 2, next = None } })"
+            ),
+            Ok(Some("2 : Number".into()))
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use super::{module_context::ModuleContext, value::*};
+use crate::ast::FunctionDefinition;
+use std::sync::Arc;
+
+// Scopes are persistent linked lists of bindings so that closures capture
+// them cheaply. Functions defined together in let expressions are bound as
+// groups so that they can refer to each other recursively.
+#[derive(Clone, Debug, Default)]
+pub struct Scope {
+    node: Option<Arc<ScopeNode>>,
+}
+
+#[derive(Debug)]
+struct ScopeNode {
+    binding: Binding,
+    parent: Scope,
+}
+
+#[derive(Debug)]
+enum Binding {
+    Functions(Vec<Arc<FunctionDefinition>>, Arc<ModuleContext>),
+    Variable(String, Value),
+}
+
+impl Scope {
+    pub fn bind_variable(&self, name: impl Into<String>, value: Value) -> Self {
+        self.bind(Binding::Variable(name.into(), value))
+    }
+
+    pub fn bind_functions(
+        &self,
+        definitions: Vec<Arc<FunctionDefinition>>,
+        context: Arc<ModuleContext>,
+    ) -> Self {
+        self.bind(Binding::Functions(definitions, context))
+    }
+
+    pub fn find(&self, name: &str) -> Option<Value> {
+        let mut scope = self;
+
+        while let Some(node) = &scope.node {
+            match &node.binding {
+                Binding::Functions(definitions, context) => {
+                    if let Some(definition) = definitions
+                        .iter()
+                        .find(|definition| definition.name() == name)
+                    {
+                        return Some(Value::Function(Function::new(
+                            definition.clone(),
+                            context.clone(),
+                            scope.clone(),
+                            vec![],
+                        )));
+                    }
+                }
+                Binding::Variable(variable_name, value) => {
+                    if variable_name == name {
+                        return Some(value.clone());
+                    }
+                }
+            }
+
+            scope = &node.parent;
+        }
+
+        None
+    }
+
+    fn bind(&self, binding: Binding) -> Self {
+        Self {
+            node: Some(
+                ScopeNode {
+                    binding,
+                    parent: self.clone(),
+                }
+                .into(),
+            ),
+        }
+    }
+}
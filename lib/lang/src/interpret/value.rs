@@ -0,0 +1,309 @@
+use super::{module_context::ModuleContext, scope::Scope};
+use crate::{ast::FunctionDefinition, types::Type};
+use std::{any::Any, collections::BTreeMap, sync::Arc};
+
+#[derive(Clone, Debug)]
+pub enum Value {
+    Boolean(bool),
+    ForeignFunction(ForeignFunction),
+    ForeignValue(ForeignValue),
+    Function(Function),
+    Integer(i64),
+    List(List),
+    None,
+    Number(f64),
+    Record(Record),
+    String(Arc<str>),
+    Variant(Variant),
+}
+
+impl PartialEq for Value {
+    // Functions are never equal as they cannot be compared in Ein.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Boolean(one), Self::Boolean(other)) => one == other,
+            (Self::Integer(one), Self::Integer(other)) => one == other,
+            (Self::List(one), Self::List(other)) => one == other,
+            (Self::None, Self::None) => true,
+            (Self::Number(one), Self::Number(other)) => one == other,
+            (Self::Record(one), Self::Record(other)) => one == other,
+            (Self::String(one), Self::String(other)) => one == other,
+            (Self::Variant(one), Self::Variant(other)) => one.value() == other.value(),
+            _ => false,
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(boolean: bool) -> Self {
+        Self::Boolean(boolean)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(number: f64) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(integer: i64) -> Self {
+        Self::Integer(integer)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(string: &str) -> Self {
+        Self::String(string.into())
+    }
+}
+
+impl From<ForeignFunction> for Value {
+    fn from(function: ForeignFunction) -> Self {
+        Self::ForeignFunction(function)
+    }
+}
+
+impl From<ForeignValue> for Value {
+    fn from(value: ForeignValue) -> Self {
+        Self::ForeignValue(value)
+    }
+}
+
+impl From<Function> for Value {
+    fn from(function: Function) -> Self {
+        Self::Function(function)
+    }
+}
+
+impl From<List> for Value {
+    fn from(list: List) -> Self {
+        Self::List(list)
+    }
+}
+
+impl From<Variant> for Value {
+    fn from(variant: Variant) -> Self {
+        Self::Variant(variant)
+    }
+}
+
+impl From<Record> for Value {
+    fn from(record: Record) -> Self {
+        Self::Record(record)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Function {
+    definition: Arc<FunctionDefinition>,
+    context: Arc<ModuleContext>,
+    scope: Scope,
+    arguments: Vec<Value>,
+}
+
+impl Function {
+    pub(super) fn new(
+        definition: Arc<FunctionDefinition>,
+        context: Arc<ModuleContext>,
+        scope: Scope,
+        arguments: Vec<Value>,
+    ) -> Self {
+        Self {
+            definition,
+            context,
+            scope,
+            arguments,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.definition.name()
+    }
+
+    pub(super) fn definition(&self) -> &Arc<FunctionDefinition> {
+        &self.definition
+    }
+
+    pub(super) fn context(&self) -> &Arc<ModuleContext> {
+        &self.context
+    }
+
+    pub(super) fn scope(&self) -> &Scope {
+        &self.scope
+    }
+
+    pub(super) fn arguments(&self) -> &[Value] {
+        &self.arguments
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ForeignFunction {
+    name: String,
+    arity: usize,
+    arguments: Vec<Value>,
+}
+
+impl ForeignFunction {
+    pub(super) fn new(name: impl Into<String>, arity: usize, arguments: Vec<Value>) -> Self {
+        Self {
+            name: name.into(),
+            arity,
+            arguments,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(super) fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub(super) fn arguments(&self) -> &[Value] {
+        &self.arguments
+    }
+}
+
+// Foreign values are returned by foreign functions and opaque to
+// interpreters. They are only passed back to foreign functions.
+#[derive(Clone)]
+pub struct ForeignValue {
+    value: Arc<dyn Any>,
+}
+
+impl ForeignValue {
+    pub fn new(value: impl Any) -> Self {
+        Self {
+            value: Arc::new(value),
+        }
+    }
+
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+}
+
+impl std::fmt::Debug for ForeignValue {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "ForeignValue")
+    }
+}
+
+// Lists are persistent linked lists so that their rests are shared.
+#[derive(Clone, Debug, Default)]
+pub struct List {
+    node: Option<Arc<(Value, List)>>,
+}
+
+impl List {
+    pub fn new(elements: Vec<Value>) -> Self {
+        Self::concatenate(elements, Self::default())
+    }
+
+    pub(super) fn concatenate(elements: Vec<Value>, rest: Self) -> Self {
+        elements
+            .into_iter()
+            .rev()
+            .fold(rest, |list, element| list.prepend(element))
+    }
+
+    pub fn prepend(&self, element: Value) -> Self {
+        Self {
+            node: Some((element, self.clone()).into()),
+        }
+    }
+
+    pub fn first_rest(&self) -> Option<(&Value, &List)> {
+        self.node.as_ref().map(|node| (&node.0, &node.1))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Value> {
+        let mut list = self;
+
+        std::iter::from_fn(move || {
+            let (first, rest) = list.first_rest()?;
+
+            list = rest;
+
+            Some(first)
+        })
+    }
+}
+
+// Long lists are dropped iteratively not to overflow stacks.
+impl Drop for List {
+    fn drop(&mut self) {
+        let mut node = self.node.take();
+
+        while let Some(Ok((_, mut rest))) = node.map(Arc::try_unwrap) {
+            node = rest.node.take();
+        }
+    }
+}
+
+impl PartialEq for List {
+    fn eq(&self, other: &Self) -> bool {
+        let mut one = self.iter();
+        let mut other = other.iter();
+
+        loop {
+            match (one.next(), other.next()) {
+                (Some(one), Some(other)) if one == other => {}
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    name: String,
+    elements: Arc<BTreeMap<String, Value>>,
+}
+
+impl Record {
+    pub fn new(name: impl Into<String>, elements: BTreeMap<String, Value>) -> Self {
+        Self {
+            name: name.into(),
+            elements: elements.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn elements(&self) -> &BTreeMap<String, Value> {
+        &self.elements
+    }
+}
+
+// Lists and functions are tagged with their types when they are coerced into
+// union or any types as their values do not tell their element, argument or
+// result types.
+#[derive(Clone, Debug)]
+pub struct Variant {
+    type_: Type,
+    value: Arc<Value>,
+}
+
+impl Variant {
+    pub(super) fn new(type_: impl Into<Type>, value: Value) -> Self {
+        Self {
+            type_: type_.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+}
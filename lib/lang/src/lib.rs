@@ -1,18 +1,29 @@
 mod ast;
 mod compile;
 pub mod debug;
+mod document;
+mod format;
+mod interpret;
 mod package;
 mod parse;
 mod path;
 pub mod types;
 
-pub use ast::{Import, ModuleInterface, UnresolvedModule};
+pub use ast::{Import, Module, ModuleInterface, ReplInput, UnresolvedModule};
 pub use compile::{
-    compile, CompileConfiguration, ErrorTypeConfiguration, ListTypeConfiguration,
-    MainModuleConfiguration, StringTypeConfiguration,
+    compile, compile_with_stage_hook, is_wasm_target, ArrayTypeConfiguration,
+    CodeGenerationConfiguration, CompileConfiguration, CompileWarning, ErrorTypeConfiguration,
+    IntegerTypeConfiguration, ListTypeConfiguration, MainModuleConfiguration, MapTypeConfiguration,
+    NumberTypeConfiguration, OptimizationLevel, OutputFormat, Stage, StageHook,
+    StringTypeConfiguration,
+};
+pub use document::{document, DocumentationFormat};
+pub use interpret::{
+    ForeignFunction, ForeignFunctionCallback, ForeignFunctionResolver, ForeignValue, Function,
+    InterpretError, Interpreter, List, Record, Repl, Value, Variant,
 };
 pub use package::Package;
-pub use parse::{parse, ParseError};
+pub use parse::{parse, parse_repl_input, ParseError};
 pub use path::{
     ExternalUnresolvedModulePath, InternalUnresolvedModulePath, ModulePath, UnresolvedModulePath,
 };
@@ -1,18 +1,24 @@
 #[macro_use]
 mod attempt;
 mod error;
+mod number_literal;
 mod parsers;
 mod utilities;
 
 use crate::ast;
-use combine::Parser;
 pub use error::ParseError;
-use parsers::{module, stream};
+use parsers::{module, parse_source, repl_input};
 
 pub fn parse(source_content: &str, source_name: &str) -> Result<ast::UnresolvedModule, ParseError> {
-    module()
-        .parse(stream(source_content, source_name))
-        .map(|(module, _)| module)
+    parse_source(module(), source_content, source_name)
+        .map_err(|error| ParseError::new(source_name, &error))
+}
+
+pub fn parse_repl_input(
+    source_content: &str,
+    source_name: &str,
+) -> Result<ast::ReplInput, ParseError> {
+    parse_source(repl_input(), source_content, source_name)
         .map_err(|error| ParseError::new(source_name, &error))
 }
 
@@ -0,0 +1,377 @@
+use std::convert::TryFrom;
+
+const INTEGER_SUFFIX: char = 'i';
+const DIGIT_SEPARATOR: char = '_';
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NumberLiteral {
+    Integer(i64),
+    Number(f64),
+}
+
+// Number literals are validated here after the lexer takes every character
+// which can be a part of them so that malformed ones are reported precisely.
+pub fn parse_number_literal(literal: &str) -> Result<NumberLiteral, String> {
+    let (negative, literal) = match literal.strip_prefix('-') {
+        Some(literal) => (true, literal),
+        None => (false, literal),
+    };
+    let (integer, literal) = match literal.strip_suffix(INTEGER_SUFFIX) {
+        Some(literal) => (true, literal),
+        None => (false, literal),
+    };
+
+    if let Some((radix, digits)) = split_radix_prefix(literal) {
+        parse_radix_literal(negative, integer, radix, digits)
+    } else {
+        parse_decimal_literal(negative, integer, literal)
+    }
+}
+
+fn split_radix_prefix(literal: &str) -> Option<(u32, &str)> {
+    [(16, "0x"), (8, "0o"), (2, "0b")]
+        .iter()
+        .find_map(|(radix, prefix)| Some((*radix, literal.strip_prefix(prefix)?)))
+}
+
+fn parse_radix_literal(
+    negative: bool,
+    integer: bool,
+    radix: u32,
+    digits: &str,
+) -> Result<NumberLiteral, String> {
+    let name = match radix {
+        2 => "binary",
+        8 => "octal",
+        _ => "hexadecimal",
+    };
+    let digits = remove_digit_separators(digits, radix, name)?;
+    let magnitude =
+        u128::from_str_radix(&digits, radix).map_err(|_| out_of_range_message(integer))?;
+
+    if integer {
+        convert_to_integer(negative, magnitude)
+    } else {
+        let number = magnitude as f64;
+
+        // Only numbers representable exactly are allowed.
+        if number as u128 == magnitude {
+            Ok(NumberLiteral::Number(if negative {
+                -number
+            } else {
+                number
+            }))
+        } else {
+            Err(format!(
+                "{} literal not representable exactly as number",
+                name
+            ))
+        }
+    }
+}
+
+fn parse_decimal_literal(
+    negative: bool,
+    integer: bool,
+    literal: &str,
+) -> Result<NumberLiteral, String> {
+    let (mantissa, exponent) = match literal.find(['e', 'E'].as_ref()) {
+        Some(index) => (&literal[..index], Some(&literal[index + 1..])),
+        None => (literal, None),
+    };
+    let (whole, fraction) = match mantissa.find('.') {
+        Some(index) => (&mantissa[..index], Some(&mantissa[index + 1..])),
+        None => (mantissa, None),
+    };
+
+    let whole = remove_digit_separators(whole, 10, "decimal")?;
+
+    if whole.len() > 1 && whole.starts_with('0') {
+        return Err("leading zeros in decimal literal".into());
+    }
+
+    let fraction = fraction
+        .map(|fraction| remove_digit_separators(fraction, 10, "decimal"))
+        .transpose()?;
+    let exponent = exponent
+        .map(|exponent| {
+            let (sign, digits) = match exponent.strip_prefix(['+', '-'].as_ref()) {
+                Some(digits) => (&exponent[..1], digits),
+                None => ("", exponent),
+            };
+
+            Ok::<_, String>(sign.to_owned() + &remove_digit_separators(digits, 10, "exponent")?)
+        })
+        .transpose()?;
+
+    if integer {
+        if fraction.is_some() || exponent.is_some() {
+            return Err("fraction or exponent in integer literal".into());
+        }
+
+        return convert_to_integer(
+            negative,
+            whole.parse().map_err(|_| out_of_range_message(true))?,
+        );
+    }
+
+    let string = [
+        whole.as_str(),
+        ".",
+        fraction.as_deref().unwrap_or("0"),
+        "e",
+        exponent.as_deref().unwrap_or("0"),
+    ]
+    .concat();
+    let number = string
+        .parse::<f64>()
+        .map_err(|_| out_of_range_message(false))?;
+
+    // Numbers are rejected on overflow or underflow into zero.
+    if number.is_infinite()
+        || number == 0.0
+            && string[..string.find('e').unwrap()].contains(|digit| matches!(digit, '1'..='9'))
+    {
+        Err(out_of_range_message(false))
+    } else if !is_decimal_literal_exact(&whole, fraction.as_deref(), exponent.as_deref()) {
+        Err("decimal literal not representable exactly as number".into())
+    } else {
+        Ok(NumberLiteral::Number(if negative {
+            -number
+        } else {
+            number
+        }))
+    }
+}
+
+// Only literals of non-integral values are rounded. Otherwise, their
+// significant digits need to form an integer representable exactly while
+// exponents can still scale it inexactly. Numbers are formatted with all their
+// digits at zero precision.
+fn is_decimal_literal_exact(whole: &str, fraction: Option<&str>, exponent: Option<&str>) -> bool {
+    let fraction = fraction.unwrap_or_default();
+    let digits = [whole, fraction].concat();
+    let significand = digits.trim_start_matches('0').trim_end_matches('0');
+
+    if significand.is_empty() {
+        return true;
+    }
+
+    // Exponents not fitting into integers overflow or underflow numbers
+    // which are rejected already unless their significands are zero.
+    let scale = exponent
+        .map(|exponent| exponent.parse::<i64>().unwrap_or(i64::MIN))
+        .unwrap_or_default()
+        .saturating_add((digits.len() - digits.trim_end_matches('0').len()) as i64)
+        .saturating_sub(fraction.len() as i64);
+    let digits = if exponent.is_some() {
+        significand
+    } else {
+        whole
+    };
+
+    scale < 0 || format!("{:.0}", digits.parse::<f64>().unwrap()) == digits
+}
+
+// Digit separators are allowed only between digits.
+fn remove_digit_separators(digits: &str, radix: u32, name: &str) -> Result<String, String> {
+    if digits.is_empty() {
+        return Err(format!("missing digits in {} literal", name));
+    } else if digits.starts_with(DIGIT_SEPARATOR)
+        || digits.ends_with(DIGIT_SEPARATOR)
+        || digits.contains("__")
+    {
+        return Err(format!("misplaced digit separator in {} literal", name));
+    }
+
+    digits
+        .chars()
+        .filter(|&character| character != DIGIT_SEPARATOR)
+        .map(|character| {
+            if character.is_digit(radix) {
+                Ok(character)
+            } else {
+                Err(format!("invalid digit `{}` in {} literal", character, name))
+            }
+        })
+        .collect()
+}
+
+fn convert_to_integer(negative: bool, magnitude: u128) -> Result<NumberLiteral, String> {
+    i128::try_from(magnitude)
+        .ok()
+        .and_then(|magnitude| i64::try_from(if negative { -magnitude } else { magnitude }).ok())
+        .map(NumberLiteral::Integer)
+        .ok_or_else(|| out_of_range_message(true))
+}
+
+fn out_of_range_message(integer: bool) -> String {
+    format!(
+        "{} literal out of range",
+        if integer { "integer" } else { "number" }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_decimal_literals() {
+        for (literal, number) in &[
+            ("0", 0.0),
+            ("42", 42.0),
+            ("-42", -42.0),
+            ("0.5", 0.5),
+            ("1_000_000", 1e6),
+            ("1e9", 1e9),
+            ("1E9", 1e9),
+            ("1e+9", 1e9),
+            ("1e-9", 1e-9),
+            ("1.5e-9", 1.5e-9),
+            ("1_0.0_1e1_0", 10.01e10),
+            ("0.1", 0.1),
+            ("9007199254740992", 9007199254740992.0),
+            ("9007199254740992.0", 9007199254740992.0),
+            ("9007199254740993e-1", 900719925474099.3),
+            ("1.0e23", 1e23),
+            ("1e100", 1e100),
+            ("0e99999999999999999999", 0.0),
+            ("1361129467683753853853498429727072845824", 2f64.powi(130)),
+        ] {
+            assert_eq!(
+                parse_number_literal(literal),
+                Ok(NumberLiteral::Number(*number)),
+                "{}",
+                literal
+            );
+        }
+    }
+
+    #[test]
+    fn parse_radix_literals() {
+        for (literal, number) in &[
+            ("0xff", 255.0),
+            ("0xFF", 255.0),
+            ("-0x10", -16.0),
+            ("0o17", 15.0),
+            ("0b1010", 10.0),
+            ("0b1111_0000", 240.0),
+            ("0x20000000000000", 9007199254740992.0),
+        ] {
+            assert_eq!(
+                parse_number_literal(literal),
+                Ok(NumberLiteral::Number(*number)),
+                "{}",
+                literal
+            );
+        }
+    }
+
+    #[test]
+    fn parse_integer_literals() {
+        for (literal, integer) in &[
+            ("0i", 0),
+            ("-42i", -42),
+            ("1_000i", 1000),
+            ("0xFFi", 255),
+            ("0x7fff_ffff_ffff_ffffi", i64::MAX),
+            ("-0x8000_0000_0000_0000i", i64::MIN),
+            ("-9223372036854775808i", i64::MIN),
+            ("0b101i", 5),
+            ("0o777i", 511),
+        ] {
+            assert_eq!(
+                parse_number_literal(literal),
+                Ok(NumberLiteral::Integer(*integer)),
+                "{}",
+                literal
+            );
+        }
+    }
+
+    #[test]
+    fn fail_to_parse_malformed_literals() {
+        for (literal, message) in &[
+            ("0x", "missing digits in hexadecimal literal"),
+            ("0b", "missing digits in binary literal"),
+            ("0xi", "missing digits in hexadecimal literal"),
+            ("0b102", "invalid digit `2` in binary literal"),
+            ("0o8", "invalid digit `8` in octal literal"),
+            ("0xG", "invalid digit `G` in hexadecimal literal"),
+            ("12ab", "invalid digit `a` in decimal literal"),
+            ("0X1", "invalid digit `X` in decimal literal"),
+            ("01", "leading zeros in decimal literal"),
+            ("1_", "misplaced digit separator in decimal literal"),
+            ("1__0", "misplaced digit separator in decimal literal"),
+            ("0x_1", "misplaced digit separator in hexadecimal literal"),
+            ("1._5", "misplaced digit separator in decimal literal"),
+            ("1e", "missing digits in exponent literal"),
+            ("1e-", "missing digits in exponent literal"),
+            ("1e1.5", "invalid digit `.` in exponent literal"),
+            ("1.5i", "fraction or exponent in integer literal"),
+            ("1e3i", "fraction or exponent in integer literal"),
+        ] {
+            assert_eq!(
+                parse_number_literal(literal),
+                Err(message.to_string()),
+                "{}",
+                literal
+            );
+        }
+    }
+
+    #[test]
+    fn fail_to_parse_literals_out_of_range() {
+        for (literal, message) in &[
+            ("1e309", "number literal out of range"),
+            ("-1e309", "number literal out of range"),
+            ("1e-400", "number literal out of range"),
+            (
+                "0x20000000000001",
+                "hexadecimal literal not representable exactly as number",
+            ),
+            (
+                "9007199254740993",
+                "decimal literal not representable exactly as number",
+            ),
+            (
+                "-9_007_199_254_740_993",
+                "decimal literal not representable exactly as number",
+            ),
+            (
+                "9007199254740993.0",
+                "decimal literal not representable exactly as number",
+            ),
+            (
+                "9007199254740993e0",
+                "decimal literal not representable exactly as number",
+            ),
+            (
+                "900719925474099.3e1",
+                "decimal literal not representable exactly as number",
+            ),
+            (
+                "90071992547409930e-1",
+                "decimal literal not representable exactly as number",
+            ),
+            (
+                "100000000000000000000000",
+                "decimal literal not representable exactly as number",
+            ),
+            ("9223372036854775808i", "integer literal out of range"),
+            ("0x8000_0000_0000_0000i", "integer literal out of range"),
+            (
+                "0x1_0000_0000_0000_0000_0000_0000_0000_0000i",
+                "integer literal out of range",
+            ),
+        ] {
+            assert_eq!(
+                parse_number_literal(literal),
+                Err(message.to_string()),
+                "{}",
+                literal
+            );
+        }
+    }
+}
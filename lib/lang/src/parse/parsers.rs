@@ -1,5 +1,6 @@
 use super::{
     attempt::{many, many1, optional, sep_end_by, sep_end_by1},
+    number_literal::{parse_number_literal, NumberLiteral},
     utilities::*,
 };
 use crate::{
@@ -9,14 +10,14 @@ use crate::{
     types::{self, Type},
 };
 use combine::{
-    easy, from_str, none_of, one_of,
+    count_min_max, easy, from_str, none_of, one_of,
     parser::{
-        char::{alpha_num, char as character, letter, string},
+        char::{alpha_num, char as character, hex_digit, letter, string},
         combinator::{lazy, look_ahead, no_partial, not_followed_by},
         regex::find,
         sequence::between,
     },
-    sep_by1,
+    position, sep_by1,
     stream::{
         position::{self, SourcePosition},
         state,
@@ -29,16 +30,24 @@ use std::{collections::HashSet, sync::Arc};
 const KEYWORDS: &[&str] = &[
     "case", "else", "export", "foreign", "if", "import", "in", "let", "then", "type",
 ];
-const OPERATOR_CHARACTERS: &str = "+-*/=<>&|";
+const OPERATOR_CHARACTERS: &str = "+-*/%=<>&|";
 const SPACE_CHARACTERS: &str = " \t\r";
 
-static NUMBER_REGEX: Lazy<regex::Regex> =
-    Lazy::new(|| regex::Regex::new(r"^-?([123456789][0123456789]*|0)(\.[0123456789]+)?").unwrap());
-static STRING_REGEX: Lazy<regex::Regex> = Lazy::new(|| regex::Regex::new(r#"^[^\\"]"#).unwrap());
+// Number literals are lexed loosely here and validated later.
+static NUMBER_REGEX: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new(
+        r"^-?(0[xob][0-9A-Za-z_]*|[0-9]([eE][+-]|[0-9A-Za-z_])*(\.[0-9]([eE][+-]|[0-9A-Za-z_])*)?)",
+    )
+    .unwrap()
+});
+static STRING_REGEX: Lazy<regex::Regex> = Lazy::new(|| regex::Regex::new(r##"^[^\\"#]"##).unwrap());
 
 pub struct State<'a> {
     source_name: &'a str,
     lines: Vec<&'a str>,
+    // Errors of malformed literals are kept here as they are lost on
+    // backtracking otherwise.
+    literal_error: Option<easy::Errors<char, &'a str, SourcePosition>>,
 }
 
 pub type Stream<'a> =
@@ -50,11 +59,29 @@ pub fn stream<'a>(source: &'a str, source_name: &'a str) -> Stream<'a> {
         state: State {
             source_name,
             lines: source.split('\n').collect(),
+            literal_error: None,
         },
     }
     .into()
 }
 
+pub fn parse_source<'a, O>(
+    mut parser: impl Parser<Stream<'a>, Output = O>,
+    source: &'a str,
+    source_name: &'a str,
+) -> Result<O, easy::Errors<char, &'a str, SourcePosition>> {
+    let mut stream = stream(source, source_name);
+    let result = parser.parse_stream(&mut stream).into_result();
+
+    if let Some(error) = stream.0.state.literal_error.take() {
+        Err(error)
+    } else {
+        result
+            .map(|(output, _)| output)
+            .map_err(|error| error.into_inner().error)
+    }
+}
+
 pub fn module<'a>() -> impl Parser<Stream<'a>, Output = UnresolvedModule> {
     (
         optional(export()),
@@ -80,17 +107,48 @@ pub fn module<'a>() -> impl Parser<Stream<'a>, Output = UnresolvedModule> {
         )
 }
 
+pub fn repl_input<'a>() -> impl Parser<Stream<'a>, Output = ReplInput> {
+    choice!(
+        type_definition().map(ReplInput::from),
+        definition().map(ReplInput::from),
+        untyped_function_definition().map(|definition| Definition::from(definition).into()),
+        untyped_variable_definition().map(|definition| Definition::from(definition).into()),
+        expression().map(ReplInput::from),
+    )
+    .skip(blank())
+    .skip(eof())
+}
+
 fn export<'a>() -> impl Parser<Stream<'a>, Output = Export> {
     keyword("export")
         .with(between(
             sign("{"),
             sign("}"),
-            sep_end_by1(identifier(), sign(",")),
+            sep_end_by1(exported_name(), sign(",")),
         ))
-        .map(Export::new)
+        .map(|names: Vec<(String, bool)>| {
+            Export::with_opaque_names(
+                names.iter().map(|(name, _)| name.clone()).collect(),
+                names
+                    .iter()
+                    .filter(|(_, opaque)| *opaque)
+                    .map(|(name, _)| name.clone())
+                    .collect(),
+            )
+        })
         .expected("export statement")
 }
 
+// `opaque` is not a keyword so that it can still be used as a name.
+fn exported_name<'a>() -> impl Parser<Stream<'a>, Output = (String, bool)> {
+    choice!(
+        keyword("opaque")
+            .with(identifier())
+            .map(|name| (name, true)),
+        identifier().map(|name| (name, false)),
+    )
+}
+
 fn import<'a>() -> impl Parser<Stream<'a>, Output = UnresolvedImport> {
     keyword("import")
         .with(module_path())
@@ -178,6 +236,7 @@ fn definition<'a>() -> impl Parser<Stream<'a>, Output = Definition> {
 
 fn function_definition<'a>() -> impl Parser<Stream<'a>, Output = FunctionDefinition> {
     (
+        optional(documentation()),
         source_information(),
         type_annotation(),
         identifier(),
@@ -186,15 +245,28 @@ fn function_definition<'a>() -> impl Parser<Stream<'a>, Output = FunctionDefinit
         expression(),
     )
         .then(
-            |(source_information, (typed_name, type_), name, arguments, _, expression)| {
+            |(
+                documentation,
+                source_information,
+                (typed_name, type_),
+                name,
+                arguments,
+                _,
+                expression,
+            )| {
                 if typed_name == name {
-                    value(FunctionDefinition::new(
+                    let definition = FunctionDefinition::new(
                         name,
                         arguments,
                         expression,
                         type_,
                         source_information,
-                    ))
+                    );
+
+                    value(match documentation {
+                        Some(documentation) => definition.with_documentation(documentation),
+                        None => definition,
+                    })
                     .left()
                 } else {
                     unexpected_any("unmatched identifiers in definition").right()
@@ -306,7 +378,15 @@ fn untyped_result_definition<'a>() -> impl Parser<Stream<'a>, Output = VariableD
 }
 
 fn type_definition<'a>() -> impl Parser<Stream<'a>, Output = TypeDefinition> {
-    choice!(type_alias_definition(), record_type_definition()).expected("type definition")
+    (
+        optional(documentation()),
+        choice!(type_alias_definition(), record_type_definition()),
+    )
+        .map(|(documentation, definition)| match documentation {
+            Some(documentation) => definition.with_documentation(documentation),
+            None => definition,
+        })
+        .expected("type definition")
 }
 
 fn record_type_definition<'a>() -> impl Parser<Stream<'a>, Output = TypeDefinition> {
@@ -314,6 +394,7 @@ fn record_type_definition<'a>() -> impl Parser<Stream<'a>, Output = TypeDefiniti
         keyword("type"),
         source_information(),
         identifier(),
+        many(type_argument(parameter_type().map(Type::from))),
         optional(between(
             sign("{"),
             sign("}"),
@@ -321,11 +402,12 @@ fn record_type_definition<'a>() -> impl Parser<Stream<'a>, Output = TypeDefiniti
         )),
     )
         .map(
-            |(_, source_information, name, elements): (_, _, _, Option<Vec<_>>)| {
+            |(_, source_information, name, parameters, elements): (_, _, _, _, Option<Vec<_>>)| {
                 TypeDefinition::new(
                     &name,
-                    types::Record::new(
+                    types::Record::with_arguments(
                         &name,
+                        parameters,
                         elements
                             .unwrap_or_default()
                             .into_iter()
@@ -377,7 +459,31 @@ fn union_type<'a>() -> impl Parser<Stream<'a>, Output = Type> {
 }
 
 fn type_application<'a>() -> impl Parser<Stream<'a>, Output = Type> {
-    choice!(list_type().map(Type::from), atomic_type())
+    choice!(
+        list_type().map(Type::from),
+        reference_type_application().map(Type::from),
+        atomic_type()
+    )
+}
+
+fn reference_type_application<'a>() -> impl Parser<Stream<'a>, Output = types::Reference> {
+    (reference_type(), many1(type_argument(atomic_type())))
+        .map(|(reference, arguments)| {
+            types::Reference::with_arguments(
+                reference.name(),
+                arguments,
+                reference.source_information().clone(),
+            )
+        })
+        .expected("reference type application")
+}
+
+// Type arguments and parameters never continue on the next lines so that
+// type annotations are not merged into the following definitions.
+fn type_argument<'a>(
+    type_: impl Parser<Stream<'a>, Output = Type>,
+) -> impl Parser<Stream<'a>, Output = Type> {
+    not_followed_by(newlines1().with(value("newline"))).with(type_)
 }
 
 fn list_type<'a>() -> impl Parser<Stream<'a>, Output = types::List> {
@@ -390,9 +496,11 @@ fn atomic_type<'a>() -> impl Parser<Stream<'a>, Output = Type> {
     choice!(
         boolean_type().map(Type::from),
         none_type().map(Type::from),
+        integer_type().map(Type::from),
         number_type().map(Type::from),
         string_type().map(Type::from),
         any_type().map(Type::from),
+        parameter_type().map(Type::from),
         reference_type().map(Type::from),
         between(sign("("), sign(")"), type_()),
     )
@@ -412,6 +520,13 @@ fn none_type<'a>() -> impl Parser<Stream<'a>, Output = types::None> {
         .expected("none type")
 }
 
+fn integer_type<'a>() -> impl Parser<Stream<'a>, Output = types::Integer> {
+    source_information()
+        .skip(keyword("Integer"))
+        .map(types::Integer::new)
+        .expected("integer type")
+}
+
 fn number_type<'a>() -> impl Parser<Stream<'a>, Output = types::Number> {
     source_information()
         .skip(keyword("Number"))
@@ -433,14 +548,37 @@ fn any_type<'a>() -> impl Parser<Stream<'a>, Output = types::Any> {
         .expected("any type")
 }
 
+fn parameter_type<'a>() -> impl Parser<Stream<'a>, Output = types::Parameter> {
+    (
+        source_information(),
+        raw_identifier().skip(not_followed_by(string("."))),
+    )
+        .then(|(source_information, identifier)| {
+            if is_type_parameter_name(&identifier) {
+                value(types::Parameter::new(identifier, source_information)).left()
+            } else {
+                unexpected_any("non-parameter type").right()
+            }
+        })
+        .expected("type parameter")
+}
+
 fn reference_type<'a>() -> impl Parser<Stream<'a>, Output = types::Reference> {
     (source_information(), qualified_identifier())
-        .map(|(source_information, identifier)| {
-            types::Reference::new(identifier, source_information)
+        .then(|(source_information, identifier)| {
+            if is_type_parameter_name(&identifier) {
+                unexpected_any("type parameter").left()
+            } else {
+                value(types::Reference::new(identifier, source_information)).right()
+            }
         })
         .expected("reference type")
 }
 
+fn is_type_parameter_name(name: &str) -> bool {
+    !name.contains('.') && name.starts_with(|character: char| character.is_lowercase())
+}
+
 fn expression<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
     lazy(|| no_partial(operation_or_term()))
         .boxed()
@@ -455,11 +593,14 @@ fn strict_atomic_expression<'a>() -> impl Parser<Stream<'a>, Output = Expression
     choice!(
         record_construction().map(Expression::from),
         record_update().map(Expression::from),
+        array_literal().map(Expression::from),
         list_literal().map(Expression::from),
+        map_literal().map(Expression::from),
         boolean_literal().map(Expression::from),
         none_literal().map(Expression::from),
+        integer_literal().map(Expression::from),
         number_literal().map(Expression::from),
-        string_literal().map(Expression::from),
+        string_expression(),
         variable().map(Expression::from),
         between(sign("("), sign(")"), expression()),
     )
@@ -546,9 +687,124 @@ fn list_case<'a>() -> impl Parser<Stream<'a>, Output = ListCase> {
         .expected("list case expression")
 }
 
+fn list_pattern_case<'a>() -> impl Parser<Stream<'a>, Output = ListPatternCase> {
+    (
+        source_information(),
+        keyword("case").expected("case keyword"),
+        expression(),
+        many1(list_alternative()),
+    )
+        .map(|(source_information, _, argument, alternatives)| {
+            ListPatternCase::new(argument, alternatives, source_information)
+        })
+        .expected("list case expression")
+}
+
 fn alternative<'a>() -> impl Parser<Stream<'a>, Output = Alternative> {
-    (type_(), sign("=>"), expression())
-        .map(|(type_, _, expression)| Alternative::new(type_, expression))
+    choice!(
+        (alternative_pattern(), sign("=>"), expression()).map(
+            |((type_, pattern), _, expression)| Alternative::with_pattern(
+                type_, pattern, expression
+            )
+        ),
+        (type_(), sign("=>"), expression())
+            .map(|(type_, _, expression)| Alternative::new(type_, expression)),
+    )
+}
+
+// Patterns in alternatives of type case expressions determine types of
+// arguments to be matched.
+fn alternative_pattern<'a>() -> impl Parser<Stream<'a>, Output = (Type, Pattern)> {
+    choice!(
+        record_pattern().map(|pattern| (pattern.type_().clone(), pattern.into())),
+        number_literal().map(|number| (
+            types::Number::new(number.source_information().clone()).into(),
+            number.into()
+        )),
+        string_literal().map(|string| (
+            types::EinString::new(string.source_information().clone()).into(),
+            string.into()
+        )),
+    )
+}
+
+fn list_alternative<'a>() -> impl Parser<Stream<'a>, Output = ListAlternative> {
+    (list_pattern(), sign("=>"), expression())
+        .map(|(pattern, _, expression)| ListAlternative::new(pattern, expression))
+}
+
+fn pattern<'a>() -> impl Parser<Stream<'a>, Output = Pattern> {
+    lazy(|| {
+        no_partial(choice!(
+            record_pattern().map(Pattern::from),
+            list_pattern().map(Pattern::from),
+            number_literal().map(Pattern::from),
+            string_literal().map(Pattern::from),
+            token((source_information(), raw_identifier()))
+                .map(|(source_information, name)| Variable::new(name, source_information).into()),
+        ))
+    })
+    .boxed()
+    .expected("pattern")
+}
+
+fn record_pattern<'a>() -> impl Parser<Stream<'a>, Output = RecordPattern> {
+    (
+        source_information(),
+        reference_type(),
+        string("{"),
+        sep_end_by1((identifier().skip(sign("=")), pattern()), sign(",")),
+        sign("}"),
+    )
+        .then(|(source_information, reference_type, _, elements, _)| {
+            let elements: Vec<_> = elements;
+
+            if elements
+                .iter()
+                .map(|(key, _)| key.into())
+                .collect::<HashSet<String>>()
+                .len()
+                == elements.len()
+            {
+                value(RecordPattern::new(
+                    reference_type,
+                    elements.into_iter().collect(),
+                    source_information,
+                ))
+                .left()
+            } else {
+                unexpected_any("duplicate keys in record pattern").right()
+            }
+        })
+        .expected("record pattern")
+}
+
+fn list_pattern<'a>() -> impl Parser<Stream<'a>, Output = ListPattern> {
+    (
+        source_information(),
+        sign("["),
+        sep_end_by(pattern(), sign(",")),
+        optional(sign("...").with(identifier())),
+        sign("]"),
+    )
+        .map(|(source_information, _, elements, rest_name, _)| {
+            ListPattern::new(elements, rest_name, source_information)
+        })
+        .expected("list pattern")
+}
+
+fn lambda<'a>() -> impl Parser<Stream<'a>, Output = Lambda> {
+    (
+        source_information(),
+        sign("\\"),
+        many1(identifier()),
+        sign("->"),
+        expression(),
+    )
+        .map(|(source_information, _, arguments, _, body)| {
+            Lambda::new(arguments, body, source_information)
+        })
+        .expected("lambda expression")
 }
 
 fn let_<'a>() -> impl Parser<Stream<'a>, Output = Let> {
@@ -570,6 +826,35 @@ fn let_<'a>() -> impl Parser<Stream<'a>, Output = Let> {
         .expected("let expression")
 }
 
+fn let_pattern<'a>() -> impl Parser<Stream<'a>, Output = LetPattern> {
+    (
+        source_information(),
+        keyword("let").expected("let keyword"),
+        many1(pattern_definition()),
+        keyword("in").expected("in keyword"),
+        expression(),
+    )
+        .map(|(source_information, _, definitions, _, expression)| {
+            LetPattern::new(definitions, expression, source_information)
+        })
+        .expected("let-pattern expression")
+}
+
+fn pattern_definition<'a>() -> impl Parser<Stream<'a>, Output = PatternDefinition> {
+    (
+        source_information(),
+        choice!(
+            record_pattern().map(Pattern::from),
+            list_pattern().map(Pattern::from)
+        ),
+        sign("="),
+        expression(),
+    )
+        .map(|(source_information, pattern, _, expression)| {
+            PatternDefinition::new(pattern, expression, source_information)
+        })
+}
+
 fn let_error<'a>() -> impl Parser<Stream<'a>, Output = LetError> {
     (
         source_information(),
@@ -700,8 +985,11 @@ fn term<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
         if_().map(Expression::from),
         case().map(Expression::from),
         list_case().map(Expression::from),
+        list_pattern_case().map(Expression::from),
         let_().map(Expression::from),
         let_error().map(Expression::from),
+        let_pattern().map(Expression::from),
+        lambda().map(Expression::from),
         let_().map(Expression::from),
     )
 }
@@ -722,6 +1010,8 @@ fn operator<'a>() -> impl Parser<Stream<'a>, Output = ParsedOperator> {
         concrete_operator("-", ParsedOperator::Subtract),
         concrete_operator("*", ParsedOperator::Multiply),
         concrete_operator("/", ParsedOperator::Divide),
+        concrete_operator("//", ParsedOperator::IntegerDivide),
+        concrete_operator("%", ParsedOperator::Remainder),
         concrete_operator("==", ParsedOperator::Equal),
         concrete_operator("/=", ParsedOperator::NotEqual),
         concrete_operator("<", ParsedOperator::LessThan),
@@ -768,37 +1058,215 @@ fn none_literal<'a>() -> impl Parser<Stream<'a>, Output = None> {
         .expected("none literal")
 }
 
+// Integer literals are distinguished from number literals by their suffixes.
+fn integer_literal<'a>() -> impl Parser<Stream<'a>, Output = Integer> {
+    raw_number_literal()
+        .then(|(literal, source_information)| match literal {
+            NumberLiteral::Integer(integer) => {
+                value(Integer::new(integer, source_information)).left()
+            }
+            NumberLiteral::Number(_) => unexpected_any("number literal").right(),
+        })
+        .expected("integer literal")
+}
+
 fn number_literal<'a>() -> impl Parser<Stream<'a>, Output = Number> {
+    raw_number_literal()
+        .then(|(literal, source_information)| match literal {
+            NumberLiteral::Number(number) => value(Number::new(number, source_information)).left(),
+            NumberLiteral::Integer(_) => unexpected_any("integer literal").right(),
+        })
+        .expected("number literal")
+}
+
+fn raw_number_literal<'a>() -> impl Parser<Stream<'a>, Output = (NumberLiteral, SourceInformation)>
+{
     let regex: &'static regex::Regex = &NUMBER_REGEX;
 
-    token((source_information(), from_str(find(regex))))
-        .map(|(source_information, number)| Number::new(number, source_information))
-        .expected("number literal")
+    token((source_information(), position(), find(regex)))
+        .map_input(
+            |(source_information, position, literal), stream: &mut Stream<'a>| {
+                parse_number_literal(literal)
+                    .map(|literal| (literal, source_information))
+                    .map_err(|message| record_literal_error(stream, position, message))
+            },
+        )
+        .then(|result| match result {
+            Ok(literal) => value(literal).left(),
+            Err(()) => unexpected_any("malformed number literal").right(),
+        })
 }
 
 fn string_literal<'a>() -> impl Parser<Stream<'a>, Output = EinString> {
-    let regex: &'static regex::Regex = &STRING_REGEX;
+    string_expression()
+        .then(|expression| match expression {
+            Expression::String(string) => value(string).left(),
+            _ => unexpected_any("string interpolation").right(),
+        })
+        .expected("string literal")
+}
+
+fn string_expression<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
+    choice!(
+        raw_string_literal().map(Expression::from),
+        interpolated_string_literal(),
+    )
+}
 
+// Raw string literals can span multiple lines and keep everything between
+// their back quotes as they are.
+fn raw_string_literal<'a>() -> impl Parser<Stream<'a>, Output = EinString> {
+    token((
+        source_information(),
+        character('`'),
+        many(none_of("`".chars())),
+        character('`'),
+    ))
+    .map(|(source_information, _, string, _): (_, _, String, _)| {
+        EinString::new(string, source_information)
+    })
+    .expected("raw string literal")
+}
+
+fn interpolated_string_literal<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
     token((
         source_information(),
         character('"'),
-        many(choice!(
-            from_str(find(regex)),
-            string("\\\\").map(|_| "\\".into()),
-            string("\\\"").map(|_| "\"".into()),
-            string("\\n").map(|_| "\n".into()),
-            string("\\t").map(|_| "\t".into())
-        )),
+        many(string_part()),
         character('"'),
     ))
     .map(
-        |(source_information, _, strings, _): (_, _, Vec<String>, _)| {
-            EinString::new(strings.join(""), source_information)
+        |(source_information, _, parts, _): (_, _, Vec<StringInterpolationPart>, _)| {
+            let parts = merge_string_parts(parts);
+
+            match parts.as_slice() {
+                [] => EinString::new("", source_information).into(),
+                [StringInterpolationPart::String(string)] => {
+                    EinString::new(string, source_information).into()
+                }
+                _ => StringInterpolation::new(parts, source_information).into(),
+            }
         },
     )
     .expected("string literal")
 }
 
+fn string_part<'a>() -> impl Parser<Stream<'a>, Output = StringInterpolationPart> {
+    choice!(
+        between(string("#{"), sign("}"), expression()).map(StringInterpolationPart::Expression),
+        string_character().map(StringInterpolationPart::String),
+    )
+}
+
+fn string_character<'a>() -> impl Parser<Stream<'a>, Output = String> {
+    let regex: &'static regex::Regex = &STRING_REGEX;
+
+    choice!(
+        from_str(find(regex)),
+        character('#')
+            .skip(not_followed_by(character('{')))
+            .map(|_| "#".into()),
+        escape_sequence().map(String::from),
+    )
+}
+
+fn escape_sequence<'a>() -> impl Parser<Stream<'a>, Output = char> {
+    (position(), character('\\'), optional(escaped_character()))
+        .map_input(|(position, _, character), stream: &mut Stream<'a>| {
+            if character.is_none() {
+                let escaped_character = stream.0.state.lines[position.line as usize - 1]
+                    .chars()
+                    .nth(position.column as usize)
+                    .map(String::from)
+                    .unwrap_or_default();
+
+                record_literal_error(
+                    stream,
+                    position,
+                    format!("invalid escape sequence \\{}", escaped_character),
+                );
+            }
+
+            character
+        })
+        .then(|character| match character {
+            Some(character) => value(character).left(),
+            None => unexpected_any("escape sequence").right(),
+        })
+        .expected("escape sequence")
+}
+
+fn escaped_character<'a>() -> impl Parser<Stream<'a>, Output = char> {
+    choice!(
+        character('\\'),
+        character('"'),
+        character('#'),
+        character('n').map(|_| '\n'),
+        character('r').map(|_| '\r'),
+        character('t').map(|_| '\t'),
+        character('0').map(|_| '\0'),
+        character('x')
+            .with(count_min_max(2, 2, hex_digit()))
+            .then(|digits: String| match u8::from_str_radix(&digits, 16) {
+                Ok(byte) if byte.is_ascii() => value(byte as char).left(),
+                _ => unexpected_any("non-ASCII character").right(),
+            }),
+        character('u')
+            .with(between(
+                character('{'),
+                character('}'),
+                count_min_max(1, 6, hex_digit()),
+            ))
+            .then(|digits: String| {
+                match u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .and_then(std::char::from_u32)
+                {
+                    Some(character) => value(character).left(),
+                    None => unexpected_any("invalid Unicode scalar value").right(),
+                }
+            }),
+    )
+}
+
+// Only the first error is recorded as the others can be caused by it.
+fn record_literal_error(stream: &mut Stream, position: SourcePosition, message: String) {
+    let state = &mut stream.0.state;
+
+    if state.literal_error.is_none() {
+        state.literal_error = Some(easy::Errors::new(
+            position,
+            easy::Error::Message(message.into()),
+        ));
+    }
+}
+
+// Consecutive characters in string literals are merged into single strings.
+fn merge_string_parts(parts: Vec<StringInterpolationPart>) -> Vec<StringInterpolationPart> {
+    let mut merged_parts = vec![];
+
+    for part in parts {
+        match (merged_parts.last_mut(), part) {
+            (
+                Some(StringInterpolationPart::String(string)),
+                StringInterpolationPart::String(other),
+            ) => string.push_str(&other),
+            (_, part) => merged_parts.push(part),
+        }
+    }
+
+    merged_parts
+}
+
+fn array_literal<'a>() -> impl Parser<Stream<'a>, Output = Array> {
+    (
+        source_information(),
+        between(sign("[|"), sign("|]"), sep_end_by(expression(), sign(","))),
+    )
+        .map(|(source_information, elements)| Array::new(elements, source_information))
+        .expected("array literal")
+}
+
 fn list_literal<'a>() -> impl Parser<Stream<'a>, Output = List> {
     (
         source_information(),
@@ -818,6 +1286,19 @@ fn list_element<'a>() -> impl Parser<Stream<'a>, Output = ListElement> {
     })
 }
 
+fn map_literal<'a>() -> impl Parser<Stream<'a>, Output = Map> {
+    (
+        source_information(),
+        between(sign("{"), sign("}"), sep_end_by(map_entry(), sign(","))),
+    )
+        .map(|(source_information, entries)| Map::new(entries, source_information))
+        .expected("map literal")
+}
+
+fn map_entry<'a>() -> impl Parser<Stream<'a>, Output = MapEntry> {
+    (expression(), sign(":"), expression()).map(|(key, _, value)| MapEntry::new(key, value))
+}
+
 fn variable<'a>() -> impl Parser<Stream<'a>, Output = Variable> {
     token((source_information(), qualified_identifier()))
         .map(|(source_information, identifier)| Variable::new(identifier, source_information))
@@ -923,6 +1404,29 @@ fn eof<'a>() -> impl Parser<Stream<'a>, Output = ()> {
         .expected("end of file")
 }
 
+// Documentation comments are preceded by blanks without them as blank parsers
+// consume every comment.
+fn documentation<'a>() -> impl Parser<Stream<'a>, Output = String> {
+    many::<Vec<_>, _, _>(choice!(
+        spaces1(),
+        optional(spaces1()).with(choice!(
+            combine::parser::char::newline().with(value(())),
+            not_followed_by(string("##")).with(comment()),
+        )),
+    ))
+    .with(many1(documentation_line()))
+    .map(|lines: Vec<String>| lines.join("\n"))
+    .expected("documentation comment")
+}
+
+fn documentation_line<'a>() -> impl Parser<Stream<'a>, Output = String> {
+    optional(spaces1())
+        .with(string("##"))
+        .with(optional(character(' ')))
+        .with(many(none_of("\n".chars())))
+        .skip(combine::parser::char::newline())
+}
+
 fn comment<'a>() -> impl Parser<Stream<'a>, Output = ()> {
     string("#")
         .with(many::<Vec<_>, _, _>(none_of("\n".chars())))
@@ -1077,6 +1581,20 @@ mod tests {
             export().parse(stream("export {\nfoo }", "")).unwrap().0,
             Export::new(vec!["foo".into()].drain(..).collect()),
         );
+        assert_eq!(
+            export()
+                .parse(stream("export { opaque Foo, bar }", ""))
+                .unwrap()
+                .0,
+            Export::with_opaque_names(
+                vec!["Foo".into(), "bar".into()].drain(..).collect(),
+                vec!["Foo".into()].drain(..).collect()
+            ),
+        );
+        assert_eq!(
+            export().parse(stream("export { opaque }", "")).unwrap().0,
+            Export::new(vec!["opaque".into()].drain(..).collect()),
+        );
     }
 
     #[test]
@@ -1094,6 +1612,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_repl_input() {
+        assert!(repl_input().parse(stream("", "")).is_err());
+        assert_eq!(
+            repl_input().parse(stream("x = 42", "")).unwrap().0,
+            Definition::from(VariableDefinition::new(
+                "x",
+                Number::new(42.0, SourceInformation::dummy()),
+                types::Unknown::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ))
+            .into()
+        );
+        assert_eq!(
+            repl_input()
+                .parse(stream("f : Number -> Number\nf x = x", ""))
+                .unwrap()
+                .0,
+            Definition::from(FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ))
+            .into()
+        );
+        assert_eq!(
+            repl_input()
+                .parse(stream("type Foo = Number", ""))
+                .unwrap()
+                .0,
+            TypeDefinition::new("Foo", types::Number::new(SourceInformation::dummy())).into()
+        );
+        assert_eq!(
+            repl_input().parse(stream("x == 42 ", "")).unwrap().0,
+            Expression::from(EqualityOperation::new(
+                EqualityOperator::Equal,
+                Variable::new("x", SourceInformation::dummy()),
+                Number::new(42.0, SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ))
+            .into()
+        );
+    }
+
     #[test]
     fn parse_module_path() {
         assert!(module_path().parse(stream("?", "")).is_err());
@@ -1332,6 +1900,21 @@ mod tests {
                     types::Record::new("Foo", Default::default(), SourceInformation::dummy()),
                 ),
             ),
+            (
+                "type Foo a ( foo : a )",
+                TypeDefinition::new(
+                    "Foo",
+                    types::Record::with_arguments(
+                        "Foo",
+                        vec![types::Parameter::new("a", SourceInformation::dummy()).into()],
+                        vec![types::RecordElement::new(
+                            "foo",
+                            types::Parameter::new("a", SourceInformation::dummy()),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+            ),
             (
                 "type Foo ( foo : Number )",
                 TypeDefinition::new(
@@ -1423,6 +2006,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fail_to_parse_type_definitions_with_malformed_type_parameters() {
+        for source in &[
+            "type Foo A ( foo : A )",
+            "type Foo a.b ( foo : a.b )",
+            "type Foo 42",
+            "type Foo (a) ( foo : a )",
+            "type Foo a ( foo : a",
+        ] {
+            assert!(
+                parse_source(type_definition().skip(eof()), source, "").is_err(),
+                "{}",
+                source
+            );
+        }
+    }
+
     #[test]
     fn parse_type_alias_definition() {
         for (source, expected) in &[
@@ -1472,6 +2072,10 @@ mod tests {
                 type_().parse(stream("Number", "")).unwrap().0,
                 types::Number::new(SourceInformation::dummy()).into()
             );
+            assert_eq!(
+                type_().parse(stream("Integer", "")).unwrap().0,
+                types::Integer::new(SourceInformation::dummy()).into()
+            );
             assert_eq!(
                 type_().parse(stream("Number -> Number", "")).unwrap().0,
                 types::Function::new(
@@ -1615,6 +2219,58 @@ mod tests {
                 type_().parse(stream("Foo.Bar", "")).unwrap().0,
                 types::Reference::new("Foo.Bar", SourceInformation::dummy()).into()
             );
+            assert_eq!(
+                type_().parse(stream("Foo a (List Number)", "")).unwrap().0,
+                types::Reference::with_arguments(
+                    "Foo",
+                    vec![
+                        types::Parameter::new("a", SourceInformation::dummy()).into(),
+                        types::List::new(
+                            types::Number::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into()
+                    ],
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+            assert_eq!(
+                type_().parse(stream("Foo\nBar", "")).unwrap().0,
+                types::Reference::new("Foo", SourceInformation::dummy()).into()
+            );
+        }
+
+        #[test]
+        fn parse_parameter_type() {
+            assert_eq!(
+                type_().parse(stream("a", "")).unwrap().0,
+                types::Parameter::new("a", SourceInformation::dummy()).into()
+            );
+            assert_eq!(
+                type_().parse(stream("a -> a", "")).unwrap().0,
+                types::Function::new(
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+            assert_eq!(
+                type_().parse(stream("foo.Bar", "")).unwrap().0,
+                types::Reference::new("foo.Bar", SourceInformation::dummy()).into()
+            );
+        }
+
+        #[test]
+        fn fail_to_parse_malformed_type_applications() {
+            for source in &["a b", "Foo (", "Foo a ->", "Foo (a", "(a -> a"] {
+                assert!(
+                    parse_source(type_().skip(eof()), source, "").is_err(),
+                    "{}",
+                    source
+                );
+            }
         }
 
         #[test]
@@ -1940,31 +2596,190 @@ mod tests {
         }
 
         #[test]
-        fn parse_list_case() {
-            assert_eq!(
-                list_case()
-                    .parse(stream(
-                        indoc!(
-                            "
-                            case xs
-                                [] => None
-                                [ x, ...xs ] => None
-                            "
-                        ),
-                        ""
-                    ))
-                    .unwrap()
-                    .0,
-                ListCase::new(
-                    Variable::new("xs", SourceInformation::dummy()),
-                    types::Unknown::new(SourceInformation::dummy()),
-                    "x",
-                    "xs",
-                    None::new(SourceInformation::dummy()),
-                    None::new(SourceInformation::dummy()),
-                    SourceInformation::dummy(),
-                )
-            );
+        fn parse_list_case() {
+            assert_eq!(
+                list_case()
+                    .parse(stream(
+                        indoc!(
+                            "
+                            case xs
+                                [] => None
+                                [ x, ...xs ] => None
+                            "
+                        ),
+                        ""
+                    ))
+                    .unwrap()
+                    .0,
+                ListCase::new(
+                    Variable::new("xs", SourceInformation::dummy()),
+                    types::Unknown::new(SourceInformation::dummy()),
+                    "x",
+                    "xs",
+                    None::new(SourceInformation::dummy()),
+                    None::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+            );
+        }
+
+        #[test]
+        fn parse_case_with_patterns() {
+            assert_eq!(
+                case()
+                    .parse(stream(
+                        indoc!(
+                            "
+                          case foo = x
+                            Foo{ bar = y } => y
+                            42 => None
+                            \"foo\" => None
+                        "
+                        ),
+                        ""
+                    ))
+                    .unwrap()
+                    .0,
+                Case::new(
+                    "foo",
+                    Variable::new("x", SourceInformation::dummy()),
+                    vec![
+                        Alternative::with_pattern(
+                            types::Reference::new("Foo", SourceInformation::dummy()),
+                            RecordPattern::new(
+                                types::Reference::new("Foo", SourceInformation::dummy()),
+                                vec![(
+                                    "bar".into(),
+                                    Variable::new("y", SourceInformation::dummy()).into()
+                                )]
+                                .into_iter()
+                                .collect(),
+                                SourceInformation::dummy()
+                            ),
+                            Variable::new("y", SourceInformation::dummy())
+                        ),
+                        Alternative::with_pattern(
+                            types::Number::new(SourceInformation::dummy()),
+                            Number::new(42.0, SourceInformation::dummy()),
+                            None::new(SourceInformation::dummy())
+                        ),
+                        Alternative::with_pattern(
+                            types::EinString::new(SourceInformation::dummy()),
+                            EinString::new("foo", SourceInformation::dummy()),
+                            None::new(SourceInformation::dummy())
+                        ),
+                    ],
+                    SourceInformation::dummy()
+                )
+            );
+        }
+
+        #[test]
+        fn parse_list_pattern_case() {
+            assert_eq!(
+                expression()
+                    .parse(stream(
+                        indoc!(
+                            "
+                            case xs
+                                [] => None
+                                [ 42 ] => None
+                                [ x, y, ...ys ] => None
+                            "
+                        ),
+                        ""
+                    ))
+                    .unwrap()
+                    .0,
+                ListPatternCase::new(
+                    Variable::new("xs", SourceInformation::dummy()),
+                    vec![
+                        ListAlternative::new(
+                            ListPattern::new(vec![], None, SourceInformation::dummy()),
+                            None::new(SourceInformation::dummy())
+                        ),
+                        ListAlternative::new(
+                            ListPattern::new(
+                                vec![Number::new(42.0, SourceInformation::dummy()).into()],
+                                None,
+                                SourceInformation::dummy()
+                            ),
+                            None::new(SourceInformation::dummy())
+                        ),
+                        ListAlternative::new(
+                            ListPattern::new(
+                                vec![
+                                    Variable::new("x", SourceInformation::dummy()).into(),
+                                    Variable::new("y", SourceInformation::dummy()).into()
+                                ],
+                                Some("ys".into()),
+                                SourceInformation::dummy()
+                            ),
+                            None::new(SourceInformation::dummy())
+                        ),
+                    ],
+                    SourceInformation::dummy(),
+                )
+                .into()
+            );
+        }
+
+        #[test]
+        fn parse_lambda() {
+            assert!(lambda().parse(stream("\\ -> x", "")).is_err());
+            assert_eq!(
+                lambda().parse(stream("\\x -> x", "")).unwrap().0,
+                Lambda::new(
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+            );
+            assert_eq!(
+                expression()
+                    .parse(stream("f (\\x y -> x + y) z", ""))
+                    .unwrap()
+                    .0,
+                Application::new(
+                    Application::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        Lambda::new(
+                            vec!["x".into(), "y".into()],
+                            ArithmeticOperation::new(
+                                ArithmeticOperator::Add,
+                                Variable::new("x", SourceInformation::dummy()),
+                                Variable::new("y", SourceInformation::dummy()),
+                                SourceInformation::dummy()
+                            ),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    Variable::new("z", SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+        }
+
+        #[test]
+        fn fail_to_parse_malformed_lambdas() {
+            for source in &[
+                "\\x",
+                "\\x y",
+                "\\x ->",
+                "\\x => x",
+                "\\42 -> x",
+                "\\x -> in",
+                "f \\x ->",
+                "(\\x -> x",
+            ] {
+                assert!(
+                    parse_source(expression().skip(eof()), source, "").is_err(),
+                    "{}",
+                    source
+                );
+            }
         }
 
         #[test]
@@ -2147,6 +2962,80 @@ mod tests {
             );
         }
 
+        #[test]
+        fn parse_let_pattern() {
+            assert_eq!(
+                expression()
+                    .parse(stream("let Foo{ foo = [x, ...xs] } = y in x", ""))
+                    .unwrap()
+                    .0,
+                LetPattern::new(
+                    vec![PatternDefinition::new(
+                        RecordPattern::new(
+                            types::Reference::new("Foo", SourceInformation::dummy()),
+                            vec![(
+                                "foo".into(),
+                                ListPattern::new(
+                                    vec![Variable::new("x", SourceInformation::dummy()).into()],
+                                    Some("xs".into()),
+                                    SourceInformation::dummy()
+                                )
+                                .into()
+                            )]
+                            .into_iter()
+                            .collect(),
+                            SourceInformation::dummy()
+                        ),
+                        Variable::new("y", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )],
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+        }
+
+        #[test]
+        fn fail_to_parse_malformed_patterns() {
+            for source in &[
+                "case x Foo{} => x",
+                "case x Foo{ foo = } => x",
+                "case x Foo{ = y } => x",
+                "case x Foo{ foo = y => x",
+                "case x Foo{ foo = y } x",
+                "case xs [ ...ys, y ] => y",
+                "case xs [ y, ... ] => y",
+                "case xs [ y => y",
+                "let Foo{ foo = x } y in x",
+                "let [ x ] in x",
+                "let [ x, ...xs ] = in x",
+            ] {
+                assert!(
+                    parse_source(expression().skip(eof()), source, "").is_err(),
+                    "{}",
+                    source
+                );
+            }
+        }
+
+        #[test]
+        fn fail_to_parse_record_pattern_with_duplicate_keys() {
+            let error =
+                parse_source(expression(), "let Foo{ x = a, x = b } = y in a", "").unwrap_err();
+
+            assert_eq!(
+                error.position,
+                SourcePosition {
+                    line: 1,
+                    column: 24
+                }
+            );
+            assert!(error.errors.contains(&easy::Error::Unexpected(
+                "duplicate keys in record pattern".into()
+            )));
+        }
+
         #[test]
         fn parse_let_with_function_definitions() {
             assert!(let_().parse(stream("let in 0", "")).is_err());
@@ -2776,6 +3665,8 @@ mod tests {
                 ("-", ParsedOperator::Subtract),
                 ("*", ParsedOperator::Multiply),
                 ("/", ParsedOperator::Divide),
+                ("//", ParsedOperator::IntegerDivide),
+                ("%", ParsedOperator::Remainder),
                 ("==", ParsedOperator::Equal),
                 ("/=", ParsedOperator::NotEqual),
                 ("<", ParsedOperator::LessThan),
@@ -2831,15 +3722,21 @@ mod tests {
             assert!(number_literal().parse(stream("", "")).is_err());
             assert!(number_literal().parse(stream("foo", "")).is_err());
             assert!(number_literal().parse(stream("x1", "")).is_err());
+            assert!(number_literal().parse(stream("01", "")).is_err());
+            assert!(number_literal().parse(stream("1i", "")).is_err());
 
             for (source, value) in &[
-                ("01", 0.0),
                 ("0", 0.0),
                 ("1", 1.0),
                 ("123456789", 123456789.0),
                 ("-1", -1.0),
                 ("0.1", 0.1),
                 ("0.01", 0.01),
+                ("0xFF", 255.0),
+                ("0o17", 15.0),
+                ("0b1010", 10.0),
+                ("1e-9", 1e-9),
+                ("1_000_000", 1e6),
             ] {
                 assert_eq!(
                     number_literal().parse(stream(source, "")).unwrap().0,
@@ -2848,6 +3745,42 @@ mod tests {
             }
         }
 
+        #[test]
+        fn parse_integer_literal() {
+            assert!(integer_literal().parse(stream("", "")).is_err());
+            assert!(integer_literal().parse(stream("1", "")).is_err());
+            assert!(integer_literal().parse(stream("1.0i", "")).is_err());
+            assert!(integer_literal().parse(stream("1ix", "")).is_err());
+            assert!(integer_literal()
+                .parse(stream("9223372036854775808i", ""))
+                .is_err());
+
+            for (source, value) in &[
+                ("0i", 0),
+                ("1i", 1),
+                ("-1i", -1),
+                ("9007199254740993i", 9007199254740993),
+                ("0xFFi", 255),
+                ("-9223372036854775808i", i64::MIN),
+            ] {
+                assert_eq!(
+                    integer_literal().parse(stream(source, "")).unwrap().0,
+                    Integer::new(*value, SourceInformation::dummy())
+                );
+            }
+
+            assert_eq!(
+                expression().parse(stream("1i + 2", "")).unwrap().0,
+                ArithmeticOperation::new(
+                    ArithmeticOperator::Add,
+                    Integer::new(1, SourceInformation::dummy()),
+                    Number::new(2.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+        }
+
         #[test]
         fn parse_string_literal() {
             assert!(string_literal().parse(stream("", "")).is_err());
@@ -2862,6 +3795,15 @@ mod tests {
                 ("\"\\t\"", "\t"),
                 ("\"\\\\\"", "\\"),
                 ("\"\\n\\n\"", "\n\n"),
+                ("\"\\r\"", "\r"),
+                ("\"\\0\"", "\0"),
+                ("\"\\x41\"", "A"),
+                ("\"\\u{1F600}\"", "😀"),
+                ("\"\\#{\"", "#{"),
+                ("\"#\"", "#"),
+                ("`foo`", "foo"),
+                ("`\\n\"#{}`", "\\n\"#{}"),
+                ("`foo\n  bar`", "foo\n  bar"),
             ] {
                 assert_eq!(
                     string_literal().parse(stream(source, "")).unwrap().0,
@@ -2870,6 +3812,157 @@ mod tests {
             }
         }
 
+        #[test]
+        fn fail_to_parse_string_literal_with_interpolation() {
+            assert!(string_literal().parse(stream("\"#{x}\"", "")).is_err());
+        }
+
+        #[test]
+        fn fail_to_parse_invalid_escape_sequences() {
+            for (source, escape_sequence) in &[
+                ("\"\\a\"", "\\a"),
+                ("\"\\x80\"", "\\x"),
+                ("\"\\x4\"", "\\x"),
+                ("\"\\u{}\"", "\\u"),
+                ("\"\\u{110000}\"", "\\u"),
+                ("\"\\u{D800}\"", "\\u"),
+            ] {
+                let error = parse_source(expression(), source, "").unwrap_err();
+
+                assert_eq!(error.position, SourcePosition { line: 1, column: 2 });
+                assert_eq!(
+                    error.errors,
+                    vec![easy::Error::Message(
+                        format!("invalid escape sequence {}", escape_sequence).into()
+                    )]
+                );
+            }
+        }
+
+        #[test]
+        fn fail_to_parse_invalid_escape_sequences_at_their_positions() {
+            for (source, line, column) in &[
+                ("\"foo\\a\"", 1, 5),
+                ("\"#{x}\\a\"", 1, 6),
+                ("\"\\n\\a\"", 1, 4),
+                ("f `foo` \"\\a\"", 1, 10),
+            ] {
+                let error = parse_source(expression(), source, "").unwrap_err();
+
+                assert_eq!(
+                    error.position,
+                    SourcePosition {
+                        line: *line,
+                        column: *column
+                    },
+                    "{}",
+                    source
+                );
+                assert_eq!(
+                    error.errors,
+                    vec![easy::Error::Message("invalid escape sequence \\a".into())]
+                );
+            }
+        }
+
+        #[test]
+        fn fail_to_parse_invalid_escape_sequence_in_module() {
+            let error = parse_source(
+                module(),
+                indoc!(
+                    "
+                    x : String
+                    x =
+                      \"foo\\qbar\"
+                    "
+                ),
+                "",
+            )
+            .unwrap_err();
+
+            assert_eq!(error.position, SourcePosition { line: 3, column: 7 });
+            assert_eq!(
+                error.errors,
+                vec![easy::Error::Message("invalid escape sequence \\q".into())]
+            );
+        }
+
+        #[test]
+        fn fail_to_parse_malformed_strings() {
+            for source in &[
+                "\"foo",
+                "`foo",
+                "\"#{}\"",
+                "\"#{x\"",
+                "\"#{x}",
+                "\"\\u{1F600\"",
+            ] {
+                assert!(
+                    parse_source(expression(), source, "").is_err(),
+                    "{}",
+                    source
+                );
+            }
+        }
+
+        #[test]
+        fn fail_to_parse_malformed_number_literals() {
+            for (source, message) in &[
+                ("0x", "missing digits in hexadecimal literal"),
+                ("1__0", "misplaced digit separator in decimal literal"),
+                ("1e999", "number literal out of range"),
+                ("x + 1.5i", "fraction or exponent in integer literal"),
+            ] {
+                let error = parse_source(expression(), source, "").unwrap_err();
+
+                assert_eq!(
+                    error.position,
+                    SourcePosition {
+                        line: 1,
+                        column: source.find(char::is_numeric).unwrap() as i32 + 1
+                    }
+                );
+                assert_eq!(
+                    error.errors,
+                    vec![easy::Error::Message(message.to_string().into())]
+                );
+            }
+        }
+
+        #[test]
+        fn parse_string_interpolation() {
+            assert_eq!(
+                expression()
+                    .parse(stream("\"foo#{x}bar#{ 42 }\"", ""))
+                    .unwrap()
+                    .0,
+                StringInterpolation::new(
+                    vec![
+                        StringInterpolationPart::String("foo".into()),
+                        StringInterpolationPart::Expression(
+                            Variable::new("x", SourceInformation::dummy()).into()
+                        ),
+                        StringInterpolationPart::String("bar".into()),
+                        StringInterpolationPart::Expression(
+                            Number::new(42.0, SourceInformation::dummy()).into()
+                        ),
+                    ],
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+            assert_eq!(
+                expression().parse(stream("\"#{\"foo\"}\"", "")).unwrap().0,
+                StringInterpolation::new(
+                    vec![StringInterpolationPart::Expression(
+                        EinString::new("foo", SourceInformation::dummy()).into()
+                    )],
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+        }
+
         #[test]
         fn parse_list() {
             for (source, target) in vec![
@@ -3001,6 +4094,92 @@ mod tests {
                 );
             }
         }
+
+        #[test]
+        fn parse_array() {
+            for (source, target) in [
+                ("[||]", Array::new(vec![], SourceInformation::dummy())),
+                (
+                    "[| 42 |]",
+                    Array::new(
+                        vec![Number::new(42.0, SourceInformation::dummy()).into()],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+                (
+                    "[| x, y + 1, |]",
+                    Array::new(
+                        vec![
+                            Variable::new("x", SourceInformation::dummy()).into(),
+                            ArithmeticOperation::new(
+                                ArithmeticOperator::Add,
+                                Variable::new("y", SourceInformation::dummy()),
+                                Number::new(1.0, SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            )
+                            .into(),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+                (
+                    "[| [1] |]",
+                    Array::new(
+                        vec![List::new(
+                            vec![ListElement::Single(
+                                Number::new(1.0, SourceInformation::dummy()).into(),
+                            )],
+                            SourceInformation::dummy(),
+                        )
+                        .into()],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+            ] {
+                assert_eq!(
+                    expression().parse(stream(source, "")).unwrap().0,
+                    target.into()
+                );
+            }
+        }
+
+        #[test]
+        fn parse_map() {
+            for (source, target) in [
+                ("{}", Map::new(vec![], SourceInformation::dummy())),
+                (
+                    "{ \"foo\": 42 }",
+                    Map::new(
+                        vec![MapEntry::new(
+                            EinString::new("foo", SourceInformation::dummy()),
+                            Number::new(42.0, SourceInformation::dummy()),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+                (
+                    "{ x: 1, y: 2, }",
+                    Map::new(
+                        vec![
+                            MapEntry::new(
+                                Variable::new("x", SourceInformation::dummy()),
+                                Number::new(1.0, SourceInformation::dummy()),
+                            ),
+                            MapEntry::new(
+                                Variable::new("y", SourceInformation::dummy()),
+                                Number::new(2.0, SourceInformation::dummy()),
+                            ),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+            ] {
+                assert_eq!(
+                    expression().parse(stream(source, "")).unwrap().0,
+                    target.into()
+                );
+            }
+        }
     }
 
     #[test]
@@ -3093,4 +4272,81 @@ mod tests {
         assert!(comment().parse(stream("#\n", "")).is_ok());
         assert!(comment().parse(stream("#x\n", "")).is_ok());
     }
+
+    #[test]
+    fn parse_documentation() {
+        assert!(documentation().parse(stream("", "")).is_err());
+        assert!(documentation().parse(stream("# foo\n", "")).is_err());
+        assert_eq!(
+            documentation().parse(stream("## foo\n", "")).unwrap().0,
+            "foo"
+        );
+        assert_eq!(
+            documentation()
+                .parse(stream("# foo\n\n## bar\n##\n## baz\n", ""))
+                .unwrap()
+                .0,
+            "bar\n\nbaz"
+        );
+    }
+
+    #[test]
+    fn parse_module_with_documentation() {
+        assert_eq!(
+            module()
+                .parse(stream(
+                    indoc!(
+                        "
+                        ## Foo type
+                        type Foo
+
+                        ## Function f
+                        ## returns none.
+                        f : Foo -> None
+                        f x = None
+
+                        ## Not documentation of variables
+                        x : None
+                        x = None
+                        "
+                    ),
+                    ""
+                ))
+                .unwrap()
+                .0,
+            UnresolvedModule::new(
+                Export::new(Default::default()),
+                ExportForeign::new(Default::default()),
+                vec![],
+                vec![],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    types::Record::new("Foo", vec![], SourceInformation::dummy())
+                )
+                .with_documentation("Foo type")],
+                vec![
+                    FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        None::new(SourceInformation::dummy()),
+                        types::Function::new(
+                            types::Reference::new("Foo", SourceInformation::dummy()),
+                            types::None::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    )
+                    .with_documentation("Function f\nreturns none.")
+                    .into(),
+                    VariableDefinition::new(
+                        "x",
+                        None::new(SourceInformation::dummy()),
+                        types::None::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into()
+                ]
+            )
+        );
+    }
 }
@@ -6,6 +6,8 @@ pub enum ParsedOperator {
     Subtract,
     Multiply,
     Divide,
+    IntegerDivide,
+    Remainder,
     Equal,
     NotEqual,
     LessThan,
@@ -101,6 +103,20 @@ fn create_operation(
             source_information.clone(),
         )
         .into(),
+        ParsedOperator::IntegerDivide => ArithmeticOperation::new(
+            ArithmeticOperator::IntegerDivide,
+            lhs,
+            rhs,
+            source_information.clone(),
+        )
+        .into(),
+        ParsedOperator::Remainder => ArithmeticOperation::new(
+            ArithmeticOperator::Remainder,
+            lhs,
+            rhs,
+            source_information.clone(),
+        )
+        .into(),
         ParsedOperator::LessThan => OrderOperation::new(
             OrderOperator::LessThan,
             lhs,
@@ -144,6 +160,9 @@ fn operator_priority(operator: ParsedOperator) -> usize {
         | ParsedOperator::GreaterThan
         | ParsedOperator::GreaterThanOrEqual => 3,
         ParsedOperator::Add | ParsedOperator::Subtract => 4,
-        ParsedOperator::Multiply | ParsedOperator::Divide => 5,
+        ParsedOperator::Multiply
+        | ParsedOperator::Divide
+        | ParsedOperator::IntegerDivide
+        | ParsedOperator::Remainder => 5,
     }
 }
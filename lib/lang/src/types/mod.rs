@@ -1,9 +1,11 @@
 mod any;
 mod boolean;
 mod function;
+mod integer;
 mod list;
 mod none;
 mod number;
+mod parameter;
 mod record;
 mod record_element;
 mod reference;
@@ -16,9 +18,11 @@ mod variable;
 pub use any::*;
 pub use boolean::*;
 pub use function::*;
+pub use integer::*;
 pub use list::*;
 pub use none::*;
 pub use number::*;
+pub use parameter::*;
 pub use record::*;
 pub use record_element::*;
 pub use reference::*;
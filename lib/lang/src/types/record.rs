@@ -8,6 +8,8 @@ use std::sync::Arc;
 #[derivative(Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Record {
     name: String,
+    #[serde(default)]
+    arguments: Vec<Type>,
     #[derivative(
         Hash = "ignore",
         Ord = "ignore",
@@ -23,9 +25,19 @@ impl Record {
         name: impl Into<String>,
         elements: Vec<RecordElement>,
         source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self::with_arguments(name, vec![], elements, source_information)
+    }
+
+    pub fn with_arguments(
+        name: impl Into<String>,
+        arguments: Vec<Type>,
+        elements: Vec<RecordElement>,
+        source_information: impl Into<Arc<SourceInformation>>,
     ) -> Self {
         Self {
             name: name.into(),
+            arguments,
             elements,
             source_information: source_information.into(),
         }
@@ -35,6 +47,10 @@ impl Record {
         &self.name
     }
 
+    pub fn arguments(&self) -> &[Type] {
+        &self.arguments
+    }
+
     pub fn elements(&self) -> &[RecordElement] {
         &self.elements
     }
@@ -49,6 +65,11 @@ impl Record {
     ) -> Result<Self, E> {
         Ok(Self {
             name: self.name.clone(),
+            arguments: self
+                .arguments
+                .iter()
+                .map(|type_| type_.transform_types(transform))
+                .collect::<Result<_, _>>()?,
             elements: self
                 .elements
                 .iter()
@@ -1,7 +1,7 @@
 use super::{
-    any::Any, boolean::Boolean, function::Function, list::List, none::None, number::Number,
-    record::Record, reference::Reference, string::EinString, union::Union, unknown::Unknown,
-    variable::Variable,
+    any::Any, boolean::Boolean, function::Function, integer::Integer, list::List, none::None,
+    number::Number, parameter::Parameter, record::Record, reference::Reference, string::EinString,
+    union::Union, unknown::Unknown, variable::Variable,
 };
 use crate::debug::SourceInformation;
 use serde::{Deserialize, Serialize};
@@ -12,9 +12,11 @@ pub enum Type {
     Any(Any),
     Boolean(Boolean),
     Function(Function),
+    Integer(Integer),
     List(List),
     None(None),
     Number(Number),
+    Parameter(Parameter),
     Record(Record),
     Reference(Reference),
     String(EinString),
@@ -29,9 +31,11 @@ impl Type {
             Self::Any(any) => any.source_information(),
             Self::Boolean(boolean) => boolean.source_information(),
             Self::Function(function) => function.source_information(),
+            Self::Integer(integer) => integer.source_information(),
             Self::List(list) => list.source_information(),
             Self::None(none) => none.source_information(),
             Self::Number(number) => number.source_information(),
+            Self::Parameter(parameter) => parameter.source_information(),
             Self::Record(record) => record.source_information(),
             Self::Reference(reference) => reference.source_information(),
             Self::String(string) => string.source_information(),
@@ -66,12 +70,14 @@ impl Type {
             Self::Function(function) => function.transform_types(transform)?.into(),
             Self::List(list) => list.transform_types(transform)?.into(),
             Self::Record(record) => record.transform_types(transform)?.into(),
+            Self::Reference(reference) => reference.transform_types(transform)?.into(),
             Self::Union(union) => union.transform_types(transform)?.into(),
             Self::Any(_)
             | Self::Boolean(_)
+            | Self::Integer(_)
             | Self::None(_)
             | Self::Number(_)
-            | Self::Reference(_)
+            | Self::Parameter(_)
             | Self::String(_)
             | Self::Unknown(_)
             | Self::Variable(_) => self.clone(),
@@ -105,6 +111,12 @@ impl From<Function> for Type {
     }
 }
 
+impl From<Integer> for Type {
+    fn from(integer: Integer) -> Self {
+        Self::Integer(integer)
+    }
+}
+
 impl From<List> for Type {
     fn from(list: List) -> Self {
         Self::List(list)
@@ -123,6 +135,12 @@ impl From<Number> for Type {
     }
 }
 
+impl From<Parameter> for Type {
+    fn from(parameter: Parameter) -> Self {
+        Self::Parameter(parameter)
+    }
+}
+
 impl From<Record> for Type {
     fn from(record: Record) -> Self {
         Self::Record(record)
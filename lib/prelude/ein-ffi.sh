@@ -1,7 +0,0 @@
-#!/bin/sh
-
-set -e
-
-cd ffi
-cargo build --release
-echo ffi/target/release/libprelude.a
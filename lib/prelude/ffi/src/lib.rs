@@ -1,12 +1,302 @@
+use std::convert::TryFrom;
+
+const HASH_CHUNK_SIZE: u32 = 5;
+
 #[no_mangle]
 extern "C" fn _ein_equal_strings(one: ffi::EinString, other: ffi::EinString) -> ffi::Boolean {
     (one.as_slice() == other.as_slice()).into()
 }
 
+#[no_mangle]
+extern "C" fn _ein_concatenate_strings(
+    one: ffi::EinString,
+    other: ffi::EinString,
+) -> ffi::EinString {
+    one.join(&other)
+}
+
+#[no_mangle]
+extern "C" fn _ein_number_to_string(number: ffi::Number) -> ffi::EinString {
+    f64::from(number).to_string().into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_integer_to_string(integer: ffi::Integer) -> ffi::EinString {
+    i64::from(integer).to_string().into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_hash_number(number: ffi::Number) -> ffi::Number {
+    let number = f64::from(number);
+    // Zeros of different signs are equal.
+    let number = if number == 0.0 { 0.0 } else { number };
+
+    hash_bytes(&number.to_bits().to_le_bytes())
+}
+
+#[no_mangle]
+extern "C" fn _ein_hash_string(string: ffi::EinString) -> ffi::Number {
+    hash_bytes(string.as_slice())
+}
+
+#[no_mangle]
+extern "C" fn _ein_combine_hashes(one: ffi::Number, other: ffi::Number) -> ffi::Number {
+    let one = f64::from(one) as u32;
+    let other = f64::from(other) as u32;
+
+    let hash = other
+        .wrapping_add(0x9e3779b9)
+        .wrapping_add(one << 6)
+        .wrapping_add(one >> 2);
+
+    ((one ^ hash) as f64).into()
+}
+
+// Maps branch on chunks of 5 bits of hashes at each level of their tries.
+#[no_mangle]
+extern "C" fn _ein_get_hash_chunk(hash: ffi::Number, depth: ffi::Number) -> ffi::Number {
+    (((f64::from(hash) as u32)
+        .checked_shr(HASH_CHUNK_SIZE * f64::from(depth) as u32)
+        .unwrap_or(0)
+        & ((1 << HASH_CHUNK_SIZE) - 1)) as f64)
+        .into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_has_bitmap_bit(bitmap: ffi::Number, chunk: ffi::Number) -> ffi::Boolean {
+    (f64::from(bitmap) as u32 & get_bitmap_bit(chunk) != 0).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_set_bitmap_bit(bitmap: ffi::Number, chunk: ffi::Number) -> ffi::Number {
+    ((f64::from(bitmap) as u32 | get_bitmap_bit(chunk)) as f64).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_clear_bitmap_bit(bitmap: ffi::Number, chunk: ffi::Number) -> ffi::Number {
+    ((f64::from(bitmap) as u32 & !get_bitmap_bit(chunk)) as f64).into()
+}
+
+// Indices are 1-based positions of children in the order of bits of bitmaps.
+#[no_mangle]
+extern "C" fn _ein_get_bitmap_index(bitmap: ffi::Number, chunk: ffi::Number) -> ffi::Number {
+    (((f64::from(bitmap) as u32 & get_bitmap_bit(chunk).wrapping_sub(1)).count_ones() + 1) as f64)
+        .into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_create_array(length: ffi::Number, element: ffi::Any) -> ffi::Any {
+    (0..f64::from(length).max(0.0) as usize)
+        .fold(ffi::Array::default(), |array, _| {
+            array.push(element.clone())
+        })
+        .into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_get_array_length(array: ffi::Any) -> ffi::Number {
+    (ffi::Array::from(array).len() as f64).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_has_array_index(array: ffi::Any, index: ffi::Number) -> ffi::Boolean {
+    ffi::Array::from(array).get(index).is_some().into()
+}
+
+// Default values are returned for indices out of bounds.
+#[no_mangle]
+extern "C" fn _ein_get_array_element(
+    array: ffi::Any,
+    index: ffi::Number,
+    default: ffi::Any,
+) -> ffi::Any {
+    ffi::Array::from(array)
+        .get(index)
+        .cloned()
+        .unwrap_or(default)
+}
+
+#[no_mangle]
+extern "C" fn _ein_set_array_element(
+    array: ffi::Any,
+    index: ffi::Number,
+    element: ffi::Any,
+) -> ffi::Any {
+    ffi::Array::from(array).set(index, element).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_push_to_array(array: ffi::Any, element: ffi::Any) -> ffi::Any {
+    ffi::Array::from(array).push(element).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_pop_from_array(array: ffi::Any) -> ffi::Any {
+    ffi::Array::from(array).pop().into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_slice_array(array: ffi::Any, start: ffi::Number, end: ffi::Number) -> ffi::Any {
+    ffi::Array::from(array).slice(start, end).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_concatenate_arrays(one: ffi::Any, other: ffi::Any) -> ffi::Any {
+    ffi::Array::from(one)
+        .concatenate(&ffi::Array::from(other))
+        .into()
+}
+
+// Integer operations wrap around on overflow and result in zero on division
+// by zero.
+#[no_mangle]
+extern "C" fn _ein_add_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Integer {
+    i64::from(one).wrapping_add(other.into()).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_subtract_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Integer {
+    i64::from(one).wrapping_sub(other.into()).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_multiply_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Integer {
+    i64::from(one).wrapping_mul(other.into()).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_divide_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Integer {
+    match i64::from(other) {
+        0 => 0,
+        other => i64::from(one).wrapping_div(other),
+    }
+    .into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_remainder_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Integer {
+    match i64::from(other) {
+        0 => 0,
+        other => i64::from(one).wrapping_rem(other),
+    }
+    .into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_equal_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Boolean {
+    (i64::from(one) == i64::from(other)).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_compare_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Number {
+    (i64::from(one).cmp(&other.into()) as i64 as f64).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_hash_integer(integer: ffi::Integer) -> ffi::Number {
+    hash_bytes(&i64::from(integer).to_le_bytes())
+}
+
+#[no_mangle]
+extern "C" fn _ein_and_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Integer {
+    (i64::from(one) & i64::from(other)).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_or_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Integer {
+    (i64::from(one) | i64::from(other)).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_xor_integers(one: ffi::Integer, other: ffi::Integer) -> ffi::Integer {
+    (i64::from(one) ^ i64::from(other)).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_not_integer(integer: ffi::Integer) -> ffi::Integer {
+    (!i64::from(integer)).into()
+}
+
+// Bits are shifted out entirely when shift amounts are out of range.
+#[no_mangle]
+extern "C" fn _ein_shift_integer_left(amount: ffi::Integer, integer: ffi::Integer) -> ffi::Integer {
+    convert_shift_amount(amount)
+        .and_then(|amount| i64::from(integer).checked_shl(amount))
+        .unwrap_or(0)
+        .into()
+}
+
+// Right shifts are arithmetic ones keeping signs.
+#[no_mangle]
+extern "C" fn _ein_shift_integer_right(
+    amount: ffi::Integer,
+    integer: ffi::Integer,
+) -> ffi::Integer {
+    let integer = i64::from(integer);
+
+    convert_shift_amount(amount)
+        .and_then(|amount| integer.checked_shr(amount))
+        .unwrap_or(if integer < 0 { -1 } else { 0 })
+        .into()
+}
+
+fn convert_shift_amount(amount: ffi::Integer) -> Option<u32> {
+    u32::try_from(i64::from(amount)).ok()
+}
+
+#[no_mangle]
+extern "C" fn _ein_integer_to_number(integer: ffi::Integer) -> ffi::Number {
+    (i64::from(integer) as f64).into()
+}
+
+// Numbers are truncated and saturated into integers. NaN is converted into
+// zero.
+#[no_mangle]
+extern "C" fn _ein_number_to_integer(number: ffi::Number) -> ffi::Integer {
+    (f64::from(number) as i64).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_integer_divide_numbers(one: ffi::Number, other: ffi::Number) -> ffi::Number {
+    (f64::from(one) / f64::from(other)).trunc().into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_remainder_numbers(one: ffi::Number, other: ffi::Number) -> ffi::Number {
+    (f64::from(one) % f64::from(other)).into()
+}
+
+fn get_bitmap_bit(chunk: ffi::Number) -> u32 {
+    1u32.checked_shl(f64::from(chunk) as u32).unwrap_or(0)
+}
+
+// Hashes are 32-bit FNV-1a ones represented as numbers.
+fn hash_bytes(bytes: &[u8]) -> ffi::Number {
+    (bytes.iter().fold(0x811c9dc5u32, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    }) as f64)
+        .into()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn concatenate_strings() {
+        assert_eq!(
+            _ein_concatenate_strings("foo".into(), "bar".into()),
+            "foobar".into()
+        );
+    }
+
+    #[test]
+    fn convert_numbers_to_strings() {
+        assert_eq!(_ein_number_to_string(42.0.into()), "42".into());
+        assert_eq!(_ein_number_to_string(0.5.into()), "0.5".into());
+        assert_eq!(_ein_integer_to_string((-42).into()), "-42".into());
+    }
+
     #[test]
     fn equal_empty_strings() {
         let string = ffi::EinString::empty();
@@ -48,4 +338,189 @@ mod tests {
             false.into()
         );
     }
+
+    #[test]
+    fn hash_zeros() {
+        assert_eq!(
+            _ein_hash_number(0.0.into()),
+            _ein_hash_number((-0.0).into())
+        );
+    }
+
+    #[test]
+    fn hash_strings() {
+        assert_eq!(
+            _ein_hash_string("foo".into()),
+            _ein_hash_string("foo".into())
+        );
+        assert_ne!(
+            _ein_hash_string("foo".into()),
+            _ein_hash_string("bar".into())
+        );
+    }
+
+    #[test]
+    fn get_hash_chunks() {
+        assert_eq!(
+            _ein_get_hash_chunk((0x43 as f64).into(), 0.0.into()),
+            3.0.into()
+        );
+        assert_eq!(
+            _ein_get_hash_chunk((0x43 as f64).into(), 1.0.into()),
+            2.0.into()
+        );
+        assert_eq!(
+            _ein_get_hash_chunk((u32::MAX as f64).into(), 6.0.into()),
+            3.0.into()
+        );
+        assert_eq!(
+            _ein_get_hash_chunk((u32::MAX as f64).into(), 7.0.into()),
+            0.0.into()
+        );
+    }
+
+    #[test]
+    fn set_bitmap_bits() {
+        let bitmap = _ein_set_bitmap_bit(0.0.into(), 3.0.into());
+
+        assert_eq!(bitmap, 8.0.into());
+        assert_eq!(_ein_has_bitmap_bit(bitmap, 3.0.into()), true.into());
+        assert_eq!(_ein_has_bitmap_bit(bitmap, 2.0.into()), false.into());
+        assert_eq!(_ein_clear_bitmap_bit(bitmap, 3.0.into()), 0.0.into());
+    }
+
+    #[test]
+    fn set_last_bitmap_bit() {
+        let bitmap = _ein_set_bitmap_bit(0.0.into(), 31.0.into());
+
+        assert_eq!(bitmap, (2f64.powi(31)).into());
+        assert_eq!(_ein_has_bitmap_bit(bitmap, 31.0.into()), true.into());
+    }
+
+    #[test]
+    fn get_bitmap_indices() {
+        let bitmap = [1.0, 4.0, 31.0].iter().fold(0.0.into(), |bitmap, &chunk| {
+            _ein_set_bitmap_bit(bitmap, chunk.into())
+        });
+
+        assert_eq!(_ein_get_bitmap_index(bitmap, 1.0.into()), 1.0.into());
+        assert_eq!(_ein_get_bitmap_index(bitmap, 3.0.into()), 2.0.into());
+        assert_eq!(_ein_get_bitmap_index(bitmap, 4.0.into()), 2.0.into());
+        assert_eq!(_ein_get_bitmap_index(bitmap, 31.0.into()), 3.0.into());
+    }
+
+    #[test]
+    fn divide_integers() {
+        assert_eq!(_ein_divide_integers(7.into(), 2.into()), 3.into());
+        assert_eq!(_ein_divide_integers((-7).into(), 2.into()), (-3).into());
+        assert_eq!(_ein_divide_integers(7.into(), 0.into()), 0.into());
+        assert_eq!(
+            _ein_divide_integers(i64::MIN.into(), (-1).into()),
+            i64::MIN.into()
+        );
+    }
+
+    #[test]
+    fn calculate_integer_remainders() {
+        assert_eq!(_ein_remainder_integers(7.into(), 2.into()), 1.into());
+        assert_eq!(_ein_remainder_integers((-7).into(), 2.into()), (-1).into());
+        assert_eq!(_ein_remainder_integers(7.into(), 0.into()), 0.into());
+    }
+
+    #[test]
+    fn add_integers_with_overflow() {
+        assert_eq!(
+            _ein_add_integers(i64::MAX.into(), 1.into()),
+            i64::MIN.into()
+        );
+    }
+
+    #[test]
+    fn compare_integers() {
+        assert_eq!(_ein_compare_integers(1.into(), 2.into()), (-1.0).into());
+        assert_eq!(_ein_compare_integers(2.into(), 2.into()), 0.0.into());
+        assert_eq!(
+            _ein_compare_integers(i64::MAX.into(), i64::MIN.into()),
+            1.0.into()
+        );
+    }
+
+    #[test]
+    fn shift_integers() {
+        assert_eq!(_ein_shift_integer_left(2.into(), 1.into()), 4.into());
+        assert_eq!(_ein_shift_integer_left(64.into(), 1.into()), 0.into());
+        assert_eq!(_ein_shift_integer_left((-1).into(), 1.into()), 0.into());
+        assert_eq!(_ein_shift_integer_right(1.into(), (-4).into()), (-2).into());
+        assert_eq!(
+            _ein_shift_integer_right(64.into(), (-4).into()),
+            (-1).into()
+        );
+        assert_eq!(_ein_shift_integer_right(64.into(), 4.into()), 0.into());
+    }
+
+    #[test]
+    fn convert_numbers_to_integers() {
+        assert_eq!(_ein_number_to_integer(42.9.into()), 42.into());
+        assert_eq!(_ein_number_to_integer((-42.9).into()), (-42).into());
+        assert_eq!(_ein_number_to_integer(f64::NAN.into()), 0.into());
+        assert_eq!(_ein_number_to_integer(1e300.into()), i64::MAX.into());
+    }
+
+    #[test]
+    fn create_array() {
+        let array = _ein_create_array(3.0.into(), ffi::Array::default().into());
+
+        assert_eq!(_ein_get_array_length(array), 3.0.into());
+    }
+
+    #[test]
+    fn get_array_element() {
+        let array = _ein_push_to_array(
+            _ein_create_array(0.0.into(), ffi::Array::default().into()),
+            _ein_create_array(42.0.into(), ffi::Array::default().into()),
+        );
+
+        assert_eq!(_ein_has_array_index(array.clone(), 1.0.into()), true.into());
+        assert_eq!(
+            _ein_has_array_index(array.clone(), 2.0.into()),
+            false.into()
+        );
+        assert_eq!(
+            _ein_get_array_length(_ein_get_array_element(
+                array,
+                1.0.into(),
+                ffi::Array::default().into()
+            )),
+            42.0.into()
+        );
+    }
+
+    #[test]
+    fn get_default_array_element() {
+        let array = _ein_create_array(1.0.into(), ffi::Array::default().into());
+
+        for index in &[0.0, 2.0, f64::NAN] {
+            assert_eq!(
+                _ein_get_array_length(_ein_get_array_element(
+                    array.clone(),
+                    (*index).into(),
+                    _ein_create_array(42.0.into(), ffi::Array::default().into()),
+                )),
+                42.0.into()
+            );
+        }
+    }
+
+    #[test]
+    fn slice_and_concatenate_arrays() {
+        let array = _ein_create_array(3.0.into(), ffi::Array::default().into());
+
+        assert_eq!(
+            _ein_get_array_length(_ein_concatenate_arrays(
+                _ein_slice_array(array.clone(), 2.0.into(), 3.0.into()),
+                _ein_pop_from_array(array)
+            )),
+            4.0.into()
+        );
+    }
 }